@@ -0,0 +1,272 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    Date, HoraeError, Weekday,
+    date_time::common::{days_from_civil, week_day},
+};
+
+/// How to move a date that is not a business day onto one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RollConvention {
+    /// The next business day.
+    Following,
+    /// The next business day, unless it is in the next month, then the previous business day.
+    ModifiedFollowing,
+    /// The previous business day.
+    Preceding,
+    /// The previous business day, unless it is in the previous month, then the next business
+    /// day.
+    ModifiedPreceding,
+}
+
+/// A working-day calendar of weekend days and holidays.
+///
+/// Every day that is neither a weekend day nor a holiday is a business day.
+/// Saturday and Sunday are the weekend of a new calendar, it has no holidays.
+///
+/// # Examples
+/// ```rust
+/// use horae::{BusinessCalendar, Date};
+///
+/// let mut calendar = BusinessCalendar::new();
+/// calendar.add_holiday(Date::from_ymd(2024, 12, 25).unwrap());
+///
+/// let friday = Date::from_ymd(2024, 12, 20).unwrap();
+/// assert!(!calendar.is_business_day(Date::from_ymd(2024, 12, 21).unwrap()));
+/// assert_eq!(
+///     calendar.add_business_days(friday, 3).unwrap().to_string(),
+///     "2024-12-26"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BusinessCalendar {
+    /// Bit mask of the weekend days, Monday is bit 0
+    weekend: u8,
+    holidays: BTreeSet<Date>,
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> BusinessCalendar {
+        BusinessCalendar::new()
+    }
+}
+
+impl BusinessCalendar {
+    /// Instantiates a calendar with Saturday and Sunday as the weekend and no holidays.
+    #[must_use]
+    pub fn new() -> BusinessCalendar {
+        BusinessCalendar {
+            weekend: 0b110_0000,
+            holidays: BTreeSet::new(),
+        }
+    }
+
+    /// Sets the weekend days.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{BusinessCalendar, Date, Weekday};
+    ///
+    /// let mut calendar = BusinessCalendar::new();
+    /// calendar.with_weekend(&[Weekday::Friday, Weekday::Saturday]);
+    /// assert!(calendar.is_business_day(Date::from_ymd(2024, 3, 3).unwrap()));
+    /// assert!(!calendar.is_business_day(Date::from_ymd(2024, 3, 1).unwrap()));
+    /// ```
+    pub fn with_weekend(&mut self, weekdays: &[Weekday]) {
+        self.weekend = weekdays
+            .iter()
+            .fold(0, |mask, weekday| mask | 1 << *weekday as u8);
+    }
+
+    /// Returns the weekend days, starting on Monday.
+    #[must_use]
+    pub fn weekend(&self) -> Vec<Weekday> {
+        Weekday::ALL
+            .into_iter()
+            .filter(|weekday| self.weekend & 1 << *weekday as u8 != 0)
+            .collect()
+    }
+
+    /// Adds a holiday, returns `false` if it already was one.
+    pub fn add_holiday(&mut self, date: Date) -> bool {
+        self.holidays.insert(date)
+    }
+
+    /// Adds every supplied date as a holiday.
+    pub fn add_holidays<I: IntoIterator<Item = Date>>(&mut self, dates: I) {
+        self.holidays.extend(dates);
+    }
+
+    /// Removes a holiday, returns `false` if it was none.
+    pub fn remove_holiday(&mut self, date: Date) -> bool {
+        self.holidays.remove(&date)
+    }
+
+    /// Returns the holidays in order.
+    pub fn holidays(&self) -> impl Iterator<Item = Date> + '_ {
+        self.holidays.iter().copied()
+    }
+
+    /// Returns `true` if the date is one of the holidays.
+    #[must_use]
+    pub fn is_holiday(&self, date: Date) -> bool {
+        self.holidays.contains(&date)
+    }
+
+    /// Returns `true` if the date falls on a weekend day.
+    #[must_use]
+    pub fn is_weekend(&self, date: Date) -> bool {
+        self.weekend & 1 << date.weekday() as u8 != 0
+    }
+
+    /// Returns `true` if the date is neither a weekend day nor a holiday.
+    #[must_use]
+    pub fn is_business_day(&self, date: Date) -> bool {
+        !self.is_weekend(date) && !self.is_holiday(date)
+    }
+
+    /// Returns the date moved by the supplied number of business days, backwards if negative.
+    ///
+    /// `date` itself does not have to be a business day, adding zero returns it unchanged.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::OutOfRange` if there are not enough business days before the end,
+    /// or after the start, of the supported years.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{BusinessCalendar, Date};
+    ///
+    /// let calendar = BusinessCalendar::new();
+    /// let saturday = Date::from_ymd(2024, 3, 2).unwrap();
+    /// assert_eq!(calendar.add_business_days(saturday, 1).unwrap().to_string(), "2024-03-04");
+    /// assert_eq!(calendar.add_business_days(saturday, -1).unwrap().to_string(), "2024-03-01");
+    /// ```
+    pub fn add_business_days(&self, date: Date, days: i64) -> Result<Date, HoraeError> {
+        let step = days.signum();
+        let mut date = date;
+        for _ in 0..days.unsigned_abs() {
+            date = self.step(date, step)?;
+        }
+        Ok(date)
+    }
+
+    /// Returns the number of business days from `start` up to, but not including, `end`.
+    ///
+    /// Negative if `end` is before `start`, so that adding the result to a business day
+    /// `start` gives `end` if it is a business day.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{BusinessCalendar, Date};
+    ///
+    /// let calendar = BusinessCalendar::new();
+    /// let monday = Date::from_ymd(2024, 3, 4).unwrap();
+    /// let next_monday = Date::from_ymd(2024, 3, 11).unwrap();
+    /// assert_eq!(calendar.business_days_between(monday, next_monday), 5);
+    /// assert_eq!(calendar.business_days_between(next_monday, monday), -5);
+    /// ```
+    #[must_use]
+    pub fn business_days_between(&self, start: Date, end: Date) -> i64 {
+        if end < start {
+            return -self.business_days_between(end, start);
+        }
+        let first = start.days_since_epoch();
+        let days = end.days_since_epoch() - first;
+        let week_days = i64::from(7 - self.weekend.count_ones());
+        let mut count = days / 7 * week_days;
+        // The remaining days are fewer than a week, so every week day appears at most once
+        for day in first + days / 7 * 7..first + days {
+            if self.weekend & 1 << (week_day(day) - 1) == 0 {
+                count += 1;
+            }
+        }
+        let holidays = self
+            .holidays
+            .range(start..end)
+            .filter(|holiday| !self.is_weekend(**holiday))
+            .count();
+        count - holidays as i64
+    }
+
+    /// Returns the first business day after `date`.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::OutOfRange` if there is none before the end of the supported years.
+    pub fn next_business_day(&self, date: Date) -> Result<Date, HoraeError> {
+        self.step(date, 1)
+    }
+
+    /// Returns the last business day before `date`.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::OutOfRange` if there is none after the start of the supported years.
+    pub fn previous_business_day(&self, date: Date) -> Result<Date, HoraeError> {
+        self.step(date, -1)
+    }
+
+    /// Returns `date` if it is a business day, or moves it onto one by the convention.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::OutOfRange` if there is no business day to move to within the
+    /// supported years.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{BusinessCalendar, Date, RollConvention};
+    ///
+    /// let calendar = BusinessCalendar::new();
+    /// let saturday = Date::from_ymd(2024, 8, 31).unwrap();
+    /// assert_eq!(
+    ///     calendar.roll(saturday, RollConvention::Following).unwrap().to_string(),
+    ///     "2024-09-02"
+    /// );
+    /// assert_eq!(
+    ///     calendar.roll(saturday, RollConvention::ModifiedFollowing).unwrap().to_string(),
+    ///     "2024-08-30"
+    /// );
+    /// ```
+    pub fn roll(&self, date: Date, convention: RollConvention) -> Result<Date, HoraeError> {
+        if self.is_business_day(date) {
+            return Ok(date);
+        }
+        let (forward, modified) = match convention {
+            RollConvention::Following => (true, false),
+            RollConvention::ModifiedFollowing => (true, true),
+            RollConvention::Preceding => (false, false),
+            RollConvention::ModifiedPreceding => (false, true),
+        };
+        let rolled = if forward {
+            self.next_business_day(date)
+        } else {
+            self.previous_business_day(date)
+        };
+        match rolled {
+            Ok(rolled) if !modified || rolled.month() == date.month() => Ok(rolled),
+            // Leaving the month, or the supported years, moves the other way when modified
+            _ if modified && forward => self.previous_business_day(date),
+            _ if modified => self.next_business_day(date),
+            rolled => rolled,
+        }
+    }
+
+    /// Returns the next business day in the direction of `step`, 1 or -1.
+    fn step(&self, date: Date, step: i64) -> Result<Date, HoraeError> {
+        let last = days_from_civil(9999, 12, 31);
+        let first = days_from_civil(1, 1, 1);
+        let mut day = date.days_since_epoch();
+        loop {
+            day += step;
+            if !(first..=last).contains(&day) {
+                return Err(HoraeError::OutOfRange);
+            }
+            if self.weekend & 1 << (week_day(day) - 1) != 0 {
+                continue;
+            }
+            let date = Date::from_days_since_epoch(day)?;
+            if !self.is_holiday(date) {
+                return Ok(date);
+            }
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use crate::{
+    HoraeError, ParseError, Utc, Weekday,
+    cron::TimeMask,
+    date_time::common::{
+        SECONDS_IN_DAY, SECONDS_IN_HOUR, SECONDS_IN_MINUTE, civil_from_days, days_from_civil,
+        days_in_month_of_year, week_day,
+    },
+};
+
+mod parser;
+
+/// One item of a calendar event component, a value, a range `a..b`, or a repetition `a/n` or
+/// `a..b/n`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Item {
+    start: u16,
+    end: Option<u16>,
+    step: Option<u16>,
+}
+
+impl Item {
+    fn matches(&self, value: u16) -> bool {
+        let end = match (self.end, self.step) {
+            (Some(end), _) => end,
+            (None, Some(_)) => u16::MAX,
+            (None, None) => self.start,
+        };
+        (self.start..=end).contains(&value)
+            && self
+                .step
+                .is_none_or(|step| (value - self.start).is_multiple_of(step))
+    }
+
+    /// Matches a day counted from the end of the month, where 1 is the last day and a
+    /// repetition counts towards the end of the month.
+    fn matches_from_end(&self, value: u16) -> bool {
+        match self.step {
+            Some(step) => value <= self.start && (self.start - value).is_multiple_of(step),
+            None => self.matches(value),
+        }
+    }
+}
+
+/// A systemd calendar event, like `Mon..Fri *-*-* 09:00:00` or `quarterly`, as used by the
+/// `OnCalendar=` setting of timer units.
+///
+/// An event has an optional list of week days, a date of `year-month-day`, a time of
+/// `hour:minute:second` and an optional trailing zone, `UTC` or an offset like `+01:00`.
+/// Date and time components take `*`, values, ranges like `1..5`, repetitions like `0/15` and
+/// lists of these separated by `,`. A `~` instead of the last `-` of the date counts the day
+/// from the end of the month, `*-02~01` is the last day of February.
+/// A left out date is `*-*-*`, a left out time is `00:00:00`, and left out seconds are `00`.
+///
+/// `Display` writes the normalized form, as `systemd-analyze calendar` does.
+///
+/// # Examples
+/// ```rust
+/// use horae::{CalendarEvent, Utc};
+///
+/// let event = CalendarEvent::parse("mon..fri 9:00").unwrap();
+/// assert_eq!(event.to_string(), "Mon..Fri *-*-* 09:00:00");
+///
+/// let friday = Utc::from_ymd_hms(2024, 3, 1, 12, 0, 0);
+/// assert_eq!(
+///     event.next_elapse(friday),
+///     Some(Utc::from_ymd_hms(2024, 3, 4, 9, 0, 0))
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CalendarEvent {
+    /// Bit mask of the week days, Monday is bit 0, no bits set matches every day
+    week_days: u8,
+    // Every component is a list of items, an empty list is `*`
+    years: Vec<Item>,
+    months: Vec<Item>,
+    days: Vec<Item>,
+    /// Whether the days count from the end of the month
+    days_from_end: bool,
+    hours: Vec<Item>,
+    minutes: Vec<Item>,
+    seconds: Vec<Item>,
+    time: TimeMask,
+    /// UTC offset in seconds of the trailing zone
+    zone: Option<i32>,
+}
+
+impl CalendarEvent {
+    /// Parses a systemd calendar event, or one of the shorthands `minutely`, `hourly`,
+    /// `daily`, `weekly`, `monthly`, `quarterly`, `semiannually`, `yearly` and `annually`.
+    ///
+    /// Week days may be written in full or shortened to three letters, in any case.
+    /// Years, months and days are 1-9999, 1-12 and 1-31.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` naming the component that could not be read, and where.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{CalendarEvent, ParseComponent};
+    ///
+    /// let quarterly = CalendarEvent::parse("quarterly").unwrap();
+    /// assert_eq!(quarterly.to_string(), "*-01,04,07,10-01 00:00:00");
+    ///
+    /// let error = CalendarEvent::parse("*-13-01").unwrap_err();
+    /// assert_eq!(error.component(), ParseComponent::Month);
+    /// assert_eq!(error.position(), 2);
+    /// ```
+    pub fn parse(s: &str) -> Result<CalendarEvent, ParseError> {
+        parser::parse_calendar_event(s).map_err(ParseError::from)
+    }
+
+    /// Returns the first time the event elapses strictly after `after`.
+    ///
+    /// The event is evaluated in the UTC offset of its zone, or in the UTC offset of `after`
+    /// if it has none. The returned `Utc` is in that offset as well.
+    ///
+    /// # Returns
+    /// `None` if the event does not elapse again before the end of the supported years.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{CalendarEvent, Utc};
+    ///
+    /// let event = CalendarEvent::parse("*-*-01 06:00 +02:00").unwrap();
+    /// let next = event.next_elapse(Utc::from_ymd_hms(2024, 1, 15, 0, 0, 0)).unwrap();
+    /// assert_eq!(next, Utc::from_ymd_hms(2024, 2, 1, 4, 0, 0));
+    /// assert_eq!(next.get_utc_offset(), 2.0);
+    /// ```
+    #[must_use]
+    pub fn next_elapse(&self, after: Utc) -> Option<Utc> {
+        let offset = self.zone.unwrap_or_else(|| after.utc_offset_seconds());
+        let from = after.unix_timestamp_secs() + i64::from(offset) + 1;
+        let mut day = from.div_euclid(SECONDS_IN_DAY);
+        let mut second_of_day = from.rem_euclid(SECONDS_IN_DAY);
+        let last_day = days_from_civil(9999, 12, 31);
+        while day <= last_day {
+            let (year, month, day_of_month) = civil_from_days(day);
+            if !any_matches(&self.years, year as u16) {
+                day = days_from_civil(year + 1, 1, 1);
+                second_of_day = 0;
+                continue;
+            }
+            if !any_matches(&self.months, u16::from(month)) {
+                day = if month == 12 {
+                    days_from_civil(year + 1, 1, 1)
+                } else {
+                    days_from_civil(year, month + 1, 1)
+                };
+                second_of_day = 0;
+                continue;
+            }
+            if self.day_matches(day, year, month, day_of_month)
+                && let Some(second) = self.time.next(second_of_day)
+            {
+                return Some(Utc::from_local_seconds(
+                    day * SECONDS_IN_DAY + second,
+                    0,
+                    offset,
+                ));
+            }
+            day += 1;
+            second_of_day = 0;
+        }
+        None
+    }
+
+    fn day_matches(&self, day: i64, year: i64, month: u8, day_of_month: u8) -> bool {
+        let week_day_bit = 1 << (week_day(day) - 1);
+        if self.week_days != 0 && self.week_days & week_day_bit == 0 {
+            return false;
+        }
+        if self.days.is_empty() {
+            return true;
+        }
+        if self.days_from_end {
+            let from_end =
+                u16::from(days_in_month_of_year(year as u16, month)) - u16::from(day_of_month) + 1;
+            self.days.iter().any(|item| item.matches_from_end(from_end))
+        } else {
+            any_matches(&self.days, u16::from(day_of_month))
+        }
+    }
+}
+
+impl std::str::FromStr for CalendarEvent {
+    type Err = HoraeError;
+
+    fn from_str(s: &str) -> Result<CalendarEvent, HoraeError> {
+        parser::parse_calendar_event(s).map_err(HoraeError::from)
+    }
+}
+
+// Display implementation
+
+impl std::fmt::Display for CalendarEvent {
+    /// Formats the normalized form of the event, with every component written out.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.week_days != 0 {
+            // Runs of three or more consecutive week days are written as a range
+            let mut names = Vec::new();
+            let mut day = 0;
+            while day < 7 {
+                if self.week_days & 1 << day == 0 {
+                    day += 1;
+                    continue;
+                }
+                let mut last = day;
+                while last < 6 && self.week_days & 1 << (last + 1) != 0 {
+                    last += 1;
+                }
+                if last - day >= 2 {
+                    names.push(format!(
+                        "{}..{}",
+                        Weekday::ALL[day].short_name(),
+                        Weekday::ALL[last].short_name()
+                    ));
+                } else {
+                    names.extend(
+                        Weekday::ALL[day..=last]
+                            .iter()
+                            .map(|weekday| weekday.short_name().to_string()),
+                    );
+                }
+                day = last + 1;
+            }
+            write!(f, "{}", names.join(","))?;
+            write!(f, " ")?;
+        }
+        write_component(f, &self.years, 4)?;
+        write!(f, "-")?;
+        write_component(f, &self.months, 2)?;
+        write!(f, "{}", if self.days_from_end { '~' } else { '-' })?;
+        write_component(f, &self.days, 2)?;
+        write!(f, " ")?;
+        write_component(f, &self.hours, 2)?;
+        write!(f, ":")?;
+        write_component(f, &self.minutes, 2)?;
+        write!(f, ":")?;
+        write_component(f, &self.seconds, 2)?;
+        match self.zone {
+            Some(0) => write!(f, " UTC")?,
+            Some(offset) => {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.unsigned_abs();
+                write!(
+                    f,
+                    " {sign}{:02}:{:02}",
+                    offset / SECONDS_IN_HOUR as u32,
+                    offset % SECONDS_IN_HOUR as u32 / SECONDS_IN_MINUTE as u32
+                )?;
+            }
+            None => {}
+        }
+        Ok(())
+    }
+}
+
+fn write_component(f: &mut std::fmt::Formatter, items: &[Item], width: usize) -> std::fmt::Result {
+    if items.is_empty() {
+        return write!(f, "*");
+    }
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            write!(f, ",")?;
+        }
+        write!(f, "{:0width$}", item.start)?;
+        if let Some(end) = item.end {
+            write!(f, "..{end:0width$}")?;
+        }
+        if let Some(step) = item.step {
+            write!(f, "/{step}")?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns `true` if the component is `*` or any of its items matches.
+fn any_matches(items: &[Item], value: u16) -> bool {
+    items.is_empty() || items.iter().any(|item| item.matches(value))
+}
@@ -0,0 +1,377 @@
+use crate::{
+    ParseComponent, Weekday,
+    cron::TimeMask,
+    date_time::{
+        common::{SECONDS_IN_HOUR, SECONDS_IN_MINUTE},
+        parser::{Cursor, ParseFailure},
+    },
+};
+
+use super::{CalendarEvent, Item};
+
+/// The shorthands and the events they stand for.
+const SHORTHANDS: [(&str, &str); 9] = [
+    ("minutely", "*-*-* *:*:00"),
+    ("hourly", "*-*-* *:00:00"),
+    ("daily", "*-*-* 00:00:00"),
+    ("weekly", "Mon *-*-* 00:00:00"),
+    ("monthly", "*-*-01 00:00:00"),
+    ("quarterly", "*-01,04,07,10-01 00:00:00"),
+    ("semiannually", "*-01,07-01 00:00:00"),
+    ("yearly", "*-01-01 00:00:00"),
+    ("annually", "*-01-01 00:00:00"),
+];
+
+/// The allowed values of one date or time component.
+struct Spec {
+    component: ParseComponent,
+    min: u16,
+    max: u16,
+    expected: &'static str,
+}
+
+const YEAR: Spec = Spec {
+    component: ParseComponent::Year,
+    min: 1,
+    max: 9999,
+    expected: "'*' or years of 1-9999",
+};
+const MONTH: Spec = Spec {
+    component: ParseComponent::Month,
+    min: 1,
+    max: 12,
+    expected: "'*' or months of 1-12",
+};
+const DAY: Spec = Spec {
+    component: ParseComponent::Day,
+    min: 1,
+    max: 31,
+    expected: "'*' or days of 1-31",
+};
+const HOUR: Spec = Spec {
+    component: ParseComponent::Hour,
+    min: 0,
+    max: 23,
+    expected: "'*' or hours of 0-23",
+};
+const MINUTE: Spec = Spec {
+    component: ParseComponent::Minute,
+    min: 0,
+    max: 59,
+    expected: "'*' or minutes of 0-59",
+};
+const SECOND: Spec = Spec {
+    component: ParseComponent::Second,
+    min: 0,
+    max: 59,
+    expected: "'*' or seconds of 0-59",
+};
+
+/// Parses a systemd calendar event of `[WEEKDAYS] [DATE] [TIME] [ZONE]`, or a shorthand.
+pub fn parse_calendar_event(input: &str) -> Result<CalendarEvent, ParseFailure> {
+    let cursor = Cursor::new(input);
+    let tokens = tokens(input);
+    let mut event = CalendarEvent {
+        week_days: 0,
+        years: Vec::new(),
+        months: Vec::new(),
+        days: Vec::new(),
+        days_from_end: false,
+        hours: vec![value(0)],
+        minutes: vec![value(0)],
+        seconds: vec![value(0)],
+        time: TimeMask {
+            hours: 0,
+            minutes: 0,
+            seconds: 0,
+        },
+        zone: None,
+    };
+
+    let mut next = 0;
+    let shorthand = tokens.first().and_then(|(start, end)| {
+        SHORTHANDS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(&input[*start..*end]))
+    });
+    if let Some((_, expanded)) = shorthand {
+        event = parse_calendar_event(expanded)?;
+        next = 1;
+    } else {
+        if let Some((start, end)) = tokens.get(next).copied()
+            && input.as_bytes()[start].is_ascii_alphabetic()
+            && !input[start..end].eq_ignore_ascii_case("UTC")
+        {
+            event.week_days = parse_week_days(input, start, end)?;
+            next += 1;
+        }
+        if let Some((start, end)) = tokens.get(next).copied() {
+            let token = &input[start..end];
+            if token.contains(['-', '~']) && !token.contains(':') && !token.starts_with(['+', '-'])
+            {
+                parse_date(input, start, end, &mut event)?;
+                next += 1;
+            }
+        }
+        if let Some((start, end)) = tokens.get(next).copied() {
+            let token = &input[start..end];
+            if token.contains(':') && !token.starts_with(['+', '-']) {
+                parse_time(input, start, end, &mut event)?;
+                next += 1;
+            }
+        }
+        if next == 0 {
+            let position = tokens.first().map_or(input.len(), |(start, _)| *start);
+            return Err(cursor.error(
+                ParseComponent::Field,
+                position,
+                "week days, a date, a time or a shorthand like daily",
+            ));
+        }
+    }
+
+    if let Some((start, end)) = tokens.get(next).copied() {
+        event.zone = Some(parse_zone(input, start, end)?);
+        next += 1;
+    }
+    if let Some((start, _)) = tokens.get(next) {
+        return Err(cursor.error(ParseComponent::Field, *start, "the end of the event"));
+    }
+
+    event.time = TimeMask {
+        hours: mask(&event.hours, 23),
+        minutes: mask(&event.minutes, 59),
+        seconds: mask(&event.seconds, 59),
+    };
+    Ok(event)
+}
+
+/// Returns the start and end of every whitespace separated token.
+fn tokens(input: &str) -> Vec<(usize, usize)> {
+    let bytes = input.as_bytes();
+    let mut out = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        if bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+            continue;
+        }
+        let start = pos;
+        while pos < bytes.len() && !bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        out.push((start, pos));
+    }
+    out
+}
+
+/// Parses a `,` separated list of week days and week day ranges like `Mon..Fri` into a bit
+/// mask, Monday is bit 0.
+fn parse_week_days(input: &str, start: usize, end: usize) -> Result<u8, ParseFailure> {
+    let cursor = Cursor::new(input);
+    let mut mask = 0;
+    let mut pos = start;
+    for item in input[start..end].split(',') {
+        let error = |position| {
+            cursor.error(
+                ParseComponent::WeekDay,
+                position,
+                "week days like Mon or Monday, or ranges like Mon..Fri",
+            )
+        };
+        let (first, last) = match item.split_once("..") {
+            Some((first, last)) => {
+                let first = week_day(first).ok_or_else(|| error(pos))?;
+                let last_pos = pos + item.len() - last.len();
+                let last = week_day(last)
+                    .filter(|last| *last >= first)
+                    .ok_or_else(|| error(last_pos))?;
+                (first, last)
+            }
+            None => {
+                let day = week_day(item).ok_or_else(|| error(pos))?;
+                (day, day)
+            }
+        };
+        for day in first..=last {
+            mask |= 1 << day;
+        }
+        pos += item.len() + 1;
+    }
+    Ok(mask)
+}
+
+/// Reads a week day name, in full or its first three letters, into Monday as 0.
+fn week_day(name: &str) -> Option<u8> {
+    Weekday::from_name(name).map(|weekday| weekday as u8)
+}
+
+/// Parses a date of `YEAR-MONTH-DAY` or `MONTH-DAY`, where the last `-` may be a `~`.
+fn parse_date(
+    input: &str,
+    start: usize,
+    end: usize,
+    event: &mut CalendarEvent,
+) -> Result<(), ParseFailure> {
+    let cursor = Cursor::new(input);
+    let token = &input[start..end];
+    let day_separator = token.rfind(['-', '~']).unwrap_or(0);
+    event.days_from_end = token.as_bytes()[day_separator] == b'~';
+    let day_start = start + day_separator + 1;
+    let (year, month) = match token[..day_separator].split_once('-') {
+        Some((year, _)) => (Some((start, start + year.len())), start + year.len() + 1),
+        None => (None, start),
+    };
+    if let Some(extra) = input[month..start + day_separator].find(['-', '~']) {
+        return Err(cursor.error(
+            ParseComponent::DateSeparator,
+            month + extra,
+            "a date of YEAR-MONTH-DAY or MONTH-DAY",
+        ));
+    }
+    if let Some((year_start, year_end)) = year {
+        event.years = parse_component(input, year_start, year_end, &YEAR)?;
+    }
+    event.months = parse_component(input, month, start + day_separator, &MONTH)?;
+    event.days = parse_component(input, day_start, end, &DAY)?;
+    Ok(())
+}
+
+/// Parses a time of `HOUR:MINUTE` or `HOUR:MINUTE:SECOND`.
+fn parse_time(
+    input: &str,
+    start: usize,
+    end: usize,
+    event: &mut CalendarEvent,
+) -> Result<(), ParseFailure> {
+    let cursor = Cursor::new(input);
+    let mut parts = Vec::new();
+    let mut pos = start;
+    for part in input[start..end].split(':') {
+        parts.push((pos, pos + part.len()));
+        pos += part.len() + 1;
+    }
+    if let Some((start, _)) = parts.get(3) {
+        return Err(cursor.error(
+            ParseComponent::TimeSeparator,
+            start - 1,
+            "a time of HOUR:MINUTE or HOUR:MINUTE:SECOND",
+        ));
+    }
+    event.hours = parse_component(input, parts[0].0, parts[0].1, &HOUR)?;
+    event.minutes = parse_component(input, parts[1].0, parts[1].1, &MINUTE)?;
+    if let Some((start, end)) = parts.get(2) {
+        event.seconds = parse_component(input, *start, *end, &SECOND)?;
+    }
+    Ok(())
+}
+
+/// Parses `*` or a `,` separated list of values, ranges and repetitions into sorted items.
+fn parse_component(
+    input: &str,
+    start: usize,
+    end: usize,
+    spec: &Spec,
+) -> Result<Vec<Item>, ParseFailure> {
+    let cursor = Cursor::new(input);
+    let error = |position| cursor.error(spec.component, position, spec.expected);
+    let text = &input[start..end];
+    if text == "*" {
+        return Ok(Vec::new());
+    }
+
+    let mut items = Vec::new();
+    let mut pos = start;
+    for item in text.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => {
+                let step_pos = pos + range.len() + 1;
+                let step = number(step).filter(|step| *step > 0).ok_or_else(|| {
+                    cursor.error(spec.component, step_pos, "a repetition of at least 1")
+                })?;
+                (range, Some(step))
+            }
+            None => (item, None),
+        };
+        let parsed = if range == "*" && step.is_some() {
+            Item {
+                start: spec.min,
+                end: None,
+                step,
+            }
+        } else if let Some((first, last)) = range.split_once("..") {
+            let first = number(first)
+                .filter(|value| (spec.min..=spec.max).contains(value))
+                .ok_or_else(|| error(pos))?;
+            let last = number(last)
+                .filter(|value| (first..=spec.max).contains(value))
+                .ok_or_else(|| error(pos + range.find("..").unwrap_or(0) + 2))?;
+            Item {
+                start: first,
+                end: Some(last),
+                step,
+            }
+        } else {
+            let first = number(range)
+                .filter(|value| (spec.min..=spec.max).contains(value))
+                .ok_or_else(|| error(pos))?;
+            Item {
+                start: first,
+                end: None,
+                step,
+            }
+        };
+        items.push(parsed);
+        pos += item.len() + 1;
+    }
+    items.sort_unstable();
+    items.dedup();
+    Ok(items)
+}
+
+/// Parses `UTC` or an offset of `+HH:MM`, `+HHMM` or `+HH` into seconds.
+fn parse_zone(input: &str, start: usize, end: usize) -> Result<i32, ParseFailure> {
+    let expected = "'UTC' or an offset like +01:00";
+    if input[start..end].eq_ignore_ascii_case("UTC") {
+        return Ok(0);
+    }
+    let mut cursor = Cursor::with_end(input, end);
+    cursor.pos = start;
+    let sign = match cursor.expect(b"+-", ParseComponent::Offset, expected)? {
+        b'-' => -1,
+        _ => 1,
+    };
+    let hours = cursor.digits(2, ParseComponent::Offset, expected)? as i32;
+    let mut minutes = 0;
+    if !cursor.is_done() {
+        cursor.eat(b":");
+        minutes = cursor.digits(2, ParseComponent::Offset, expected)? as i32;
+    }
+    if !cursor.is_done() || hours >= 24 || minutes >= 60 {
+        return Err(cursor.error(ParseComponent::Offset, start, expected));
+    }
+    Ok(sign * (hours * SECONDS_IN_HOUR as i32 + minutes * SECONDS_IN_MINUTE as i32))
+}
+
+/// Reads a decimal number of up to 4 digits.
+fn number(text: &str) -> Option<u16> {
+    if text.is_empty() || text.len() > 4 || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    text.parse().ok()
+}
+
+fn value(start: u16) -> Item {
+    Item {
+        start,
+        end: None,
+        step: None,
+    }
+}
+
+/// Returns a bit mask of the values up to `max` the items match, every value for `*`.
+fn mask(items: &[Item], max: u16) -> u64 {
+    (0..=max)
+        .filter(|value| items.is_empty() || items.iter().any(|item| item.matches(*value)))
+        .fold(0, |mask, value| mask | 1 << value)
+}
@@ -0,0 +1,378 @@
+use std::iter::FusedIterator;
+
+use crate::{
+    HoraeError, ParseError, TimeZone, Utc,
+    date_time::common::{
+        SECONDS_IN_DAY, SECONDS_IN_HOUR, SECONDS_IN_MINUTE, civil_from_days, days_from_civil,
+        days_in_month_of_year, validate_utc_offset, week_day,
+    },
+};
+
+mod parser;
+
+/// A cron schedule, parsed from a 5 field (`minute hour day month week-day`) or 6 field
+/// (`second minute hour day month week-day`) cron expression.
+///
+/// Fields take `*`, single values, ranges like `1-5`, steps like `*/15` or `10-40/10`, and
+/// lists of these separated by `,`. Months and week days may be written as names like `JAN`
+/// or `MON`, Sunday is both 0 and 7. The day field also takes `L` for the last day of the
+/// month, `LW` for its last weekday and `15W` for the weekday nearest to the 15th. The week day
+/// field also takes `5L` for the last Friday of the month and `FRI#3` for the third Friday.
+/// `?` is read as `*` in both day fields.
+///
+/// If both the day and the week day field are restricted, a day matching either of them
+/// matches, as in Vixie cron. A field starting with `*` or `?` is not restricted.
+///
+/// Schedules are evaluated on the local date and time in their UTC offset, UTC by default.
+///
+/// # Examples
+/// ```rust
+/// use horae::{CronSchedule, Utc};
+///
+/// let schedule = CronSchedule::parse("30 9 * * MON-FRI").unwrap();
+/// let friday = Utc::from_ymd_hms(2024, 3, 1, 12, 0, 0);
+/// assert_eq!(
+///     schedule.next_after(friday),
+///     Some(Utc::from_ymd_hms(2024, 3, 4, 9, 30, 0))
+/// );
+/// assert_eq!(
+///     schedule.prev_before(friday),
+///     Some(Utc::from_ymd_hms(2024, 3, 1, 9, 30, 0))
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CronSchedule {
+    time: TimeMask,
+    // Bit masks of the matching values
+    days: u64,
+    months: u64,
+    /// Sunday is 0
+    week_days: u64,
+    last_day: bool,
+    last_weekday: bool,
+    nearest_weekdays: u64,
+    /// Week days, Sunday is 0, with their occurrence in the month
+    nth_week_days: Vec<(u8, u8)>,
+    last_week_days: u64,
+    any_day: bool,
+    any_week_day: bool,
+    /// UTC offset in seconds
+    offset: i32,
+    expression: String,
+}
+
+impl CronSchedule {
+    /// Parses a cron expression of 5 or 6 fields, separated by whitespace.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` naming the field that could not be read, and where.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{CronSchedule, ParseComponent};
+    ///
+    /// let error = CronSchedule::parse("0 24 * * *").unwrap_err();
+    /// assert_eq!(error.component(), ParseComponent::Hour);
+    /// assert_eq!(error.position(), 2);
+    /// ```
+    pub fn parse(s: &str) -> Result<CronSchedule, ParseError> {
+        parser::parse_cron(s).map_err(ParseError::from)
+    }
+
+    /// Mutates the schedule to be evaluated in the specified timezone.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{CronSchedule, TimeZone, Utc};
+    ///
+    /// let mut schedule = CronSchedule::parse("0 9 * * *").unwrap();
+    /// schedule.with_timezone(TimeZone::CentralEuropeanTime);
+    /// let next = schedule.next_after(Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0)).unwrap();
+    /// assert_eq!(next, Utc::from_ymd_hms(2024, 1, 1, 8, 0, 0));
+    /// assert_eq!(next.time().hour(), 9);
+    /// ```
+    pub fn with_timezone<T: Into<TimeZone>>(&mut self, timezone: T) {
+        self.offset = (timezone.into().get_utc_offset() * SECONDS_IN_HOUR as f64).round() as i32;
+    }
+
+    /// Mutates the schedule to be evaluated in the specified UTC offset in hours.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::InvalidUtcOffset` if the offset is not within -24 to +24 hours,
+    /// leaving the schedule unchanged.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{CronSchedule, HoraeError};
+    ///
+    /// let mut schedule = CronSchedule::parse("0 9 * * *").unwrap();
+    /// assert_eq!(schedule.with_utc_offset(-5.0), Ok(()));
+    /// assert_eq!(schedule.with_utc_offset(1e12), Err(HoraeError::InvalidUtcOffset(1e12)));
+    /// assert_eq!(schedule.get_utc_offset(), -5.0);
+    /// ```
+    pub fn with_utc_offset(&mut self, offset: f64) -> Result<(), HoraeError> {
+        validate_utc_offset(offset)?;
+        self.offset = (offset * SECONDS_IN_HOUR as f64).round() as i32;
+        Ok(())
+    }
+
+    /// Returns the UTC offset in hours the schedule is evaluated in.
+    #[must_use]
+    pub fn get_utc_offset(&self) -> f64 {
+        f64::from(self.offset) / SECONDS_IN_HOUR as f64
+    }
+
+    /// Returns the first firing strictly after `after`, in the UTC offset of the schedule.
+    ///
+    /// # Returns
+    /// `None` if the schedule does not fire again before the end of the supported years.
+    #[must_use]
+    pub fn next_after(&self, after: Utc) -> Option<Utc> {
+        let from = after.unix_timestamp_secs() + i64::from(self.offset) + 1;
+        let mut day = from.div_euclid(SECONDS_IN_DAY);
+        let mut second_of_day = from.rem_euclid(SECONDS_IN_DAY);
+        let last_day = days_from_civil(9999, 12, 31);
+        while day <= last_day {
+            let (year, month, day_of_month) = civil_from_days(day);
+            if !bit(self.months, month) {
+                day = if month == 12 {
+                    days_from_civil(year + 1, 1, 1)
+                } else {
+                    days_from_civil(year, month + 1, 1)
+                };
+                second_of_day = 0;
+                continue;
+            }
+            if self.day_matches(day, year, month, day_of_month)
+                && let Some(second) = self.time.next(second_of_day)
+            {
+                return Some(self.at(day, second));
+            }
+            day += 1;
+            second_of_day = 0;
+        }
+        None
+    }
+
+    /// Returns the last firing strictly before `before`, in the UTC offset of the schedule.
+    ///
+    /// # Returns
+    /// `None` if the schedule never fired since the start of the supported years.
+    #[must_use]
+    pub fn prev_before(&self, before: Utc) -> Option<Utc> {
+        let mut from = before.unix_timestamp_secs() + i64::from(self.offset);
+        if before.subsec_nanos() == 0 {
+            from -= 1;
+        }
+        let mut day = from.div_euclid(SECONDS_IN_DAY);
+        let mut second_of_day = from.rem_euclid(SECONDS_IN_DAY);
+        let first_day = days_from_civil(1, 1, 1);
+        while day >= first_day {
+            let (year, month, day_of_month) = civil_from_days(day);
+            if !bit(self.months, month) {
+                day = days_from_civil(year, month, 1) - 1;
+                second_of_day = SECONDS_IN_DAY - 1;
+                continue;
+            }
+            if self.day_matches(day, year, month, day_of_month)
+                && let Some(second) = self.time.prev(second_of_day)
+            {
+                return Some(self.at(day, second));
+            }
+            day -= 1;
+            second_of_day = SECONDS_IN_DAY - 1;
+        }
+        None
+    }
+
+    /// Returns an iterator over the firings strictly after `after`, in order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{CronSchedule, Utc};
+    ///
+    /// let schedule = CronSchedule::parse("0 0 L * *").unwrap();
+    /// let start = Utc::from_ymd_hms(2024, 1, 15, 0, 0, 0);
+    /// let dates: Vec<String> = schedule
+    ///     .upcoming(start)
+    ///     .take(3)
+    ///     .map(|utc| utc.date().to_string())
+    ///     .collect();
+    /// assert_eq!(dates, ["2024-01-31", "2024-02-29", "2024-03-31"]);
+    /// ```
+    #[must_use]
+    pub fn upcoming(&self, after: Utc) -> CronIter {
+        CronIter {
+            schedule: self.clone(),
+            last: Some(after),
+        }
+    }
+
+    fn at(&self, day: i64, second_of_day: i64) -> Utc {
+        Utc::from_local_seconds(day * SECONDS_IN_DAY + second_of_day, 0, self.offset)
+    }
+
+    /// Returns `true` if the schedule fires on the day.
+    fn day_matches(&self, day: i64, year: i64, month: u8, day_of_month: u8) -> bool {
+        let days_in_month = days_in_month_of_year(year as u16, month);
+        let weekday = week_day(day) % 7;
+
+        let first_weekday = (week_day(day - i64::from(day_of_month) + 1) % 7) as i64;
+        let weekday_at = |dom: u8| ((first_weekday + i64::from(dom) - 1) % 7) as u8;
+        let nearest_weekday = |dom: u8| match weekday_at(dom) {
+            6 if dom == 1 => dom + 2,
+            6 => dom - 1,
+            0 if dom == days_in_month => dom - 2,
+            0 => dom + 1,
+            _ => dom,
+        };
+
+        let day_match = bit(self.days, day_of_month)
+            || (self.last_day && day_of_month == days_in_month)
+            || (self.last_weekday
+                && day_of_month == nearest_last_weekday(days_in_month, weekday_at))
+            || (1..=days_in_month)
+                .filter(|dom| bit(self.nearest_weekdays, *dom))
+                .any(|dom| nearest_weekday(dom) == day_of_month);
+        let week_day_match = bit(self.week_days, weekday)
+            || self
+                .nth_week_days
+                .iter()
+                .any(|(wanted, nth)| *wanted == weekday && (day_of_month - 1) / 7 + 1 == *nth)
+            || (bit(self.last_week_days, weekday) && day_of_month + 7 > days_in_month);
+
+        if self.any_day || self.any_week_day {
+            day_match && week_day_match
+        } else {
+            day_match || week_day_match
+        }
+    }
+}
+
+impl std::str::FromStr for CronSchedule {
+    type Err = HoraeError;
+
+    fn from_str(s: &str) -> Result<CronSchedule, HoraeError> {
+        parser::parse_cron(s).map_err(HoraeError::from)
+    }
+}
+
+// Display implementation
+
+impl std::fmt::Display for CronSchedule {
+    /// Formats the cron expression as parsed, with its fields separated by single spaces.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.expression)
+    }
+}
+
+/// An iterator over the firings of a `CronSchedule`.
+///
+/// Created with `CronSchedule::upcoming`.
+#[derive(Debug, Clone)]
+pub struct CronIter {
+    schedule: CronSchedule,
+    last: Option<Utc>,
+}
+
+impl Iterator for CronIter {
+    type Item = Utc;
+
+    fn next(&mut self) -> Option<Utc> {
+        self.last = self.schedule.next_after(self.last?);
+        self.last
+    }
+}
+
+impl FusedIterator for CronIter {}
+
+/// Bit masks of the matching hours, minutes and seconds of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct TimeMask {
+    pub(crate) hours: u64,
+    pub(crate) minutes: u64,
+    pub(crate) seconds: u64,
+}
+
+impl TimeMask {
+    /// Returns the first matching second of the day at or after `second_of_day`.
+    pub(crate) fn next(&self, second_of_day: i64) -> Option<i64> {
+        let (hour, minute, second) = split(second_of_day);
+        let first_minute = next_bit(self.minutes, 0)?;
+        let first_second = next_bit(self.seconds, 0)?;
+        if bit(self.hours, hour) {
+            if bit(self.minutes, minute)
+                && let Some(second) = next_bit(self.seconds, second)
+            {
+                return Some(join(hour, minute, second));
+            }
+            if let Some(minute) = next_bit(self.minutes, minute + 1) {
+                return Some(join(hour, minute, first_second));
+            }
+        }
+        next_bit(self.hours, hour + 1).map(|hour| join(hour, first_minute, first_second))
+    }
+
+    /// Returns the last matching second of the day at or before `second_of_day`.
+    pub(crate) fn prev(&self, second_of_day: i64) -> Option<i64> {
+        let (hour, minute, second) = split(second_of_day);
+        let last_minute = prev_bit(self.minutes, 59)?;
+        let last_second = prev_bit(self.seconds, 59)?;
+        if bit(self.hours, hour) {
+            if bit(self.minutes, minute)
+                && let Some(second) = prev_bit(self.seconds, second)
+            {
+                return Some(join(hour, minute, second));
+            }
+            if let Some(minute) = minute
+                .checked_sub(1)
+                .and_then(|m| prev_bit(self.minutes, m))
+            {
+                return Some(join(hour, minute, last_second));
+            }
+        }
+        hour.checked_sub(1)
+            .and_then(|h| prev_bit(self.hours, h))
+            .map(|hour| join(hour, last_minute, last_second))
+    }
+}
+
+/// Returns the day of the month of the last weekday of a month.
+fn nearest_last_weekday(days_in_month: u8, weekday_at: impl Fn(u8) -> u8) -> u8 {
+    match weekday_at(days_in_month) {
+        6 => days_in_month - 1,
+        0 => days_in_month - 2,
+        _ => days_in_month,
+    }
+}
+
+fn bit(mask: u64, value: u8) -> bool {
+    value < 64 && mask >> value & 1 == 1
+}
+
+/// Returns the lowest set bit at or above `from`.
+fn next_bit(mask: u64, from: u8) -> Option<u8> {
+    if from >= 64 {
+        return None;
+    }
+    let mask = mask & (u64::MAX << from);
+    (mask != 0).then(|| mask.trailing_zeros() as u8)
+}
+
+/// Returns the highest set bit at or below `to`.
+fn prev_bit(mask: u64, to: u8) -> Option<u8> {
+    let mask = mask & (u64::MAX >> (63 - to.min(63)));
+    (mask != 0).then(|| 63 - mask.leading_zeros() as u8)
+}
+
+fn split(second_of_day: i64) -> (u8, u8, u8) {
+    (
+        (second_of_day / SECONDS_IN_HOUR) as u8,
+        (second_of_day % SECONDS_IN_HOUR / SECONDS_IN_MINUTE) as u8,
+        (second_of_day % SECONDS_IN_MINUTE) as u8,
+    )
+}
+
+fn join(hour: u8, minute: u8, second: u8) -> i64 {
+    i64::from(hour) * SECONDS_IN_HOUR + i64::from(minute) * SECONDS_IN_MINUTE + i64::from(second)
+}
@@ -0,0 +1,258 @@
+use crate::{
+    ParseComponent,
+    date_time::parser::{Cursor, ParseFailure},
+};
+
+use super::{CronSchedule, TimeMask};
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const WEEK_DAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// The allowed values of one cron field.
+struct Spec {
+    component: ParseComponent,
+    min: u8,
+    max: u8,
+    /// Names of the values starting at `min`
+    names: &'static [&'static str],
+    expected: &'static str,
+}
+
+const SECOND: Spec = Spec {
+    component: ParseComponent::Second,
+    min: 0,
+    max: 59,
+    names: &[],
+    expected: "a second of 0-59",
+};
+const MINUTE: Spec = Spec {
+    component: ParseComponent::Minute,
+    min: 0,
+    max: 59,
+    names: &[],
+    expected: "a minute of 0-59",
+};
+const HOUR: Spec = Spec {
+    component: ParseComponent::Hour,
+    min: 0,
+    max: 23,
+    names: &[],
+    expected: "an hour of 0-23",
+};
+const DAY: Spec = Spec {
+    component: ParseComponent::Day,
+    min: 1,
+    max: 31,
+    names: &[],
+    expected: "a day of 1-31, 'L', 'LW' or a day followed by 'W'",
+};
+const MONTH: Spec = Spec {
+    component: ParseComponent::Month,
+    min: 1,
+    max: 12,
+    names: &MONTH_NAMES,
+    expected: "a month of 1-12 or JAN-DEC",
+};
+const WEEK_DAY: Spec = Spec {
+    component: ParseComponent::WeekDay,
+    min: 0,
+    max: 7,
+    names: &WEEK_DAY_NAMES,
+    expected: "a week day of 0-7 or SUN-SAT, optionally followed by 'L' or '#' and 1-5",
+};
+
+/// Parses a cron expression of 5 fields, or 6 fields starting with the seconds.
+pub fn parse_cron(input: &str) -> Result<CronSchedule, ParseFailure> {
+    let cursor = Cursor::new(input);
+    let bytes = input.as_bytes();
+
+    let mut fields = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        if bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+            continue;
+        }
+        let start = pos;
+        while pos < bytes.len() && !bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        fields.push((start, pos));
+    }
+    if fields.len() < 5 {
+        return Err(cursor.error(ParseComponent::Field, input.len(), "5 or 6 fields"));
+    }
+    if let Some((start, _)) = fields.get(6) {
+        return Err(cursor.error(ParseComponent::Field, *start, "at most 6 fields"));
+    }
+
+    let mut schedule = CronSchedule {
+        time: TimeMask {
+            hours: 0,
+            minutes: 0,
+            seconds: 1,
+        },
+        days: 0,
+        months: 0,
+        week_days: 0,
+        last_day: false,
+        last_weekday: false,
+        nearest_weekdays: 0,
+        nth_week_days: Vec::new(),
+        last_week_days: 0,
+        any_day: false,
+        any_week_day: false,
+        offset: 0,
+        expression: input[fields[0].0..]
+            .split_ascii_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" "),
+    };
+    let mut fields = fields.into_iter();
+    if fields.len() == 6 {
+        let (start, end) = fields.next().unwrap_or_default();
+        schedule.time.seconds = parse_field(input, start, end, &SECOND, &mut schedule)?;
+    }
+    let mut next = || fields.next().unwrap_or_default();
+    let (start, end) = next();
+    schedule.time.minutes = parse_field(input, start, end, &MINUTE, &mut schedule)?;
+    let (start, end) = next();
+    schedule.time.hours = parse_field(input, start, end, &HOUR, &mut schedule)?;
+    let (start, end) = next();
+    schedule.any_day = matches!(bytes[start], b'*' | b'?');
+    schedule.days = parse_field(input, start, end, &DAY, &mut schedule)?;
+    let (start, end) = next();
+    schedule.months = parse_field(input, start, end, &MONTH, &mut schedule)?;
+    let (start, end) = next();
+    schedule.any_week_day = matches!(bytes[start], b'*' | b'?');
+    let week_days = parse_field(input, start, end, &WEEK_DAY, &mut schedule)?;
+    // Sunday is both 0 and 7
+    schedule.week_days = (week_days | (week_days >> 7)) & 0x7f;
+
+    Ok(schedule)
+}
+
+/// Parses the `,` separated items of a field from `start` up to `end` into a bit mask of the
+/// matching values.
+///
+/// The special day and week day items are written into `schedule` directly.
+fn parse_field(
+    input: &str,
+    start: usize,
+    end: usize,
+    spec: &Spec,
+    schedule: &mut CronSchedule,
+) -> Result<u64, ParseFailure> {
+    let cursor = Cursor::new(input);
+    let mut mask = 0;
+    let mut item_start = start;
+    for item in input[start..end].split(',') {
+        let item_pos = item_start;
+        item_start += item.len() + 1;
+        let error = |position: usize| cursor.error(spec.component, position, spec.expected);
+        let upper = item.to_ascii_uppercase();
+
+        if item == "?"
+            && matches!(
+                spec.component,
+                ParseComponent::Day | ParseComponent::WeekDay
+            )
+        {
+            mask |= range_mask(spec.min, spec.max, 1);
+            continue;
+        }
+        if spec.component == ParseComponent::Day {
+            match upper.as_str() {
+                "L" => {
+                    schedule.last_day = true;
+                    continue;
+                }
+                "LW" => {
+                    schedule.last_weekday = true;
+                    continue;
+                }
+                _ => {}
+            }
+            if let Some(day) = upper.strip_suffix('W') {
+                let day = value(day, spec).ok_or_else(|| error(item_pos))?;
+                schedule.nearest_weekdays |= 1 << day;
+                continue;
+            }
+        }
+        if spec.component == ParseComponent::WeekDay {
+            if let Some((week_day, nth)) = upper.split_once('#') {
+                let week_day = value(week_day, spec).ok_or_else(|| error(item_pos))? % 7;
+                let nth_pos = item_pos + item.len() - nth.len();
+                let nth = number(nth)
+                    .filter(|nth| (1..=5).contains(nth))
+                    .ok_or_else(|| {
+                        cursor.error(spec.component, nth_pos, "an occurrence of 1-5 after '#'")
+                    })?;
+                schedule.nth_week_days.push((week_day, nth));
+                continue;
+            }
+            if let Some(week_day) = upper.strip_suffix('L').filter(|day| !day.is_empty()) {
+                let week_day = value(week_day, spec).ok_or_else(|| error(item_pos))? % 7;
+                schedule.last_week_days |= 1 << week_day;
+                continue;
+            }
+        }
+
+        let (range, step) = match upper.split_once('/') {
+            Some((range, step)) => {
+                let step_pos = item_pos + range.len() + 1;
+                let step = number(step).filter(|step| *step > 0).ok_or_else(|| {
+                    cursor.error(spec.component, step_pos, "a step of at least 1")
+                })?;
+                (range, Some(step))
+            }
+            None => (upper.as_str(), None),
+        };
+        let (first, last) = if range == "*" {
+            (spec.min, spec.max)
+        } else if let Some((first, last)) = range.split_once('-') {
+            let first = value(first, spec).ok_or_else(|| error(item_pos))?;
+            let last_pos = item_pos + range.find('-').unwrap_or(0) + 1;
+            let last = value(last, spec)
+                .filter(|last| *last >= first)
+                .ok_or_else(|| {
+                    cursor.error(
+                        spec.component,
+                        last_pos,
+                        "the end of a range, not before its start",
+                    )
+                })?;
+            (first, last)
+        } else {
+            let first = value(range, spec).ok_or_else(|| error(item_pos))?;
+            (first, if step.is_some() { spec.max } else { first })
+        };
+        mask |= range_mask(first, last, step.unwrap_or(1));
+    }
+    Ok(mask)
+}
+
+/// Reads a number or a name of the field, `None` if it is outside of the field's values.
+fn value(text: &str, spec: &Spec) -> Option<u8> {
+    if let Some(i) = spec.names.iter().position(|name| *name == text) {
+        return Some(spec.min + i as u8);
+    }
+    number(text).filter(|value| (spec.min..=spec.max).contains(value))
+}
+
+/// Reads a decimal number of up to 3 digits.
+fn number(text: &str) -> Option<u8> {
+    if text.is_empty() || text.len() > 3 || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    text.parse().ok()
+}
+
+/// Returns a bit mask of every `step`th value from `first` up to `last`.
+fn range_mask(first: u8, last: u8, step: u8) -> u64 {
+    (first..=last)
+        .step_by(usize::from(step))
+        .fold(0, |mask, value| mask | 1 << value)
+}
@@ -0,0 +1,85 @@
+/// The length of the longest string `DateTime::fmt_rfc3339` writes, with the five digits of a
+/// `u16` year and the six digits of the hours of an `i32` offset in seconds,
+/// `65535-12-31T23:59:59.999999999-596523:14`.
+pub(crate) const RFC3339_MAX_LEN: usize = 40;
+
+/// A fixed-capacity buffer on the stack holding an RFC 3339 string.
+///
+/// Returned by `Utc::to_rfc3339_buffer`, to write a timestamp into a log buffer or socket
+/// without allocating. Dereferences to `str`.
+///
+/// # Examples
+/// ```rust
+/// use horae::Utc;
+///
+/// let utc = Utc::from_ymd_hms(2019, 1, 1, 9, 9, 9);
+/// let buffer = utc.to_rfc3339_buffer();
+/// assert_eq!(buffer.as_str(), "2019-01-01T09:09:09Z");
+/// assert_eq!(buffer.len(), 20);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Rfc3339Buffer {
+    bytes: [u8; RFC3339_MAX_LEN],
+    len: usize,
+}
+
+impl Rfc3339Buffer {
+    pub(crate) fn new() -> Rfc3339Buffer {
+        Rfc3339Buffer {
+            bytes: [0; RFC3339_MAX_LEN],
+            len: 0,
+        }
+    }
+
+    /// Returns the written RFC 3339 string.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // Only whole `str`s are ever written, so the bytes are always valid UTF-8
+        std::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+
+    /// Returns the bytes of the written RFC 3339 string.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl std::fmt::Write for Rfc3339Buffer {
+    /// Appends `s`, failing without writing anything if it does not fit.
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.len + s.len();
+        if end > RFC3339_MAX_LEN {
+            return Err(std::fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl std::ops::Deref for Rfc3339Buffer {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Rfc3339Buffer {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for Rfc3339Buffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::fmt::Debug for Rfc3339Buffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
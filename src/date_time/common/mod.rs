@@ -1,14 +1,16 @@
+use crate::HoraeError;
 use crate::date_time::date::Date;
 
 use super::time::Time;
 
-pub const SECONDS_IN_MINUTE: u8 = 60;
-pub const SECONDS_IN_HOUR: f64 = 3600.0;
-pub const SECONDS_IN_DAY: f64 = 86_400.0;
-pub const SECONDS_IN_YEAR: f64 = 31_536_000.0;
-const DAYS_IN_YEAR_APPROX: f64 = 365.0;
-const EPOCH_YEAR: u16 = 1970;
+pub const SECONDS_IN_MINUTE: i64 = 60;
+pub const SECONDS_IN_HOUR: i64 = 3600;
+pub const SECONDS_IN_DAY: i64 = 86_400;
+pub const NANOS_IN_SECOND: u32 = 1_000_000_000;
 const EPOCH_WEEK_DAY: u8 = 4;
+// Days between 0000-03-01 and 1970-01-01 in the proleptic Gregorian calendar
+const DAYS_TO_EPOCH: i64 = 719_468;
+const DAYS_IN_ERA: i64 = 146_097;
 const NUMBER_OF_DAYS_PER_MONTH: [u8; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
 // All leap seconds from 1972-2024
 // tuple.0 = year
@@ -69,13 +71,49 @@ const LEAP_SECONDS_ARRAY: [(u16, (bool, bool)); 53] = [
     (2024, (false, false)),
 ];
 
-/// Calculates the week day of any unix timestamp
-pub fn week_day(timestamp: f64) -> u8 {
-    let days_since_epoch = (timestamp / SECONDS_IN_DAY).trunc();
-    let completed_weeks = (days_since_epoch / 7.0).trunc();
-    let left_days = days_since_epoch - (completed_weeks * 7.0);
-    let out = left_days + f64::from(EPOCH_WEEK_DAY);
-    if out > 7.0 { out as u8 - 7 } else { out as u8 }
+/// Calculates the week day of any number of days since the unix epoch
+///
+/// Monday is 1, Sunday is 7.
+pub fn week_day(days_since_epoch: i64) -> u8 {
+    let out = (days_since_epoch + i64::from(EPOCH_WEEK_DAY) - 1).rem_euclid(7) + 1;
+    out as u8
+}
+
+/// Writes the first `digits` digits of a fraction of a second given in nanoseconds, zero-padded.
+///
+/// With `trim`, trailing zeros are left out down to a single digit.
+pub fn write_fraction_digits<W: std::fmt::Write>(
+    out: &mut W,
+    nanos: u32,
+    digits: usize,
+    trim: bool,
+) -> std::fmt::Result {
+    let mut digits = digits;
+    let mut fraction = nanos / 10u32.pow(9 - digits as u32);
+    while trim && digits > 1 && fraction.is_multiple_of(10) {
+        fraction /= 10;
+        digits -= 1;
+    }
+    write!(out, "{fraction:0digits$}")
+}
+
+/// Returns the English ordinal suffix of a day of the month, `st` for 1, `nd` for 22.
+pub fn ordinal_suffix(day: u8) -> &'static str {
+    match (day % 10, day % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+/// Returns the hour on a 12-hour clock, 12 for midnight and noon.
+pub fn twelve_hour(hour: u8) -> u8 {
+    match hour % 12 {
+        0 => 12,
+        hour => hour,
+    }
 }
 
 /// Takes the actual month number (January is 1)
@@ -84,21 +122,49 @@ pub fn days_in_month(month: u8) -> u8 {
     NUMBER_OF_DAYS_PER_MONTH[month as usize - 1]
 }
 
-/// Calculates the number of leap years since the unix epoch
-pub fn leap_years_since_epoch(years_since_epoch: u16) -> u16 {
-    let mut leap_years = 0;
-    for year in 1970..=(1970 + years_since_epoch) {
-        if year % 4 == 0 {
-            if year % 100 == 0 {
-                if year % 400 == 0 {
-                    leap_years += 1;
-                }
-            } else {
-                leap_years += 1;
-            }
-        }
+/// Returns the number of days in the month of the specified year, respecting leap years
+pub fn days_in_month_of_year(year: u16, month: u8) -> u8 {
+    if month == 2 && is_this_year_leap_year(year) {
+        29
+    } else {
+        days_in_month(month)
     }
-    leap_years
+}
+
+/// Checks that the date exists in the supported range of 0001-01-01 to 9999-12-31
+pub fn validate_date(year: u16, month: u8, day: u8) -> Result<(), HoraeError> {
+    if !(1..=9999).contains(&year) {
+        return Err(HoraeError::InvalidYear(year));
+    }
+    if !(1..=12).contains(&month) {
+        return Err(HoraeError::InvalidMonth(month));
+    }
+    if day < 1 || day > days_in_month_of_year(year, month) {
+        return Err(HoraeError::InvalidDay { year, month, day });
+    }
+    Ok(())
+}
+
+/// Checks that the time of day exists, leap seconds are not accepted
+pub fn validate_time(hour: u8, minute: u8, second: u8) -> Result<(), HoraeError> {
+    if hour > 23 {
+        return Err(HoraeError::InvalidHour(hour));
+    }
+    if minute > 59 {
+        return Err(HoraeError::InvalidMinute(minute));
+    }
+    if second > 59 {
+        return Err(HoraeError::InvalidSecond(second));
+    }
+    Ok(())
+}
+
+/// Checks that the UTC offset in hours is finite and within -24 to +24 hours
+pub fn validate_utc_offset(offset: f64) -> Result<(), HoraeError> {
+    if !offset.is_finite() || offset.abs() >= 24.0 {
+        return Err(HoraeError::InvalidUtcOffset(offset));
+    }
+    Ok(())
 }
 
 /// Checks if the specified year is a leap year
@@ -115,6 +181,43 @@ pub fn is_this_year_leap_year(year: u16) -> bool {
     false
 }
 
+/// Returns the number of days since the unix epoch of any date in the proleptic Gregorian
+/// calendar.
+///
+/// Based on the `days_from_civil` algorithm by Howard Hinnant.
+pub fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * DAYS_IN_ERA + day_of_era - DAYS_TO_EPOCH
+}
+
+/// Returns the year, month and day of any number of days since the unix epoch in the
+/// proleptic Gregorian calendar.
+///
+/// Inverse of `days_from_civil`.
+pub fn civil_from_days(days_since_epoch: i64) -> (i64, u8, u8) {
+    let days = days_since_epoch + DAYS_TO_EPOCH;
+    let era = days.div_euclid(DAYS_IN_ERA);
+    let day_of_era = days - era * DAYS_IN_ERA;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u8;
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
 /// Unix time does not count leap seconds -> add them to the number of seconds
 ///
 /// I am accurate to the second without it...
@@ -135,134 +238,23 @@ pub fn leap_seconds_since_epoch(years_since_epoch: u16) -> u16 {
     leap_seconds
 }
 
-/// Creates a new time from a partial unix timestamp - All full days have been taken out
-pub fn make_now_time(rest_timestamp: f64) -> Time {
-    let mut rest_timestamp = rest_timestamp;
-    let hour = (rest_timestamp / SECONDS_IN_HOUR).floor() as u8;
-    rest_timestamp -= f64::from(hour) * SECONDS_IN_HOUR;
-    let minute = (rest_timestamp / f64::from(SECONDS_IN_MINUTE)).floor() as u8;
-    rest_timestamp -= f64::from(minute) * f64::from(SECONDS_IN_MINUTE);
-    let second = rest_timestamp.floor() as u8;
-    let rest = rest_timestamp - f64::from(second);
-    Time::from((hour, minute, second, rest))
+/// Creates a new time from the seconds left over after all full days have been taken out of a
+/// unix timestamp, and the nanoseconds of that timestamp
+pub fn make_now_time(rest_timestamp: i64, nanos: u32) -> Time {
+    debug_assert!((0..SECONDS_IN_DAY).contains(&rest_timestamp));
+    let hour = (rest_timestamp / SECONDS_IN_HOUR) as u8;
+    let minute = ((rest_timestamp % SECONDS_IN_HOUR) / SECONDS_IN_MINUTE) as u8;
+    let second = (rest_timestamp % SECONDS_IN_MINUTE) as u8;
+    Time::from_hms_nano_unchecked(hour, minute, second, nanos)
 }
 
-/// Creates a new date from a unix timestamp
-#[allow(unused_assignments)]
-pub fn make_now_date(timestamp: f64) -> (Date, f64, f64) {
-    let mut tmp_timestamp = timestamp;
-
-    let years_since_epoch = ((timestamp / SECONDS_IN_DAY).trunc() / DAYS_IN_YEAR_APPROX).trunc();
-    let mut leap_years = leap_years_since_epoch(years_since_epoch as u16);
-    let mut year = EPOCH_YEAR + years_since_epoch as u16;
-    tmp_timestamp -= years_since_epoch * SECONDS_IN_YEAR;
-
-    let days_this_year = (tmp_timestamp / SECONDS_IN_DAY).trunc();
-    tmp_timestamp -= days_this_year * SECONDS_IN_DAY;
-
-    let mut month: u8 = 0;
-    let mut days_into_the_year: u16 = 0;
-    while f64::from(days_into_the_year) < days_this_year {
-        days_into_the_year += u16::from(NUMBER_OF_DAYS_PER_MONTH[month as usize]);
-        month += 1;
-    }
-    let completed_months = month.saturating_sub(1);
-    let completed_month_days = {
-        let mut out = 0;
-        for i in 0..completed_months {
-            out += u16::from(NUMBER_OF_DAYS_PER_MONTH[i as usize]);
-        }
-        out
-    };
-
-    // Fix for the 0.th month
-    if month == 0 {
-        month = 1;
-    }
-
-    if month <= 2 && is_this_year_leap_year(year) {
-        leap_years -= 1;
-    }
-
-    debug_assert!(days_into_the_year >= completed_month_days);
-
-    let mut days_left_in_month: i16 = days_this_year as i16 - completed_month_days as i16;
-
-    // Because the 0.th is not the 1.st!
-    days_left_in_month += 1;
-
-    let mut day: u8 = {
-        // expect: Ok, because previous logic ensures:
-        // all completed month days have been counted and removed, meaning:
-        // days_left_in_month > 0 and days_left_in_month < 255
-        // rest of logic handles values larger than the max number of days in a month if
-        // days_left_in_month > 31
-        days_left_in_month.try_into().expect("Could not convert.")
-    };
-
-    // Leap year handler
-    // taking care of edge case: leap_years > 365;
-    let mut tmp_leap_year_store = leap_years;
-    // remove full years of leap year days
-    while f64::from(tmp_leap_year_store) >= DAYS_IN_YEAR_APPROX {
-        if is_this_year_leap_year(year) {
-            tmp_leap_year_store -= 1;
-        }
-        year -= 1;
-        tmp_leap_year_store -= DAYS_IN_YEAR_APPROX as u16;
-    }
-    debug_assert!(tmp_leap_year_store < 365);
-
-    // Now I have: 0 <= leap_years <= 364
-    // if 0: no leap year days left. -> day stays the same, month stays the same
-    // if > 0: at least one leap year day left
-    //
-    // taking care of edge case: 0 < leap_years < 365;
-    let mut prev_month = 0;
-    while tmp_leap_year_store > 0 {
-        // loop through as many months as it takes
-        if tmp_leap_year_store > u16::from(day) {
-            tmp_leap_year_store -= u16::from(day);
-            prev_month = month;
-            month -= 1;
-            if month == 0 {
-                year -= 1;
-                prev_month = 1;
-                month = 12;
-            }
-            if month <= 2 && is_this_year_leap_year(year) && prev_month != 2 {
-                tmp_leap_year_store -= 1;
-            }
-            day = days_in_month(month);
-        } else {
-            let mut new_day = day - tmp_leap_year_store as u8;
-            if new_day == 0 {
-                prev_month = month;
-                month -= 1;
-                if month <= 2 && is_this_year_leap_year(year) && prev_month != 2 && prev_month != 2
-                {
-                    tmp_leap_year_store -= 1;
-                }
-                if month == 0 {
-                    year -= 1;
-                    // I know its not read, unused_assignments flag is only for line below!
-                    prev_month = 1;
-                    month = 12;
-                    new_day = days_in_month(month);
-                } else {
-                    new_day = days_in_month(month);
-                    if month == 2 && is_this_year_leap_year(year) {
-                        new_day = 29;
-                    }
-                }
-            }
-            day = new_day;
-            tmp_leap_year_store = 0;
-        }
-    }
-
-    // now at most 24 hours are left
-    debug_assert!(tmp_timestamp <= SECONDS_IN_DAY);
-    let date = Date::from((year, month, day, timestamp));
-    (date, tmp_timestamp, timestamp)
+/// Creates a new date from a unix timestamp in seconds
+///
+/// Returns the date, and the seconds left over after all full days have been taken out.
+pub fn make_now_date(timestamp: i64) -> (Date, i64) {
+    let days = timestamp.div_euclid(SECONDS_IN_DAY);
+    let rest_timestamp = timestamp.rem_euclid(SECONDS_IN_DAY);
+    let (year, month, day) = civil_from_days(days);
+    let date = Date::from_ymd_unchecked(year as u16, month, day);
+    (date, rest_timestamp)
 }
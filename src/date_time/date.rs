@@ -1,30 +1,238 @@
-use crate::tokenizer::{Token, Unit, tokenize};
+use crate::{
+    DateRange, HoraeError, ParseError, Step, Utc,
+    tokenizer::{DEFAULT_PIVOT_YEAR, Format, Token, Unit, parse_with_format, tokenize},
+};
 
-use super::common::{days_in_month, is_this_year_leap_year, week_day};
+use super::{
+    DateTime,
+    common::{
+        SECONDS_IN_DAY, SECONDS_IN_HOUR, civil_from_days, days_from_civil, days_in_month,
+        days_in_month_of_year, is_this_year_leap_year, ordinal_suffix, validate_date,
+        validate_utc_offset, week_day,
+    },
+    iso_week::IsoWeek,
+    month::Month,
+    parser::{parse_iso_week_date, parse_ordinal_date},
+    time::Time,
+    weekday::Weekday,
+};
 
-#[derive(Debug, Copy, Clone)]
+/// How to resolve a day that does not exist in the month reached by month or year arithmetic,
+/// like adding one month to January 31.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MonthOverflow {
+    /// Use the last day of the month instead, January 31 plus one month is February 28 or 29.
+    Clamp,
+    /// Carry the surplus days into the next month, January 31 plus one month is March 3 or 2.
+    Rollover,
+    /// Return `HoraeError::InvalidDay`.
+    Fail,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// Contains all date information
 ///
 /// Namely: year, month, day
+///
+/// A calendar date without a time or UTC offset.
+/// Instantiate with `Date::from_ymd(year, month, day)`, or get the local date of a `Utc` with
+/// `Utc::date()`.
+///
+/// # Examples
+/// ```rust
+/// use horae::{Date, MonthOverflow};
+///
+/// let due = Date::from_ymd(2024, 1, 31).unwrap();
+/// let next = due.add_months(1, MonthOverflow::Clamp).unwrap();
+/// assert_eq!(next.to_string(), "2024-02-29");
+/// assert_eq!(next - due, 29);
+/// assert!(next > due);
+/// ```
 pub struct Date {
-    pub year: u16,
-    pub month: u8,
-    pub day: u8,
-    unix_timestamp: f64,
+    year: u16,
+    month: u8,
+    day: u8,
 }
 
-impl From<(u16, u8, u8, f64)> for Date {
-    fn from((year, month, day, unix_timestamp): (u16, u8, u8, f64)) -> Date {
-        Date {
-            year,
-            month,
-            day,
-            unix_timestamp,
+impl Date {
+    /// Instantiates a new `Date` from fields the caller has already validated.
+    pub(crate) fn from_ymd_unchecked(year: u16, month: u8, day: u8) -> Date {
+        Date { year, month, day }
+    }
+
+    /// Instantiates a new `Date`, validating every field.
+    ///
+    /// # Errors
+    /// Returns a `HoraeError` naming the first field out of range:
+    /// - year: 1-9999
+    /// - month: 1-12
+    /// - day: 1 up to the number of days in the month, respecting leap years
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Date, HoraeError};
+    ///
+    /// assert_eq!(Date::from_ymd(2024, 2, 29).unwrap().to_string(), "2024-02-29");
+    /// assert_eq!(
+    ///     Date::from_ymd(2023, 2, 29),
+    ///     Err(HoraeError::InvalidDay { year: 2023, month: 2, day: 29 })
+    /// );
+    /// ```
+    pub fn from_ymd(year: u16, month: u8, day: u8) -> Result<Date, HoraeError> {
+        validate_date(year, month, day)?;
+        Ok(Date { year, month, day })
+    }
+
+    /// Returns the year, 1-9999.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Date;
+    ///
+    /// let date = Date::from_ymd(2024, 2, 29).unwrap();
+    /// assert_eq!((date.year(), date.month(), date.day()), (2024, 2, 29));
+    /// ```
+    #[must_use]
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month, 1-12.
+    #[must_use]
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, starting at 1.
+    #[must_use]
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns a new `Utc` at the supplied time on this date, in UTC.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Date, Time};
+    ///
+    /// let date = Date::from_ymd(2019, 1, 1).unwrap();
+    /// let utc = date.at_time(Time::from_hms(9, 9, 9).unwrap());
+    /// assert_eq!(utc.to_string(), "2019-01-01 09:09:09.000");
+    /// ```
+    #[must_use]
+    pub fn at_time(&self, time: Time) -> Utc {
+        Utc {
+            date_time: DateTime::from_local(*self, time, 0),
         }
     }
-}
 
-impl Date {
+    /// Returns a new `Utc` at the supplied local time on this date, in the supplied UTC offset
+    /// in hours.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::InvalidUtcOffset` if the offset is not within -24 to +24 hours.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Date, Time};
+    ///
+    /// let date = Date::from_ymd(2019, 1, 1).unwrap();
+    /// let local = date.in_offset(Time::from_hms(9, 9, 9).unwrap(), 5.5).unwrap(); // IST
+    /// assert_eq!(local.to_string(), "2019-01-01 09:09:09.000");
+    /// assert_eq!(local.to_rfc3339(), "2019-01-01T09:09:09+05:30");
+    /// ```
+    pub fn in_offset(&self, time: Time, offset: f64) -> Result<Utc, HoraeError> {
+        validate_utc_offset(offset)?;
+        let offset = (offset * SECONDS_IN_HOUR as f64).round() as i32;
+        Ok(Utc {
+            date_time: DateTime::from_local(*self, time, offset),
+        })
+    }
+
+    /// Returns the date moved by the supplied number of days.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::OutOfRange` if the result is outside of the supported years.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Date;
+    ///
+    /// let date = Date::from_ymd(2024, 2, 28).unwrap();
+    /// assert_eq!(date.add_days(2).unwrap().to_string(), "2024-03-01");
+    /// assert_eq!(date.add_days(-59).unwrap().to_string(), "2023-12-31");
+    /// ```
+    pub fn add_days(&self, days: i64) -> Result<Date, HoraeError> {
+        let days = self
+            .days_since_epoch()
+            .checked_add(days)
+            .ok_or(HoraeError::OutOfRange)?;
+        Date::from_days_since_epoch(days)
+    }
+
+    /// Returns the date moved by the supplied number of weeks.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::OutOfRange` if the result is outside of the supported years.
+    pub fn add_weeks(&self, weeks: i64) -> Result<Date, HoraeError> {
+        self.add_days(weeks.checked_mul(7).ok_or(HoraeError::OutOfRange)?)
+    }
+
+    /// Returns the date moved by the supplied number of calendar years, resolving February 29
+    /// according to `overflow` if the new year is not a leap year.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::InvalidDay` if the day does not exist and `overflow` is
+    /// `MonthOverflow::Fail`, or `HoraeError::OutOfRange` if the result is outside of the
+    /// supported years.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Date, MonthOverflow};
+    ///
+    /// let date = Date::from_ymd(2024, 2, 29).unwrap();
+    /// assert_eq!(date.add_years(1, MonthOverflow::Clamp).unwrap().to_string(), "2025-02-28");
+    /// ```
+    pub fn add_years(&self, years: i32, overflow: MonthOverflow) -> Result<Date, HoraeError> {
+        self.add_months(
+            years.checked_mul(12).ok_or(HoraeError::OutOfRange)?,
+            overflow,
+        )
+    }
+
+    /// Returns an iterator over the dates from `start` up to, but not including, `end`, spaced
+    /// by `step`.
+    ///
+    /// The range is empty if `end` is not after `start`.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::InvalidStep` if the step is not positive, or is a `Step::Exact`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Date, Step};
+    ///
+    /// let start = Date::from_ymd(2024, 1, 1).unwrap();
+    /// let end = Date::from_ymd(2025, 1, 1).unwrap();
+    /// let quarters: Vec<String> = Date::range(start, end, Step::Months(3))
+    ///     .unwrap()
+    ///     .map(|date| date.to_string())
+    ///     .collect();
+    /// assert_eq!(quarters, ["2024-01-01", "2024-04-01", "2024-07-01", "2024-10-01"]);
+    /// ```
+    pub fn range(start: Date, end: Date, step: Step) -> Result<DateRange, HoraeError> {
+        DateRange::new(start, end, step, false)
+    }
+
+    /// Returns an iterator over the dates from `start` up to and including `end`, spaced by
+    /// `step`.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::InvalidStep` if the step is not positive, or is a `Step::Exact`.
+    pub fn range_inclusive(start: Date, end: Date, step: Step) -> Result<DateRange, HoraeError> {
+        DateRange::new(start, end, step, true)
+    }
+
     /// Used to format a date
     ///
     /// For more information on the available formatting syntax, see the README in the API chapter.
@@ -37,146 +245,329 @@ impl Date {
     /// assert_eq!(utc_now.date().format("%yyyy-%mm-%dd"), "2019-01-01");
     /// ```
     pub fn format(&self, formatter: &str) -> String {
-        let format_tokens = tokenize(formatter);
+        self.format_tokens(&tokenize(formatter))
+    }
+
+    /// Formats the date in the compiled format, see `Format`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Date, Format};
+    ///
+    /// let format = Format::new("%dd.%mm.%yyyy").unwrap();
+    /// assert_eq!(Date::from_ymd(2019, 1, 15).unwrap().format_with(&format), "15.01.2019");
+    /// ```
+    pub fn format_with(&self, format: &Format) -> String {
+        self.format_tokens(format.tokens())
+    }
+
+    /// Writes the date in the compiled format into `out`, without allocating.
+    ///
+    /// # Errors
+    /// Returns an error if `out` fails to write.
+    pub fn format_into<W: std::fmt::Write>(
+        &self,
+        out: &mut W,
+        format: &Format,
+    ) -> std::fmt::Result {
+        self.write_tokens(format.tokens(), out)
+    }
+
+    fn format_tokens(&self, tokens: &[Token]) -> String {
         let mut formatted_string = String::new();
-        for token in format_tokens {
+        // Writing into a `String` cannot fail
+        let _ = self.write_tokens(tokens, &mut formatted_string);
+        formatted_string
+    }
+
+    fn write_tokens<W: std::fmt::Write>(&self, tokens: &[Token], out: &mut W) -> std::fmt::Result {
+        for token in tokens {
             match token {
                 Token::Unit(unit) => match unit {
                     Unit::ShortDay => {
-                        formatted_string.push_str(&format!("{:01}", self.day));
+                        write!(out, "{:01}", self.day)?;
                     }
                     Unit::Day => {
-                        formatted_string.push_str(&format!("{:02}", self.day));
+                        write!(out, "{:02}", self.day)?;
                     }
                     Unit::ShortNumMonth => {
-                        formatted_string.push_str(&format!("{:01}", self.month));
+                        write!(out, "{:01}", self.month)?;
                     }
                     Unit::NumMonth => {
-                        formatted_string.push_str(&format!("{:02}", self.month));
+                        write!(out, "{:02}", self.month)?;
                     }
                     Unit::ShortWordMonth => {
-                        const MONTHS: [&str; 12] = [
-                            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct",
-                            "Nov", "Dec",
-                        ];
-                        formatted_string.push_str(MONTHS[self.month as usize - 1]);
+                        out.write_str(Month::ALL[self.month as usize - 1].short_name())?;
                     }
                     Unit::WordMonth => {
-                        const MONTHS: [&str; 12] = [
-                            "January",
-                            "February",
-                            "March",
-                            "April",
-                            "May",
-                            "June",
-                            "July",
-                            "August",
-                            "September",
-                            "October",
-                            "November",
-                            "December",
-                        ];
-                        formatted_string.push_str(MONTHS[self.month as usize - 1]);
+                        out.write_str(Month::ALL[self.month as usize - 1].name())?;
                     }
                     Unit::ShortYear => {
-                        formatted_string.push_str(&format!(
-                            "{:01}",
-                            self.year
-                                .to_string()
-                                .chars()
-                                .last()
-                                .expect("No Year found!")
-                        ));
+                        write!(out, "{:01}", self.year % 10)?;
                     }
                     Unit::Year => {
-                        let year_tmp: String =
-                            self.year.to_string().chars().rev().take(2).collect();
-                        let year = year_tmp.chars().rev().collect::<String>();
-                        formatted_string.push_str(&year);
+                        write!(out, "{:02}", self.year % 100)?;
                     }
                     Unit::FullYear => {
-                        formatted_string.push_str(&format!("{}", self.year));
+                        write!(out, "{:04}", self.year)?;
                     }
                     Unit::WeekDay => {
-                        let week_day_num = week_day(self.unix_timestamp);
-                        let week_day = match week_day_num {
-                            1 => "Monday",
-                            2 => "Tuesday",
-                            3 => "Wednesday",
-                            4 => "Thursday",
-                            5 => "Friday",
-                            6 => "Saturday",
-                            7 => "Sunday",
-                            // Should really never happen!
-                            _ => "Error",
-                        };
-                        formatted_string.push_str(week_day);
+                        out.write_str(self.weekday().name())?;
                     }
                     Unit::ShortWeekDay => {
-                        let week_day_num = week_day(self.unix_timestamp);
-                        let week_day = match week_day_num {
-                            1 => "Mon",
-                            2 => "Tue",
-                            3 => "Wed",
-                            4 => "Thu",
-                            5 => "Fri",
-                            6 => "Sat",
-                            7 => "Sun",
-                            // Should really never happen!
-                            _ => "Error",
-                        };
-                        formatted_string.push_str(week_day);
+                        out.write_str(self.weekday().short_name())?;
                     }
                     Unit::ShortWeekNumber => {
-                        formatted_string.push_str(&format!("{:01}", self.get_weeknumber()));
+                        write!(out, "{:01}", self.get_weeknumber())?;
                     }
                     Unit::WeekNumber => {
-                        formatted_string.push_str(&format!("{:02}", self.get_weeknumber()));
+                        write!(out, "{:02}", self.get_weeknumber())?;
+                    }
+                    Unit::OrdinalDay => {
+                        write!(out, "{:03}", self.ordinal_day())?;
+                    }
+                    Unit::ShortIsoYear => {
+                        write!(out, "{:02}", self.iso_week().year() % 100)?;
+                    }
+                    Unit::IsoYear => {
+                        write!(out, "{:04}", self.iso_week().year())?;
+                    }
+                    Unit::SpacePaddedDay => {
+                        write!(out, "{:2}", self.day)?;
+                    }
+                    Unit::SpacePaddedOrdinalDay => {
+                        write!(out, "{:3}", self.ordinal_day())?;
+                    }
+                    Unit::OrdinalSuffixDay => {
+                        write!(out, "{}{}", self.day, ordinal_suffix(self.day))?;
+                    }
+                    Unit::SpacePaddedNumMonth => {
+                        write!(out, "{:2}", self.month)?;
+                    }
+                    Unit::Quarter => {
+                        write!(out, "{}", (self.month - 1) / 3 + 1)?;
+                    }
+                    Unit::SpacePaddedWeekNumber => {
+                        write!(out, "{:2}", self.get_weeknumber())?;
+                    }
+                    Unit::TimestampSeconds => {
+                        write!(out, "{}", self.days_since_epoch() * SECONDS_IN_DAY)?;
+                    }
+                    Unit::TimestampMillis => {
+                        write!(out, "{}", self.days_since_epoch() * SECONDS_IN_DAY * 1000)?;
                     }
                     // Dont want to intruduce an error state now...
                     _ => {
-                        formatted_string
-                            .push_str(" Date only supports Day, Week day, Month and Year ");
+                        out.write_str(" Date only supports Day, Week day, Month and Year ")?;
                     }
                 },
                 Token::Separator(separator) => {
-                    formatted_string.push_str(&separator.separator_symbol);
+                    out.write_str(&separator.separator_symbol)?;
                 }
             }
         }
 
-        formatted_string
+        Ok(())
+    }
+
+    /// Parses a `Date` from a string written in the supplied format, using the same directives
+    /// as `format`.
+    ///
+    /// See `Utc::parse_from_format` for the accepted directives. Time directives are read but
+    /// ignored.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` naming the component that could not be read, and where.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Date;
+    ///
+    /// let date = Date::parse_from_format("1 March 2024", "%d %mmmm %yyyy").unwrap();
+    /// assert_eq!(date.to_string(), "2024-03-01");
+    /// ```
+    pub fn parse_from_format(input: &str, format: &str) -> Result<Date, ParseError> {
+        Date::parse_from_format_with_pivot(input, format, DEFAULT_PIVOT_YEAR)
+    }
+
+    /// Parses a `Date` like `parse_from_format`, reading two-digit years as the year ending in
+    /// them in the hundred years starting at `pivot_year`.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` naming the component that could not be read, and where.
+    pub fn parse_from_format_with_pivot(
+        input: &str,
+        format: &str,
+        pivot_year: u16,
+    ) -> Result<Date, ParseError> {
+        parse_with_format(input, &Format::new(format)?, pivot_year)
+            .and_then(|parsed| parsed.date())
+            .map_err(ParseError::from)
     }
 
     /// Returns the week number of the date according to ISO 8601
     pub fn get_weeknumber(&self) -> u8 {
-        let ordinal = self.ordinal_day();
-        let weekday = week_day(self.unix_timestamp);
-        let d_thurs = ordinal as i16 - i16::from(weekday) + 4;
-
-        if d_thurs < 1 {
-            let days_in_prev_year = if is_this_year_leap_year(self.year - 1) {
-                366
-            } else {
-                365
-            };
-            let d_thurs_prev = d_thurs + days_in_prev_year as i16;
-            return ((d_thurs_prev - 1) / 7 + 1) as u8;
+        self.iso_week().week()
+    }
+
+    /// Returns the ISO 8601 week of the date, together with its week-numbering year.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Date, IsoWeek};
+    ///
+    /// let date = Date::from_ymd(2021, 1, 1).unwrap();
+    /// assert_eq!(date.iso_week(), IsoWeek::new(2020, 53).unwrap());
+    /// ```
+    #[must_use]
+    pub fn iso_week(&self) -> IsoWeek {
+        // The week belongs to the year of its Thursday
+        let thursday = self.days_since_epoch() - i64::from(self.weekday().number_from_monday()) + 4;
+        let (year, _, _) = civil_from_days(thursday);
+        let week = (thursday - days_from_civil(year, 1, 1)) / 7 + 1;
+        IsoWeek::new_unchecked(year as u16, week as u8)
+    }
+
+    /// Instantiates a validated `Date` from an ISO 8601 week-numbering year, week and week day.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::InvalidYear` or `HoraeError::InvalidWeek` if the week does not
+    /// exist, or `HoraeError::OutOfRange` if the date is after 9999-12-31.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Date, Weekday};
+    ///
+    /// let date = Date::from_iso_week(2026, 11, Weekday::Thursday).unwrap();
+    /// assert_eq!(date.to_string(), "2026-03-12");
+    /// assert!(Date::from_iso_week(2025, 53, Weekday::Monday).is_err());
+    /// ```
+    pub fn from_iso_week(iso_year: u16, week: u8, weekday: Weekday) -> Result<Date, HoraeError> {
+        let week = IsoWeek::new(iso_year, week)?;
+        Date::from_days_since_epoch(week.first_day_since_epoch() + i64::from(weekday as u8))
+    }
+
+    /// Parses an ISO 8601 week date of `YYYY-Www-D` or `YYYYWwwD`, where `D` is the week day from
+    /// Monday as 1 to Sunday as 7.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` naming the component that could not be read, and where.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Date, ParseComponent};
+    ///
+    /// let date = Date::parse_iso_week_date("2026-W11-4").unwrap();
+    /// assert_eq!(date, Date::parse_iso_week_date("2026W114").unwrap());
+    /// assert_eq!(date.to_string(), "2026-03-12");
+    /// assert_eq!(
+    ///     Date::parse_iso_week_date("2026-W11-8").unwrap_err().component(),
+    ///     ParseComponent::WeekDay
+    /// );
+    /// ```
+    pub fn parse_iso_week_date(s: &str) -> Result<Date, ParseError> {
+        parse_iso_week_date(s).map_err(ParseError::from)
+    }
+
+    /// Formats the date as an ISO 8601 week date of `YYYY-Www-D`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Date;
+    ///
+    /// let date = Date::from_ymd(2024, 12, 30).unwrap();
+    /// assert_eq!(date.to_iso_week_date(), "2025-W01-1");
+    /// ```
+    #[must_use]
+    pub fn to_iso_week_date(&self) -> String {
+        format!(
+            "{}-{}",
+            self.iso_week(),
+            self.weekday().number_from_monday()
+        )
+    }
+
+    /// Returns the date moved by the supplied number of calendar months, resolving a day that
+    /// does not exist in the new month according to `overflow`.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::InvalidDay` if the day does not exist and `overflow` is
+    /// `MonthOverflow::Fail`, or `HoraeError::OutOfRange` if the result is outside of the
+    /// supported years.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Date, MonthOverflow};
+    ///
+    /// let date = Date::from_ymd(2024, 1, 31).unwrap();
+    /// assert_eq!(date.add_months(1, MonthOverflow::Clamp).unwrap().to_string(), "2024-02-29");
+    /// assert_eq!(date.add_months(1, MonthOverflow::Rollover).unwrap().to_string(), "2024-03-02");
+    /// assert!(date.add_months(1, MonthOverflow::Fail).is_err());
+    /// ```
+    pub fn add_months(&self, months: i32, overflow: MonthOverflow) -> Result<Date, HoraeError> {
+        let total_months =
+            i64::from(self.year) * 12 + i64::from(self.month) - 1 + i64::from(months);
+        let year = total_months.div_euclid(12);
+        let month = total_months.rem_euclid(12) as u8 + 1;
+        if !(1..=9999).contains(&year) {
+            return Err(HoraeError::OutOfRange);
+        }
+        let year = year as u16;
+        let last_day = days_in_month_of_year(year, month);
+        if self.day <= last_day {
+            return Ok(Date::from_ymd_unchecked(year, month, self.day));
+        }
+        match overflow {
+            MonthOverflow::Clamp => Ok(Date::from_ymd_unchecked(year, month, last_day)),
+            MonthOverflow::Rollover => {
+                let days = days_from_civil(i64::from(year), month, last_day)
+                    + i64::from(self.day - last_day);
+                Date::from_days_since_epoch(days)
+            }
+            MonthOverflow::Fail => Err(HoraeError::InvalidDay {
+                year,
+                month,
+                day: self.day,
+            }),
         }
+    }
 
-        let days_in_this_year = if is_this_year_leap_year(self.year) {
-            366
-        } else {
-            365
-        };
-        if d_thurs > days_in_this_year as i16 {
-            return 1;
+    /// Returns the date the supplied number of days after the unix epoch.
+    pub(crate) fn from_days_since_epoch(days: i64) -> Result<Date, HoraeError> {
+        if !(days_from_civil(1, 1, 1)..=days_from_civil(9999, 12, 31)).contains(&days) {
+            return Err(HoraeError::OutOfRange);
         }
+        let (year, month, day) = civil_from_days(days);
+        Ok(Date::from_ymd_unchecked(year as u16, month, day))
+    }
 
-        ((d_thurs - 1) / 7 + 1) as u8
+    /// Returns the number of days since the unix epoch
+    pub(crate) fn days_since_epoch(&self) -> i64 {
+        days_from_civil(i64::from(self.year), self.month, self.day)
     }
 
-    fn ordinal_day(&self) -> u16 {
+    /// Returns the week day of the date.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Date, Weekday};
+    ///
+    /// assert_eq!(Date::from_ymd(2024, 1, 1).unwrap().weekday(), Weekday::Monday);
+    /// ```
+    #[must_use]
+    pub fn weekday(&self) -> Weekday {
+        Weekday::ALL[usize::from(week_day(self.days_since_epoch()) - 1)]
+    }
+
+    /// Returns the day of the year, January 1 is 1
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Date;
+    ///
+    /// assert_eq!(Date::from_ymd(2024, 12, 31).unwrap().ordinal_day(), 366);
+    /// ```
+    pub fn ordinal_day(&self) -> u16 {
         let mut days = u16::from(self.day);
         for m in 1..self.month {
             days += u16::from(days_in_month(m));
@@ -186,6 +577,97 @@ impl Date {
         }
         days
     }
+
+    /// Returns the number of days in the year of the date, 365 or 366 in leap years.
+    #[must_use]
+    pub fn days_in_year(&self) -> u16 {
+        if is_this_year_leap_year(self.year) {
+            366
+        } else {
+            365
+        }
+    }
+
+    /// Returns the number of days left in the year after the date, December 31 has 0.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Date;
+    ///
+    /// let date = Date::from_ymd(2024, 2, 1).unwrap();
+    /// assert_eq!(date.days_remaining_in_year(), 334);
+    /// assert_eq!(date.ordinal_day() + date.days_remaining_in_year(), date.days_in_year());
+    /// ```
+    #[must_use]
+    pub fn days_remaining_in_year(&self) -> u16 {
+        self.days_in_year() - self.ordinal_day()
+    }
+
+    /// Instantiates a validated `Date` from a year and a day of the year, January 1 is 1.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::InvalidYear` if the year is outside of 1-9999, or
+    /// `HoraeError::InvalidOrdinal` if the year has no such day.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Date;
+    ///
+    /// assert_eq!(Date::from_ordinal(2019, 32).unwrap().to_string(), "2019-02-01");
+    /// assert!(Date::from_ordinal(2023, 366).is_err());
+    /// ```
+    pub fn from_ordinal(year: u16, ordinal: u16) -> Result<Date, HoraeError> {
+        validate_date(year, 1, 1)?;
+        let days_in_year = Date::from_ymd_unchecked(year, 1, 1).days_in_year();
+        if ordinal < 1 || ordinal > days_in_year {
+            return Err(HoraeError::InvalidOrdinal { year, ordinal });
+        }
+        Date::from_days_since_epoch(days_from_civil(i64::from(year), 1, 1) + i64::from(ordinal) - 1)
+    }
+
+    /// Parses an ISO 8601 ordinal date of `YYYY-DDD` or `YYYYDDD`, where `DDD` is the day of the
+    /// year.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` naming the component that could not be read, and where.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Date, ParseComponent};
+    ///
+    /// let date = Date::parse_ordinal_date("2019-032").unwrap();
+    /// assert_eq!(date, Date::parse_ordinal_date("2019032").unwrap());
+    /// assert_eq!(date.to_string(), "2019-02-01");
+    /// assert_eq!(
+    ///     Date::parse_ordinal_date("2019-366").unwrap_err().component(),
+    ///     ParseComponent::Day
+    /// );
+    /// ```
+    pub fn parse_ordinal_date(s: &str) -> Result<Date, ParseError> {
+        parse_ordinal_date(s).map_err(ParseError::from)
+    }
+
+    /// Formats the date as an ISO 8601 ordinal date of `YYYY-DDD`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Date;
+    ///
+    /// assert_eq!(Date::from_ymd(2024, 12, 31).unwrap().to_ordinal_date(), "2024-366");
+    /// ```
+    #[must_use]
+    pub fn to_ordinal_date(&self) -> String {
+        format!("{:04}-{:03}", self.year, self.ordinal_day())
+    }
+}
+
+impl std::ops::Sub<Date> for Date {
+    type Output = i64;
+
+    /// Returns the number of days from `rhs` to `self`, negative if `rhs` is later.
+    fn sub(self, rhs: Date) -> i64 {
+        self.days_since_epoch() - rhs.days_since_epoch()
+    }
 }
 
 // Display implementation
@@ -0,0 +1,29 @@
+/// The smallest component given in a parsed ISO 8601 string.
+///
+/// A decimal fraction belongs to the smallest component, so `T09,5` has `Hour` precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Iso8601Precision {
+    /// A year, like `2019`.
+    Year,
+    /// A calendar month, like `2019-01`.
+    Month,
+    /// An ISO week without a week day, like `2019-W01`.
+    Week,
+    /// A calendar, ordinal or week date, like `2019-01-01`, `2019-001` or `2019-W01-2`.
+    Day,
+    /// A date and an hour, like `2019-01-01T09`.
+    Hour,
+    /// A date and a time of hours and minutes, like `2019-01-01T09:09`.
+    Minute,
+    /// A date and a complete time, like `2019-01-01T09:09:09`.
+    Second,
+}
+
+/// The ISO 8601 format of a formatted date and time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Iso8601Format {
+    /// Without separators, like `20190101T090909+0530`.
+    Basic,
+    /// With `-` and `:` separators, like `2019-01-01T09:09:09+05:30`.
+    Extended,
+}
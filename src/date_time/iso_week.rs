@@ -0,0 +1,131 @@
+use crate::{HoraeError, ParseError};
+
+use super::{
+    common::{civil_from_days, days_from_civil, is_this_year_leap_year, validate_date, week_day},
+    date::Date,
+    parser::parse_iso_week,
+};
+
+/// A week of an ISO 8601 week-numbering year.
+///
+/// ISO weeks start on Monday, and week 1 is the week holding the first Thursday of the year.
+/// The first and last days of a calendar year can therefore belong to a week of the year
+/// before or after, 2024-12-30 is in week 1 of 2025.
+///
+/// # Examples
+/// ```rust
+/// use horae::{Date, IsoWeek};
+///
+/// let week = Date::from_ymd(2024, 12, 30).unwrap().iso_week();
+/// assert_eq!(week, IsoWeek::new(2025, 1).unwrap());
+/// assert_eq!(week.to_string(), "2025-W01");
+/// assert_eq!(week.first_day(), Date::from_ymd(2024, 12, 30).unwrap());
+/// assert_eq!(IsoWeek::weeks_in_year(2026), 53);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IsoWeek {
+    year: u16,
+    week: u8,
+}
+
+impl IsoWeek {
+    /// Instantiates a validated `IsoWeek`.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::InvalidYear` if the year is outside of 1-9999, or
+    /// `HoraeError::InvalidWeek` if the year has no such week.
+    pub fn new(year: u16, week: u8) -> Result<IsoWeek, HoraeError> {
+        validate_date(year, 1, 1)?;
+        if week < 1 || week > IsoWeek::weeks_in_year(year) {
+            return Err(HoraeError::InvalidWeek { year, week });
+        }
+        Ok(IsoWeek { year, week })
+    }
+
+    /// Instantiates a new `IsoWeek` from fields the caller has already validated.
+    pub(crate) fn new_unchecked(year: u16, week: u8) -> IsoWeek {
+        IsoWeek { year, week }
+    }
+
+    /// Returns the ISO week-numbering year, which may differ from the calendar year.
+    #[must_use]
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the week of the year, 1-53.
+    #[must_use]
+    pub fn week(&self) -> u8 {
+        self.week
+    }
+
+    /// Returns the number of weeks of the ISO week-numbering year, 52 or 53.
+    ///
+    /// Years starting on a Thursday, and leap years starting on a Wednesday, have 53 weeks.
+    #[must_use]
+    pub fn weeks_in_year(year: u16) -> u8 {
+        match week_day(days_from_civil(i64::from(year), 1, 1)) {
+            4 => 53,
+            3 if is_this_year_leap_year(year) => 53,
+            _ => 52,
+        }
+    }
+
+    /// Returns the Monday the week starts on.
+    #[must_use]
+    pub fn first_day(&self) -> Date {
+        let (year, month, day) = civil_from_days(self.first_day_since_epoch());
+        Date::from_ymd_unchecked(year as u16, month, day)
+    }
+
+    /// Returns the Sunday the week ends on.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::OutOfRange` if the Sunday is after 9999-12-31, which is only the case
+    /// for the last week of 9999.
+    pub fn last_day(&self) -> Result<Date, HoraeError> {
+        Date::from_days_since_epoch(self.first_day_since_epoch() + 6)
+    }
+
+    /// Parses an ISO 8601 week of `YYYY-Www` or `YYYYWww`.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` naming the component that could not be read, and where.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::IsoWeek;
+    ///
+    /// assert_eq!(IsoWeek::parse("2026-W11"), IsoWeek::parse("2026W11"));
+    /// assert!(IsoWeek::parse("2025-W53").is_err());
+    /// ```
+    pub fn parse(s: &str) -> Result<IsoWeek, ParseError> {
+        parse_iso_week(s).map_err(ParseError::from)
+    }
+
+    /// Returns the days since the unix epoch of the Monday of the week.
+    pub(crate) fn first_day_since_epoch(&self) -> i64 {
+        // January 4 is always in week 1
+        let january_4 = days_from_civil(i64::from(self.year), 1, 4);
+        january_4 - i64::from(week_day(january_4)) + 1 + (i64::from(self.week) - 1) * 7
+    }
+}
+
+// Display implementation
+
+impl std::fmt::Display for IsoWeek {
+    /// Formats the week as `YYYY-Www`, like `2026-W11`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:04}-W{:02}", self.year, self.week)
+    }
+}
+
+impl std::str::FromStr for IsoWeek {
+    type Err = HoraeError;
+
+    /// Parses a week of `YYYY-Www` or `YYYYWww`, returning the `HoraeError` of the field if a
+    /// component is out of range.
+    fn from_str(s: &str) -> Result<IsoWeek, HoraeError> {
+        parse_iso_week(s).map_err(HoraeError::from)
+    }
+}
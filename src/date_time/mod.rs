@@ -1,24 +1,42 @@
 use aequa::XffValue;
+use buffer::RFC3339_MAX_LEN;
 use common::{
-    SECONDS_IN_DAY, SECONDS_IN_HOUR, SECONDS_IN_MINUTE, SECONDS_IN_YEAR, days_in_month,
-    is_this_year_leap_year, leap_years_since_epoch, make_now_date, make_now_time, week_day,
+    NANOS_IN_SECOND, SECONDS_IN_DAY, SECONDS_IN_HOUR, SECONDS_IN_MINUTE, days_from_civil,
+    make_now_date, make_now_time, ordinal_suffix, twelve_hour, validate_date, validate_time,
+    validate_utc_offset, write_fraction_digits,
 };
 use date::Date;
+use iso8601::{Iso8601Format, Iso8601Precision};
+use month::Month;
+use parser::{parse_iso8601, parse_rfc3339, parse_rfc9557};
+use rfc3339::{Rfc3339Options, SecondsFormat};
 use time::Time;
 
+use std::fmt::Write;
+
 use crate::{
+    HoraeError, ParseError,
     time_zones::{TimeZone, detect_local_offset},
-    tokenizer::{Token, Unit, tokenize},
+    tokenizer::{Format, Token, Unit, tokenize},
 };
 
-mod common;
+pub mod buffer;
+pub(crate) mod common;
 pub mod date;
+pub mod iso8601;
+pub mod iso_week;
+pub mod month;
+pub(crate) mod parser;
+pub mod rfc3339;
 pub mod time;
+pub mod weekday;
 
 /// Complete date and time.
-/// Also holds the timezone and the unix timestamp.
+/// Also holds the UTC offset and the unix timestamp.
 ///
-/// Date and time are always in the timezone held.
+/// The instant is stored exactly, as whole seconds since the unix epoch and the nanoseconds
+/// into that second. The held date and time are derived from it and are always local to the
+/// held UTC offset.
 ///
 /// Instantiated with `DateTime::now()`, or `DateTime::from_timestamp(timestamp)`.
 ///
@@ -26,60 +44,145 @@ pub mod time;
 pub struct DateTime {
     date: Date,
     time: Time,
-    pub unix_timestamp: f64,
-    pub timezone: f64,
+    unix_timestamp: i64,
+    subsec_nanos: u32,
+    /// UTC offset in seconds
+    offset: i32,
 }
 
 impl DateTime {
     /// Instantiates a new `DateTime` with the current date and time.
     pub fn now() -> DateTime {
-        // Only logically unguarded panic in the library below!
-        let (date, timestamp, unix_timestamp) = {
-            let timestamp = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH);
-            match timestamp {
-                Ok(duration) => make_now_date(duration.as_secs_f64()),
-                Err(e) => panic!("{}", e),
+        DateTime::from_system_time(std::time::SystemTime::now())
+    }
+
+    /// Instantiates a new `DateTime` from a `SystemTime`, including times before the unix epoch.
+    pub fn from_system_time(system_time: std::time::SystemTime) -> DateTime {
+        match system_time.duration_since(std::time::UNIX_EPOCH) {
+            Ok(duration) => {
+                DateTime::from_timestamp_parts(duration.as_secs() as i64, duration.subsec_nanos())
             }
-        };
-        let time = make_now_time(timestamp);
-        DateTime {
-            date,
-            time,
-            unix_timestamp,
-            timezone: 0.0,
+            Err(e) => DateTime::from_timestamp_nanos(-(e.duration().as_nanos() as i128)),
         }
     }
 
     /// Cast the `DateTime` to an `XFFValue::DateTime`.
-    pub fn to_xffvalue(&self) -> XffValue {
+    pub fn to_xffvalue(self) -> XffValue {
         XffValue::DateTime(aequa::DateTime::from(self.unix_timestamp_u64()))
     }
 
     /// Instantiates a new `DateTime` from an `XFFValue::DateTime`.
-    pub fn from_xffvalue(value: XffValue) -> Option<DateTime> {
+    pub fn from_xffvalue(value: XffValue) -> Result<DateTime, HoraeError> {
         match value {
-            XffValue::DateTime(xff) => {
-                Some(DateTime::from_timestamp(xff.as_unix_timestamp() as f64))
-            }
-            _ => None,
+            XffValue::DateTime(xff) => Ok(DateTime::from_timestamp(xff.as_unix_timestamp() as f64)),
+            _ => Err(HoraeError::NotADateTime),
         }
     }
 
     /// Returns the unix timestamp of the `DateTime` in milliseconds.
+    ///
+    /// Dates before the unix epoch saturate to 0.
     #[must_use]
     pub fn unix_timestamp_u64(&self) -> u64 {
-        (self.unix_timestamp * 1000.0) as u64
+        u64::try_from(self.unix_timestamp_millis()).unwrap_or(0)
     }
+
+    /// Returns the unix timestamp of the `DateTime` in milliseconds, negative before the epoch.
+    ///
+    /// Saturates at the bounds of `i64`.
+    pub fn unix_timestamp_millis(&self) -> i64 {
+        self.unix_timestamp
+            .saturating_mul(1000)
+            .saturating_add(i64::from(self.subsec_nanos / 1_000_000))
+    }
+
+    /// Returns the unix timestamp of the `DateTime` in seconds.
+    ///
+    /// Lossy for sub-microsecond precision, use `unix_timestamp_nanos` for an exact value.
+    pub fn unix_timestamp_f64(&self) -> f64 {
+        self.unix_timestamp as f64 + f64::from(self.subsec_nanos) / f64::from(NANOS_IN_SECOND)
+    }
+
+    /// Returns the whole seconds since the unix epoch.
+    pub fn unix_timestamp_secs(&self) -> i64 {
+        self.unix_timestamp
+    }
+
+    /// Returns the nanoseconds into the current second.
+    pub fn subsec_nanos(&self) -> u32 {
+        self.subsec_nanos
+    }
+
+    /// Returns the nanoseconds since the unix epoch.
+    pub fn unix_timestamp_nanos(&self) -> i128 {
+        i128::from(self.unix_timestamp) * i128::from(NANOS_IN_SECOND)
+            + i128::from(self.subsec_nanos)
+    }
+
     /// Instantiates a new `DateTime` from any supplied unix timestamp.
+    ///
+    /// The timestamp is rounded to the nearest nanosecond.
     pub fn from_timestamp(timestamp: f64) -> DateTime {
-        let (date, new_timestamp, unix_timestamp) = make_now_date(timestamp);
-        let time = make_now_time(new_timestamp);
-        DateTime {
-            date,
-            time,
-            unix_timestamp,
-            timezone: 0.0,
-        }
+        let seconds = timestamp.floor();
+        let nanos = ((timestamp - seconds) * f64::from(NANOS_IN_SECOND)).round() as i64;
+        DateTime::from_timestamp_parts(seconds as i64, 0).add_nanos(i128::from(nanos))
+    }
+
+    /// Instantiates a new `DateTime` from the whole seconds since the unix epoch and the
+    /// nanoseconds into that second.
+    ///
+    /// Nanoseconds larger than a second carry over into the seconds, saturating at the bounds
+    /// of `i64`.
+    pub fn from_timestamp_parts(seconds: i64, nanos: u32) -> DateTime {
+        let mut out = DateTime {
+            date: Date::from_ymd_unchecked(1970, 1, 1),
+            time: Time::MIDNIGHT,
+            unix_timestamp: seconds.saturating_add(i64::from(nanos / NANOS_IN_SECOND)),
+            subsec_nanos: nanos % NANOS_IN_SECOND,
+            offset: 0,
+        };
+        out.update_local_components();
+        out
+    }
+
+    /// Instantiates a new `DateTime` from the nanoseconds since the unix epoch.
+    pub fn from_timestamp_nanos(nanos: i128) -> DateTime {
+        let nanos_in_second = i128::from(NANOS_IN_SECOND);
+        DateTime::from_timestamp_parts(
+            nanos.div_euclid(nanos_in_second) as i64,
+            nanos.rem_euclid(nanos_in_second) as u32,
+        )
+    }
+
+    /// Returns a new `DateTime` moved by the supplied nanoseconds, keeping the UTC offset.
+    pub fn add_nanos(&self, nanos: i128) -> DateTime {
+        let mut out = DateTime::from_timestamp_nanos(self.unix_timestamp_nanos() + nanos);
+        out.offset = self.offset;
+        out.update_local_components();
+        out
+    }
+
+    /// Instantiates a new `DateTime` from a local date and time in the supplied UTC offset in
+    /// seconds.
+    pub fn from_local(date: Date, time: Time, offset: i32) -> DateTime {
+        let local_timestamp = date.days_since_epoch() * SECONDS_IN_DAY
+            + i64::from(time.hour()) * SECONDS_IN_HOUR
+            + i64::from(time.minute()) * SECONDS_IN_MINUTE
+            + i64::from(time.second());
+        let mut out =
+            DateTime::from_timestamp_parts(local_timestamp - i64::from(offset), time.nanosecond());
+        out.with_utc_offset_seconds(offset);
+        out
+    }
+
+    /// Returns a new `DateTime` on the supplied local date, keeping the local time and the UTC
+    /// offset.
+    pub fn with_local_date(&self, date: Date) -> DateTime {
+        let mut out = *self;
+        out.unix_timestamp +=
+            (date.days_since_epoch() - self.date.days_since_epoch()) * SECONDS_IN_DAY;
+        out.update_local_components();
+        out
     }
 
     /// Returns the held `Time` of the `DateTime`.
@@ -100,159 +203,214 @@ impl DateTime {
 
     /// Helper to get local date and time components based on the offset.
     fn get_local_components(&self) -> (Date, Time) {
-        if self.timezone == 0.0 {
-            return (self.date, self.time);
-        }
-        let local_timestamp = self.unix_timestamp + self.timezone * SECONDS_IN_HOUR;
-        let (date, rest_timestamp, _) = make_now_date(local_timestamp);
-        let time = make_now_time(rest_timestamp);
-        (date, time)
+        (self.date, self.time)
+    }
+
+    /// Recalculates the held local date and time from the unix timestamp and the offset.
+    ///
+    /// Needs to be called after every change to either.
+    fn update_local_components(&mut self) {
+        let local_timestamp = self.unix_timestamp + i64::from(self.offset);
+        let (date, rest_timestamp) = make_now_date(local_timestamp);
+        self.date = date;
+        self.time = make_now_time(rest_timestamp, self.subsec_nanos);
+    }
+
+    /// Returns the sign, hours and minutes of the held UTC offset.
+    fn offset_parts(&self) -> (char, i32, i32) {
+        let sign = if self.offset >= 0 { '+' } else { '-' };
+        let abs_offset = self.offset.abs();
+        let hours = abs_offset / SECONDS_IN_HOUR as i32;
+        let minutes = (abs_offset % SECONDS_IN_HOUR as i32) / SECONDS_IN_MINUTE as i32;
+        (sign, hours, minutes)
     }
 
     /// Returns the formatted string of the `DateTime` according to the supplied formatter.
     ///
     /// Used for formatting the entirety of the `DateTime`.
     pub fn format(&self, formatter: &str) -> String {
-        let format_tokens = tokenize(formatter);
+        self.format_tokens(&tokenize(formatter))
+    }
+
+    /// Returns the formatted string of the `DateTime` according to the compiled format.
+    pub fn format_with(&self, format: &Format) -> String {
+        self.format_tokens(format.tokens())
+    }
+
+    /// Writes the `DateTime` according to the compiled format into `out`, without allocating.
+    pub fn format_into<W: std::fmt::Write>(
+        &self,
+        out: &mut W,
+        format: &Format,
+    ) -> std::fmt::Result {
+        self.write_tokens(format.tokens(), out)
+    }
+
+    fn format_tokens(&self, tokens: &[Token]) -> String {
         let mut formatted_string = String::new();
+        // Writing into a `String` cannot fail
+        let _ = self.write_tokens(tokens, &mut formatted_string);
+        formatted_string
+    }
+
+    fn write_tokens<W: std::fmt::Write>(&self, tokens: &[Token], out: &mut W) -> std::fmt::Result {
         let (local_date, local_time) = self.get_local_components();
-        for token in format_tokens {
+        for token in tokens {
             match token {
                 Token::Unit(unit) => match unit {
                     Unit::Timezone => {
-                        if self.timezone == 0.0 {
-                            formatted_string.push_str("Coordinated Universal Time");
+                        if self.offset == 0 {
+                            out.write_str("Coordinated Universal Time")?;
                         } else {
-                            let sign = if self.timezone >= 0.0 { "+" } else { "-" };
-                            let abs_offset = self.timezone.abs();
-                            let hours = abs_offset.trunc() as i32;
-                            let minutes = (abs_offset.fract() * 60.0).round() as i32;
-                            formatted_string.push_str(&format!("GMT{sign}{hours:02}:{minutes:02}"));
+                            let (sign, hours, minutes) = self.offset_parts();
+                            write!(out, "GMT{sign}{hours:02}:{minutes:02}")?;
                         }
                     }
                     Unit::Millisecond => {
-                        formatted_string.push_str(&format!("{:03}", local_time.subseconds));
+                        write_fraction_digits(out, local_time.nanosecond(), 3, false)?;
+                    }
+                    Unit::Microsecond => {
+                        write_fraction_digits(out, local_time.nanosecond(), 6, false)?;
+                    }
+                    Unit::Nanosecond => {
+                        write_fraction_digits(out, local_time.nanosecond(), 9, false)?;
+                    }
+                    Unit::TrimmedMillisecond => {
+                        write_fraction_digits(out, local_time.nanosecond(), 3, true)?;
+                    }
+                    Unit::TrimmedMicrosecond => {
+                        write_fraction_digits(out, local_time.nanosecond(), 6, true)?;
+                    }
+                    Unit::TrimmedNanosecond => {
+                        write_fraction_digits(out, local_time.nanosecond(), 9, true)?;
                     }
                     Unit::ShortSecond => {
-                        formatted_string.push_str(&format!("{:01}", local_time.second));
+                        write!(out, "{:01}", local_time.second())?;
                     }
                     Unit::Second => {
-                        formatted_string.push_str(&format!("{:02}", local_time.second));
+                        write!(out, "{:02}", local_time.second())?;
                     }
                     Unit::ShortMinute => {
-                        formatted_string.push_str(&format!("{:01}", local_time.minute));
+                        write!(out, "{:01}", local_time.minute())?;
                     }
                     Unit::Minute => {
-                        formatted_string.push_str(&format!("{:02}", local_time.minute));
+                        write!(out, "{:02}", local_time.minute())?;
                     }
                     Unit::ShortHour => {
-                        formatted_string.push_str(&format!("{:01}", local_time.hour));
+                        write!(out, "{:01}", local_time.hour())?;
                     }
                     Unit::Hour => {
-                        formatted_string.push_str(&format!("{:02}", local_time.hour));
+                        write!(out, "{:02}", local_time.hour())?;
                     }
                     Unit::ShortDay => {
-                        formatted_string.push_str(&format!("{:01}", local_date.day));
+                        write!(out, "{:01}", local_date.day())?;
                     }
                     Unit::Day => {
-                        formatted_string.push_str(&format!("{:02}", local_date.day));
+                        write!(out, "{:02}", local_date.day())?;
                     }
                     Unit::ShortNumMonth => {
-                        formatted_string.push_str(&format!("{:01}", local_date.month));
+                        write!(out, "{:01}", local_date.month())?;
                     }
                     Unit::NumMonth => {
-                        formatted_string.push_str(&format!("{:02}", local_date.month));
+                        write!(out, "{:02}", local_date.month())?;
                     }
                     Unit::ShortWordMonth => {
-                        const MONTHS: [&str; 12] = [
-                            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct",
-                            "Nov", "Dec",
-                        ];
-                        formatted_string.push_str(MONTHS[local_date.month as usize - 1]);
+                        out.write_str(Month::ALL[local_date.month() as usize - 1].short_name())?;
                     }
                     Unit::WordMonth => {
-                        const MONTHS: [&str; 12] = [
-                            "January",
-                            "February",
-                            "March",
-                            "April",
-                            "May",
-                            "June",
-                            "July",
-                            "August",
-                            "September",
-                            "October",
-                            "November",
-                            "December",
-                        ];
-                        formatted_string.push_str(MONTHS[local_date.month as usize - 1]);
+                        out.write_str(Month::ALL[local_date.month() as usize - 1].name())?;
                     }
                     Unit::ShortYear => {
-                        formatted_string.push_str(&format!(
-                            "{:01}",
-                            local_date
-                                .year
-                                .to_string()
-                                .chars()
-                                .last()
-                                .expect("No Year found!")
-                        ));
+                        write!(out, "{:01}", local_date.year() % 10)?;
                     }
                     Unit::Year => {
-                        let year_tmp: String =
-                            local_date.year.to_string().chars().rev().take(2).collect();
-                        let year = year_tmp.chars().rev().collect::<String>();
-                        formatted_string.push_str(&year);
+                        write!(out, "{:02}", local_date.year() % 100)?;
                     }
                     Unit::FullYear => {
-                        formatted_string.push_str(&format!("{}", local_date.year));
+                        write!(out, "{:04}", local_date.year())?;
                     }
                     Unit::ShortWeekDay => {
-                        let week_day_num =
-                            week_day(self.unix_timestamp + self.timezone * SECONDS_IN_HOUR);
-                        let week_day = match week_day_num {
-                            1 => "Mon",
-                            2 => "Tue",
-                            3 => "Wed",
-                            4 => "Thu",
-                            5 => "Fri",
-                            6 => "Sat",
-                            7 => "Sun",
-                            // Should really never happen!
-                            _ => "Error",
-                        };
-                        formatted_string.push_str(week_day);
+                        out.write_str(local_date.weekday().short_name())?;
                     }
                     Unit::WeekDay => {
-                        let week_day_num =
-                            week_day(self.unix_timestamp + self.timezone * SECONDS_IN_HOUR);
-                        let week_day = match week_day_num {
-                            1 => "Monday",
-                            2 => "Tuesday",
-                            3 => "Wednesday",
-                            4 => "Thursday",
-                            5 => "Friday",
-                            6 => "Saturday",
-                            7 => "Sunday",
-                            // Should really never happen!
-                            _ => "Error",
-                        };
-                        formatted_string.push_str(week_day);
+                        out.write_str(local_date.weekday().name())?;
                     }
                     Unit::ShortWeekNumber => {
-                        formatted_string.push_str(&format!("{:01}", local_date.get_weeknumber()));
+                        write!(out, "{:01}", local_date.get_weeknumber())?;
                     }
                     Unit::WeekNumber => {
-                        formatted_string.push_str(&format!("{:02}", local_date.get_weeknumber()));
+                        write!(out, "{:02}", local_date.get_weeknumber())?;
+                    }
+                    Unit::OrdinalDay => {
+                        write!(out, "{:03}", local_date.ordinal_day())?;
+                    }
+                    Unit::ShortIsoYear => {
+                        write!(out, "{:02}", local_date.iso_week().year() % 100)?;
+                    }
+                    Unit::IsoYear => {
+                        write!(out, "{:04}", local_date.iso_week().year())?;
+                    }
+                    Unit::SpacePaddedDay => {
+                        write!(out, "{:2}", local_date.day())?;
+                    }
+                    Unit::SpacePaddedOrdinalDay => {
+                        write!(out, "{:3}", local_date.ordinal_day())?;
+                    }
+                    Unit::OrdinalSuffixDay => {
+                        write!(
+                            out,
+                            "{}{}",
+                            local_date.day(),
+                            ordinal_suffix(local_date.day())
+                        )?;
+                    }
+                    Unit::SpacePaddedNumMonth => {
+                        write!(out, "{:2}", local_date.month())?;
+                    }
+                    Unit::Quarter => {
+                        write!(out, "{}", (local_date.month() - 1) / 3 + 1)?;
+                    }
+                    Unit::SpacePaddedWeekNumber => {
+                        write!(out, "{:2}", local_date.get_weeknumber())?;
+                    }
+                    Unit::SpacePaddedHour => {
+                        write!(out, "{:2}", local_time.hour())?;
+                    }
+                    Unit::ShortTwelveHour => {
+                        write!(out, "{}", twelve_hour(local_time.hour()))?;
+                    }
+                    Unit::TwelveHour => {
+                        write!(out, "{:02}", twelve_hour(local_time.hour()))?;
+                    }
+                    Unit::SpacePaddedTwelveHour => {
+                        write!(out, "{:2}", twelve_hour(local_time.hour()))?;
+                    }
+                    Unit::SpacePaddedMinute => {
+                        write!(out, "{:2}", local_time.minute())?;
+                    }
+                    Unit::SpacePaddedSecond => {
+                        write!(out, "{:2}", local_time.second())?;
+                    }
+                    Unit::UpperMeridiem => {
+                        out.write_str(if local_time.hour() < 12 { "AM" } else { "PM" })?;
+                    }
+                    Unit::LowerMeridiem => {
+                        out.write_str(if local_time.hour() < 12 { "am" } else { "pm" })?;
+                    }
+                    Unit::TimestampSeconds => {
+                        write!(out, "{}", self.unix_timestamp)?;
+                    }
+                    Unit::TimestampMillis => {
+                        write!(out, "{}", self.unix_timestamp_millis())?;
                     }
                 },
                 Token::Separator(separator) => {
-                    formatted_string.push_str(&separator.separator_symbol);
+                    out.write_str(&separator.separator_symbol)?;
                 }
             }
         }
 
-        formatted_string
+        Ok(())
     }
 
     /// Returns the week number of the date according to ISO 8601.
@@ -268,9 +426,17 @@ impl DateTime {
         self.with_utc_offset(timezone.get_utc_offset());
     }
 
-    /// Mutates the `DateTime` to be in the supplied UTC offset.
+    /// Mutates the `DateTime` to be in the supplied UTC offset in hours.
+    ///
+    /// The offset is rounded to the nearest second.
     pub fn with_utc_offset(&mut self, offset: f64) {
-        self.timezone = offset;
+        self.with_utc_offset_seconds((offset * SECONDS_IN_HOUR as f64).round() as i32);
+    }
+
+    /// Mutates the `DateTime` to be in the supplied UTC offset in seconds.
+    pub fn with_utc_offset_seconds(&mut self, offset: i32) {
+        self.offset = offset;
+        self.update_local_components();
     }
 
     /// Mutates the `DateTime` to use the system's local timezone.
@@ -282,211 +448,131 @@ impl DateTime {
 
     /// Returns the current UTC offset in hours.
     pub fn get_utc_offset(&self) -> f64 {
-        self.timezone
+        f64::from(self.offset) / SECONDS_IN_HOUR as f64
     }
 
-    /// Returns the formatted string of the `DateTime` according to RFC 3339.
-    pub fn to_rfc3339(&self) -> String {
-        let (local_date, local_time) = self.get_local_components();
-        let mut formatted_string = format!(
-            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
-            local_date.year,
-            local_date.month,
-            local_date.day,
-            local_time.hour,
-            local_time.minute,
-            local_time.second
-        );
-        if local_time.subseconds > 0 {
-            let sub = format!("{:09}", local_time.subseconds);
-            let trimmed = sub.trim_end_matches('0');
-            if !trimmed.is_empty() {
-                formatted_string.push('.');
-                formatted_string.push_str(trimmed);
-            }
-        }
-        if self.timezone == 0.0 {
-            formatted_string.push('Z');
-        } else {
-            let sign = if self.timezone.is_sign_positive() {
-                "+"
-            } else {
-                "-"
-            };
-            let abs_offset = self.timezone.abs();
-            let hours = abs_offset.trunc() as i32;
-            let minutes = (abs_offset.fract() * 60.0).round() as i32;
-            formatted_string.push_str(&format!("{sign}{hours:02}:{minutes:02}"));
-        }
-        formatted_string
+    /// Returns the current UTC offset in seconds.
+    pub fn get_utc_offset_seconds(&self) -> i32 {
+        self.offset
     }
 
-    /// Parses an RFC 3339 string into a `DateTime`.
-    pub fn from_rfc3339(s: &str) -> Option<DateTime> {
-        if s.len() < 16 {
-            return None;
-        }
-        let year = s[0..4].parse::<u16>().ok()?;
-        if s.as_bytes()[4] != b'-' {
-            return None;
-        }
-        let month = s[5..7].parse::<u8>().ok()?;
-        if s.as_bytes()[7] != b'-' {
-            return None;
-        }
-        let day = s[8..10].parse::<u8>().ok()?;
-
-        let t_sep = s.as_bytes()[10];
-        if t_sep != b'T' && t_sep != b't' && t_sep != b' ' {
-            return None;
-        }
-
-        let hour = s[11..13].parse::<u8>().ok()?;
-        if s.as_bytes()[13] != b':' {
-            return None;
-        }
-        let minute = s[14..16].parse::<u8>().ok()?;
-
-        let mut pos;
-        let second = if s.len() < 19 {
-            pos = 17;
-            0
-        } else {
-            if s.as_bytes()[16] == b'Z'
-                || s.as_bytes()[16] == b'z'
-                || s.as_bytes()[16] == b'+'
-                || s.as_bytes()[16] == b'-'
-            {
-                pos = 16;
-                0
-            } else {
-                pos = 19;
-                s[17..19].parse::<u8>().ok()?
-            }
-        };
+    /// Returns the formatted string of the `DateTime` according to RFC 3339.
+    pub fn to_rfc3339(self) -> String {
+        self.to_rfc3339_with(Rfc3339Options::default())
+    }
 
-        let mut subseconds = 0.0;
-        if pos < s.len() && s.as_bytes()[pos] == b'.' {
-            pos += 1;
-            let start = pos;
-            while pos < s.len() && s.as_bytes()[pos].is_ascii_digit() {
-                pos += 1;
-            }
-            let frac_str = &s[start..pos];
-            if !frac_str.is_empty() {
-                let mut f = 0.0;
-                let mut div = 10.0;
-                for b in frac_str.as_bytes() {
-                    f += f64::from(b - b'0') / div;
-                    div *= 10.0;
-                }
-                subseconds = f;
-            }
-        }
+    /// Returns the formatted string of the `DateTime` according to RFC 3339, with the fraction
+    /// digits and the writing of UTC given by `options`.
+    pub fn to_rfc3339_with(self, options: Rfc3339Options) -> String {
+        let mut formatted_string = String::with_capacity(RFC3339_MAX_LEN);
+        // Writing into a `String` cannot fail
+        let _ = self.fmt_rfc3339(&mut formatted_string, options);
+        formatted_string
+    }
 
-        let offset;
-        if pos < s.len() {
-            let suffix = &s[pos..];
-            if suffix.eq_ignore_ascii_case("Z") {
-                offset = 0.0;
-            } else if suffix.len() == 6 {
-                let sign = match suffix.as_bytes()[0] {
-                    b'+' => 1.0,
-                    b'-' => -1.0,
-                    _ => return None,
-                };
-                let h = suffix[1..3].parse::<f64>().ok()?;
-                if suffix.as_bytes()[3] != b':' {
-                    return None;
-                }
-                let m = suffix[4..6].parse::<f64>().ok()?;
-                offset = sign * (h + m / 60.0);
-            } else {
-                return None;
-            }
-        } else {
-            offset = 0.0;
-        }
+    /// Writes the `DateTime` according to RFC 3339 into `out`, without allocating.
+    pub fn fmt_rfc3339<W: std::fmt::Write>(
+        &self,
+        out: &mut W,
+        options: Rfc3339Options,
+    ) -> std::fmt::Result {
+        let (local_date, local_time) = self.get_local_components();
+        write!(
+            out,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            local_date.year(),
+            local_date.month(),
+            local_date.day(),
+            local_time.hour(),
+            local_time.minute(),
+            local_time.second()
+        )?;
+        write_fraction(out, local_time.nanosecond(), options.seconds)?;
+        self.write_offset(out, ":", options.utc_as_z)
+    }
 
-        if year < 1970
-            || month < 1
-            || month > 12
-            || day < 1
-            || day > 31
-            || hour > 23
-            || minute > 59
-            || second > 60
-        {
-            return None;
+    /// Writes `Z` for UTC if `utc_as_z`, or else the sign, hours and minutes of the offset split
+    /// by `separator`.
+    fn write_offset<W: std::fmt::Write>(
+        &self,
+        out: &mut W,
+        separator: &str,
+        utc_as_z: bool,
+    ) -> std::fmt::Result {
+        if self.offset == 0 && utc_as_z {
+            return out.write_char('Z');
         }
+        let (sign, hours, minutes) = self.offset_parts();
+        write!(out, "{sign}{hours:02}{separator}{minutes:02}")
+    }
 
-        let mut unix_timestamp =
-            DateTime::from_ymd_hms(year, month, day, hour, minute, second).unix_timestamp;
-        unix_timestamp -= offset * SECONDS_IN_HOUR;
-        unix_timestamp += subseconds;
+    /// Parses an RFC 3339 string into a `DateTime`.
+    ///
+    /// Seconds and the offset may be omitted, a missing offset is read as UTC.
+    /// A leap second (`:60`) is folded into the first second of the following minute.
+    pub fn from_rfc3339(s: &str) -> Result<DateTime, HoraeError> {
+        parse_rfc3339(s, s.len()).map_err(HoraeError::from)
+    }
 
-        let mut dt = DateTime::from_timestamp(unix_timestamp);
-        dt.with_utc_offset(offset);
-        Some(dt)
+    /// Parses an RFC 3339 string into a `DateTime`, with a positioned error on failure.
+    pub fn parse_rfc3339(s: &str) -> Result<DateTime, ParseError> {
+        parse_rfc3339(s, s.len()).map_err(ParseError::from)
     }
 
     /// Returns the formatted string of the `DateTime` according to RFC 9557.
-    pub fn to_rfc9557(&self) -> String {
+    pub fn to_rfc9557(self) -> String {
         let mut s = self.to_rfc3339();
-        if self.timezone != 0.0 {
-            let sign = if self.timezone >= 0.0 { "+" } else { "-" };
-            let abs_offset = self.timezone.abs();
-            let hours = abs_offset.trunc() as i32;
-            let minutes = (abs_offset.fract() * 60.0).round() as i32;
-            s.push_str(&format!("[{sign}{hours:02}:{minutes:02}]"));
+        if self.offset != 0 {
+            let (sign, hours, minutes) = self.offset_parts();
+            // Writing into a `String` cannot fail
+            let _ = write!(s, "[{sign}{hours:02}:{minutes:02}]");
         }
         s
     }
 
     /// Parses an RFC 9557 string into a `DateTime`.
-    pub fn from_rfc9557(s: &str) -> Option<DateTime> {
-        let end_of_rfc3339 = s.find('[').unwrap_or(s.len());
-        let dt = DateTime::from_rfc3339(&s[..end_of_rfc3339])?;
-
-        let mut pos = end_of_rfc3339;
-        while pos < s.len() {
-            if s.as_bytes()[pos] != b'[' {
-                return None;
-            }
-            let end = s[pos..].find(']')? + pos;
-            let tag = &s[pos + 1..end];
+    pub fn from_rfc9557(s: &str) -> Result<DateTime, HoraeError> {
+        parse_rfc9557(s).map_err(HoraeError::from)
+    }
 
-            let critical = tag.starts_with('!');
-            let tag_content = if critical { &tag[1..] } else { tag };
+    /// Parses an RFC 9557 string into a `DateTime`, with a positioned error on failure.
+    pub fn parse_rfc9557(s: &str) -> Result<DateTime, ParseError> {
+        parse_rfc9557(s).map_err(ParseError::from)
+    }
 
-            if tag_content.contains('=') {
-                if critical {
-                    return None;
-                }
-            } else if critical {
-                // Timezone name or offset tag
-                if (tag_content.starts_with('+') || tag_content.starts_with('-'))
-                    && tag_content.len() == 6
-                {
-                    let sign = if tag_content.starts_with('+') {
-                        1.0
-                    } else {
-                        -1.0
-                    };
-                    let h = tag_content[1..3].parse::<f64>().ok()?;
-                    let m = tag_content[4..6].parse::<f64>().ok()?;
-                    let tag_offset = sign * (h + m / 60.0);
-                    if (tag_offset - dt.timezone).abs() > 1e-9 {
-                        return None;
-                    }
-                } else {
-                    return None;
-                }
-            }
-            pos = end + 1;
+    /// Returns the formatted string of the `DateTime` according to ISO 8601, in basic or
+    /// extended format.
+    pub fn to_iso8601(self, format: Iso8601Format) -> String {
+        if format == Iso8601Format::Extended {
+            return self.to_rfc3339();
         }
+        let mut formatted_string = String::with_capacity(RFC3339_MAX_LEN);
+        // Writing into a `String` cannot fail
+        let _ = self.fmt_iso8601_basic(&mut formatted_string);
+        formatted_string
+    }
+
+    /// Writes the `DateTime` in the ISO 8601 basic format into `out`, without allocating.
+    fn fmt_iso8601_basic<W: std::fmt::Write>(&self, out: &mut W) -> std::fmt::Result {
+        let (local_date, local_time) = self.get_local_components();
+        write!(
+            out,
+            "{:04}{:02}{:02}T{:02}{:02}{:02}",
+            local_date.year(),
+            local_date.month(),
+            local_date.day(),
+            local_time.hour(),
+            local_time.minute(),
+            local_time.second()
+        )?;
+        write_fraction(out, local_time.nanosecond(), SecondsFormat::Trimmed)?;
+        self.write_offset(out, "", true)
+    }
 
-        Some(dt)
+    /// Parses an ISO 8601 string into a `DateTime` and the precision it was given in, with a
+    /// positioned error on failure.
+    pub fn parse_iso8601(s: &str) -> Result<(DateTime, Iso8601Precision), ParseError> {
+        parse_iso8601(s).map_err(ParseError::from)
     }
 
     /// Instantiates a new `DateTime` with the specified date and time
@@ -504,53 +590,30 @@ impl DateTime {
         minute: u8,
         second: u8,
     ) -> DateTime {
-        assert!(year >= 1970);
-        assert!((1..=12).contains(&month));
-        assert!((1..=31).contains(&day));
-        assert!(hour <= 23);
-        assert!(minute <= 59);
-        assert!(second <= 59);
-        let time = Time::from((hour, minute, second));
-
-        let years = year - 1970;
-        let leap_years = {
-            let tmp = leap_years_since_epoch(years);
-            if month <= 2 && is_this_year_leap_year(year) {
-                tmp - 1
-            } else {
-                tmp
-            }
-        };
-        let years_in_sec = f64::from(years) * SECONDS_IN_YEAR;
-        let months_in_sec: f64 = {
-            let mut total_days: u16 = 0;
-            for i in 1..month {
-                total_days += u16::from(days_in_month(i));
-            }
-            f64::from(total_days) * SECONDS_IN_DAY
-        };
+        DateTime::try_from_ymd_hms(year, month, day, hour, minute, second)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
 
-        // The calculated date is now on the first of this month.
-        // Because of this we need to subtract 1 from day
-        let days_in_sec = {
-            let total_days = u16::from(day) + leap_years - 1;
-            f64::from(total_days) * SECONDS_IN_DAY
-        };
-        let hours_in_sec = f64::from(hour) * SECONDS_IN_HOUR;
-        let minutes_in_sec: f64 = f64::from(minute) * f64::from(SECONDS_IN_MINUTE);
-        let unix_timestamp = years_in_sec
-            + months_in_sec
-            + days_in_sec
-            + hours_in_sec
-            + minutes_in_sec
-            + f64::from(second);
-        let date = Date::from((year, month, day, unix_timestamp));
-        DateTime {
-            date,
-            time,
-            unix_timestamp,
-            timezone: 0.0,
-        }
+    /// Instantiates a new `DateTime` with the specified date and time, returning an error
+    /// naming the first field out of range.
+    ///
+    /// This function assumes that the passed in data is in UTC.
+    pub fn try_from_ymd_hms(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime, HoraeError> {
+        validate_date(year, month, day)?;
+        validate_time(hour, minute, second)?;
+        let days = days_from_civil(i64::from(year), month, day);
+        let unix_timestamp = days * SECONDS_IN_DAY
+            + i64::from(hour) * SECONDS_IN_HOUR
+            + i64::from(minute) * SECONDS_IN_MINUTE
+            + i64::from(second);
+        Ok(DateTime::from_timestamp_parts(unix_timestamp, 0))
     }
 
     /// Instantiates a new `DateTime` with the specified date, time and timezone.
@@ -596,15 +659,53 @@ impl DateTime {
         second: u8,
         offset: f64,
     ) -> DateTime {
-        let mut out = DateTime::from_ymd_hms(year, month, day, hour, minute, second);
-        out.unix_timestamp -= offset * SECONDS_IN_HOUR;
+        DateTime::try_from_ymd_hms_offset(year, month, day, hour, minute, second, offset)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Instantiates a new `DateTime` with the specified date, time and UTC offset, returning an
+    /// error naming the first field out of range.
+    ///
+    /// This function assumes that the passed in data is in local time.
+    pub fn try_from_ymd_hms_offset(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        offset: f64,
+    ) -> Result<DateTime, HoraeError> {
+        validate_utc_offset(offset)?;
+        let mut out = DateTime::try_from_ymd_hms(year, month, day, hour, minute, second)?;
         out.with_utc_offset(offset);
-        out
+        out.unix_timestamp -= i64::from(out.offset);
+        out.update_local_components();
+        Ok(out)
     }
 }
 
 // Display implementation
 
+/// Writes a fraction of a second with the digits of `seconds`, nothing for `Seconds` or a whole
+/// second that is trimmed.
+fn write_fraction<W: std::fmt::Write>(
+    out: &mut W,
+    nanos: u32,
+    seconds: SecondsFormat,
+) -> std::fmt::Result {
+    let (digits, trim) = match seconds {
+        SecondsFormat::Seconds => return Ok(()),
+        SecondsFormat::Trimmed if nanos == 0 => return Ok(()),
+        SecondsFormat::Millis => (3, false),
+        SecondsFormat::Micros => (6, false),
+        SecondsFormat::Nanos => (9, false),
+        SecondsFormat::Trimmed => (9, true),
+    };
+    out.write_char('.')?;
+    write_fraction_digits(out, nanos, digits, trim)
+}
+
 impl std::fmt::Display for DateTime {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         let (date, time) = self.get_local_components();
@@ -614,18 +715,31 @@ impl std::fmt::Display for DateTime {
 
 impl PartialOrd for DateTime {
     fn partial_cmp(&self, other: &DateTime) -> Option<std::cmp::Ordering> {
-        self.unix_timestamp.partial_cmp(&other.unix_timestamp)
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DateTime {
+    fn cmp(&self, other: &DateTime) -> std::cmp::Ordering {
+        (self.unix_timestamp, self.subsec_nanos).cmp(&(other.unix_timestamp, other.subsec_nanos))
     }
 }
 
 impl PartialEq for DateTime {
     fn eq(&self, other: &DateTime) -> bool {
-        self.unix_timestamp == other.unix_timestamp
+        self.unix_timestamp == other.unix_timestamp && self.subsec_nanos == other.subsec_nanos
     }
 }
 
 impl Eq for DateTime {}
 
+impl std::hash::Hash for DateTime {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.unix_timestamp.hash(state);
+        self.subsec_nanos.hash(state);
+    }
+}
+
 #[test]
 fn from_timestamp() {
     let ts_1970_01_01_00_00_00 = DateTime::from_timestamp(0.0);
@@ -645,7 +759,7 @@ fn from_timestamp() {
     );
     let ts_1970_01_14_02_03_10 = DateTime::from_timestamp(1130590.958881855);
     assert_eq!(
-        "1970-01-14 02:03:10.958881855".to_string(),
+        "1970-01-14 02:03:10.958".to_string(),
         format!("{}", ts_1970_01_14_02_03_10)
     );
     let ts_2054_06_10_08_36_47 = DateTime::from_timestamp(2664686207.0);
@@ -0,0 +1,174 @@
+use crate::{HoraeError, ParseComponent, ParseError};
+
+use super::common::days_in_month_of_year;
+
+const NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// A month of the year.
+///
+/// Adding or subtracting months wraps around the year.
+///
+/// # Examples
+/// ```rust
+/// use horae::Month;
+///
+/// let month: Month = "NOV".parse().unwrap();
+/// assert_eq!(month, Month::November);
+/// assert_eq!(month + 3, Month::February);
+/// assert_eq!(month.number(), 11);
+/// assert_eq!(Month::try_from(2).unwrap().days_in_year(2024), 29);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Month {
+    January,
+    February,
+    March,
+    April,
+    May,
+    June,
+    July,
+    August,
+    September,
+    October,
+    November,
+    December,
+}
+
+impl Month {
+    /// Every month, starting with January.
+    pub const ALL: [Month; 12] = [
+        Month::January,
+        Month::February,
+        Month::March,
+        Month::April,
+        Month::May,
+        Month::June,
+        Month::July,
+        Month::August,
+        Month::September,
+        Month::October,
+        Month::November,
+        Month::December,
+    ];
+
+    /// Returns the next month, January after December.
+    #[must_use]
+    pub fn succ(self) -> Month {
+        self + 1
+    }
+
+    /// Returns the previous month, December before January.
+    #[must_use]
+    pub fn pred(self) -> Month {
+        self - 1
+    }
+
+    /// Returns the number of the month, January is 1.
+    #[must_use]
+    pub fn number(self) -> u8 {
+        self as u8 + 1
+    }
+
+    /// Returns the number of days in the month of the supplied year, respecting leap years.
+    #[must_use]
+    pub fn days_in_year(self, year: u16) -> u8 {
+        days_in_month_of_year(year, self.number())
+    }
+
+    /// Returns the full English name, like `January`.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        NAMES[self as usize]
+    }
+
+    /// Returns the English name shortened to three letters, like `Jan`.
+    #[must_use]
+    pub fn short_name(self) -> &'static str {
+        &NAMES[self as usize][..3]
+    }
+
+    /// Reads a full or three letter English name in any case.
+    pub(crate) fn from_name(name: &str) -> Option<Month> {
+        Month::ALL.into_iter().find(|month| {
+            name.eq_ignore_ascii_case(month.name()) || name.eq_ignore_ascii_case(month.short_name())
+        })
+    }
+}
+
+impl TryFrom<u8> for Month {
+    type Error = HoraeError;
+
+    /// Returns the month of the supplied number, January is 1.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::InvalidMonth` if the number is outside of 1-12.
+    fn try_from(number: u8) -> Result<Month, HoraeError> {
+        number
+            .checked_sub(1)
+            .and_then(|index| Month::ALL.get(usize::from(index)))
+            .copied()
+            .ok_or(HoraeError::InvalidMonth(number))
+    }
+}
+
+impl From<Month> for u8 {
+    fn from(month: Month) -> u8 {
+        month.number()
+    }
+}
+
+impl std::ops::Add<i64> for Month {
+    type Output = Month;
+
+    /// Returns the month the supplied number of months later, wrapping around the year.
+    fn add(self, months: i64) -> Month {
+        Month::ALL[(self as i64 + months.rem_euclid(12)) as usize % 12]
+    }
+}
+
+impl std::ops::Sub<i64> for Month {
+    type Output = Month;
+
+    /// Returns the month the supplied number of months earlier, wrapping around the year.
+    fn sub(self, months: i64) -> Month {
+        self + (12 - months.rem_euclid(12))
+    }
+}
+
+impl std::str::FromStr for Month {
+    type Err = HoraeError;
+
+    /// Parses a full or three letter English name in any case, like `January`, `jan` or `JAN`.
+    fn from_str(s: &str) -> Result<Month, HoraeError> {
+        Month::from_name(s).ok_or_else(|| {
+            HoraeError::Parse(ParseError::new(
+                s,
+                ParseComponent::Month,
+                0,
+                "a month like Jan or January",
+            ))
+        })
+    }
+}
+
+// Display implementation
+
+impl std::fmt::Display for Month {
+    /// Formats the full English name, like `January`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,720 @@
+use crate::{HoraeError, ParseComponent, ParseError};
+
+use super::{
+    DateTime,
+    common::{
+        NANOS_IN_SECOND, SECONDS_IN_DAY, SECONDS_IN_HOUR, SECONDS_IN_MINUTE, days_from_civil,
+        validate_date, validate_time,
+    },
+    date::Date,
+    iso_week::IsoWeek,
+    iso8601::Iso8601Precision,
+    time::Time,
+    weekday::Weekday,
+};
+
+/// Error of a failed parse.
+///
+/// Holds the positioned `ParseError`, and the `HoraeError` of the field if a component could be
+/// read but is out of range.
+#[derive(Debug)]
+pub struct ParseFailure {
+    pub error: ParseError,
+    pub field: Option<HoraeError>,
+}
+
+impl From<ParseFailure> for HoraeError {
+    fn from(failure: ParseFailure) -> HoraeError {
+        failure.field.unwrap_or(HoraeError::Parse(failure.error))
+    }
+}
+
+impl From<ParseFailure> for ParseError {
+    fn from(failure: ParseFailure) -> ParseError {
+        failure.error
+    }
+}
+
+/// Reads a string byte by byte, keeping track of the position for error reporting.
+///
+/// Only ever compares bytes, so non-ASCII input can never cause a panic.
+pub struct Cursor<'a> {
+    input: &'a str,
+    bytes: &'a [u8],
+    pub pos: usize,
+    end: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(input: &'a str) -> Cursor<'a> {
+        Cursor::with_end(input, input.len())
+    }
+
+    /// Creates a `Cursor` that treats everything from `end` onward as not part of the input.
+    pub fn with_end(input: &'a str, end: usize) -> Cursor<'a> {
+        Cursor {
+            input,
+            bytes: input.as_bytes(),
+            pos: 0,
+            end,
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.pos >= self.end
+    }
+
+    pub fn peek(&self) -> Option<u8> {
+        if self.is_done() {
+            None
+        } else {
+            Some(self.bytes[self.pos])
+        }
+    }
+
+    pub fn error(
+        &self,
+        component: ParseComponent,
+        position: usize,
+        expected: &'static str,
+    ) -> ParseFailure {
+        ParseFailure {
+            error: ParseError::new(self.input, component, position, expected),
+            field: None,
+        }
+    }
+
+    /// Creates the error of a component that was read but is out of range.
+    pub fn range_error(
+        &self,
+        component: ParseComponent,
+        position: usize,
+        expected: &'static str,
+        field: HoraeError,
+    ) -> ParseFailure {
+        ParseFailure {
+            error: ParseError::new(self.input, component, position, expected),
+            field: Some(field),
+        }
+    }
+
+    /// Returns the number of consecutive ASCII digits from the current position.
+    pub fn digit_run(&self) -> usize {
+        self.bytes[self.pos.min(self.end)..self.end]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    }
+
+    /// Consumes `expected` if the input continues with it.
+    pub fn eat_str(&mut self, expected: &str) -> bool {
+        let end = self.pos + expected.len();
+        if end <= self.end && &self.bytes[self.pos..end] == expected.as_bytes() {
+            self.pos = end;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes and returns the ASCII letters from the current position.
+    pub fn letters(&mut self) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(|b| b.is_ascii_alphabetic()) {
+            self.pos += 1;
+        }
+        &self.input[start..self.pos]
+    }
+
+    /// Consumes one byte if it is any of `expected`.
+    pub fn eat(&mut self, expected: &[u8]) -> Option<u8> {
+        let b = self.peek()?;
+        if expected.contains(&b) {
+            self.pos += 1;
+            Some(b)
+        } else {
+            None
+        }
+    }
+
+    /// Consumes one byte that has to be any of `expected`.
+    pub fn expect(
+        &mut self,
+        expected: &[u8],
+        component: ParseComponent,
+        description: &'static str,
+    ) -> Result<u8, ParseFailure> {
+        self.eat(expected)
+            .ok_or_else(|| self.error(component, self.pos, description))
+    }
+
+    /// Reads exactly `len` ASCII digits.
+    pub fn digits(
+        &mut self,
+        len: usize,
+        component: ParseComponent,
+        description: &'static str,
+    ) -> Result<u32, ParseFailure> {
+        let start = self.pos;
+        let mut out = 0;
+        for _ in 0..len {
+            match self.peek() {
+                Some(b) if b.is_ascii_digit() => {
+                    out = out * 10 + u32::from(b - b'0');
+                    self.pos += 1;
+                }
+                _ => {
+                    self.pos = start;
+                    return Err(self.error(component, start, description));
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Reads one or more ASCII digits as a fraction of a second in nanoseconds.
+    ///
+    /// Digits past nanosecond precision are truncated.
+    pub fn fraction(&mut self) -> Result<u32, ParseFailure> {
+        let start = self.pos;
+        let mut nanos = 0;
+        let mut scale = NANOS_IN_SECOND;
+        while let Some(b) = self.eat(b"0123456789") {
+            scale /= 10;
+            nanos += u32::from(b - b'0') * scale;
+        }
+        if self.pos == start {
+            return Err(self.error(ParseComponent::Fraction, start, "at least one digit"));
+        }
+        Ok(nanos)
+    }
+
+    /// Reads a `+HH:MM` or `-HH:MM` offset into seconds.
+    pub fn numeric_offset(&mut self) -> Result<i32, ParseFailure> {
+        let sign = if self.expect(b"+-", ParseComponent::Offset, "'Z', '+' or '-'")? == b'+' {
+            1
+        } else {
+            -1
+        };
+        let hours_pos = self.pos;
+        let hours = self.digits(2, ParseComponent::Offset, "two digit offset hours")?;
+        self.expect(
+            b":",
+            ParseComponent::Offset,
+            "':' between offset hours and minutes",
+        )?;
+        let minutes_pos = self.pos;
+        let minutes = self.digits(2, ParseComponent::Offset, "two digit offset minutes")?;
+        if hours > 23 {
+            return Err(self.error(ParseComponent::Offset, hours_pos, "offset hours of 00-23"));
+        }
+        if minutes > 59 {
+            return Err(self.error(
+                ParseComponent::Offset,
+                minutes_pos,
+                "offset minutes of 00-59",
+            ));
+        }
+        Ok(sign
+            * (hours as i32 * SECONDS_IN_HOUR as i32 + minutes as i32 * SECONDS_IN_MINUTE as i32))
+    }
+}
+
+/// Parses the RFC 3339 part of `input`, up to `end`.
+///
+/// Seconds and the offset may be omitted, a missing offset is read as UTC.
+/// A leap second (`:60`) is folded into the first second of the following minute.
+pub fn parse_rfc3339(input: &str, end: usize) -> Result<DateTime, ParseFailure> {
+    use ParseComponent as C;
+
+    let mut cursor = Cursor::with_end(input, end);
+    let year_pos = cursor.pos;
+    let year = cursor.digits(4, C::Year, "a four digit year")? as u16;
+    cursor.expect(b"-", C::DateSeparator, "'-'")?;
+    let month_pos = cursor.pos;
+    let month = cursor.digits(2, C::Month, "a two digit month")? as u8;
+    cursor.expect(b"-", C::DateSeparator, "'-'")?;
+    let day_pos = cursor.pos;
+    let day = cursor.digits(2, C::Day, "a two digit day")? as u8;
+    cursor.expect(b"Tt ", C::DateTimeSeparator, "'T', 't' or ' '")?;
+    let hour_pos = cursor.pos;
+    let hour = cursor.digits(2, C::Hour, "a two digit hour")? as u8;
+    cursor.expect(b":", C::TimeSeparator, "':'")?;
+    let minute_pos = cursor.pos;
+    let minute = cursor.digits(2, C::Minute, "a two digit minute")? as u8;
+    let second_pos = cursor.pos + 1;
+    let second = if cursor.eat(b":").is_some() {
+        cursor.digits(2, C::Second, "a two digit second")? as u8
+    } else {
+        0
+    };
+    let subseconds = if cursor.eat(b".").is_some() {
+        cursor.fraction()?
+    } else {
+        0
+    };
+
+    let offset = if cursor.is_done() || cursor.eat(b"Zz").is_some() {
+        0
+    } else {
+        cursor.numeric_offset()?
+    };
+    if !cursor.is_done() {
+        return Err(cursor.error(C::Offset, cursor.pos, "the end of the input"));
+    }
+
+    if let Err(e) = validate_date(year, month, day) {
+        let (component, position, expected) = match e {
+            HoraeError::InvalidYear(_) => (C::Year, year_pos, "a year of 0001-9999"),
+            HoraeError::InvalidMonth(_) => (C::Month, month_pos, "a month of 01-12"),
+            _ => (C::Day, day_pos, "a day that exists in the month"),
+        };
+        return Err(cursor.range_error(component, position, expected, e));
+    }
+    if let Err(e) = validate_time(hour, minute, second.min(59)) {
+        let (component, position, expected) = match e {
+            HoraeError::InvalidHour(_) => (C::Hour, hour_pos, "an hour of 00-23"),
+            HoraeError::InvalidMinute(_) => (C::Minute, minute_pos, "a minute of 00-59"),
+            _ => (C::Second, second_pos, "a second of 00-60"),
+        };
+        return Err(cursor.range_error(component, position, expected, e));
+    }
+    if second > 60 {
+        return Err(cursor.range_error(
+            C::Second,
+            second_pos,
+            "a second of 00-60",
+            HoraeError::InvalidSecond(second),
+        ));
+    }
+
+    let unix_timestamp = DateTime::from_ymd_hms(year, month, day, hour, minute, 0).unix_timestamp
+        + i64::from(second)
+        - i64::from(offset);
+
+    let mut dt = DateTime::from_timestamp_parts(unix_timestamp, subseconds);
+    dt.with_utc_offset_seconds(offset);
+    Ok(dt)
+}
+
+/// Parses a time of day of `HH:MM`, `HH:MM:SS` or `HH:MM:SS.fff`.
+///
+/// The fraction may have any number of digits, digits past nanosecond precision are truncated.
+pub fn parse_time(input: &str) -> Result<Time, ParseFailure> {
+    use ParseComponent as C;
+
+    let mut cursor = Cursor::new(input);
+    let hour = cursor.digits(2, C::Hour, "a two digit hour")? as u8;
+    cursor.expect(b":", C::TimeSeparator, "':'")?;
+    let minute_pos = cursor.pos;
+    let minute = cursor.digits(2, C::Minute, "a two digit minute")? as u8;
+    let second_pos = cursor.pos + 1;
+    let mut second = 0;
+    let mut subseconds = 0;
+    if cursor.eat(b":").is_some() {
+        second = cursor.digits(2, C::Second, "a two digit second")? as u8;
+        if cursor.eat(b".").is_some() {
+            subseconds = cursor.fraction()?;
+        }
+    }
+    if !cursor.is_done() {
+        let (component, expected) = if cursor.pos == minute_pos + 2 {
+            (C::TimeSeparator, "':' or the end of the input")
+        } else if cursor.pos == second_pos + 2 {
+            (C::Fraction, "'.' or the end of the input")
+        } else {
+            (C::Fraction, "a digit or the end of the input")
+        };
+        return Err(cursor.error(component, cursor.pos, expected));
+    }
+
+    if let Err(e) = validate_time(hour, minute, second) {
+        let (component, position, expected) = match e {
+            HoraeError::InvalidHour(_) => (C::Hour, 0, "an hour of 00-23"),
+            HoraeError::InvalidMinute(_) => (C::Minute, minute_pos, "a minute of 00-59"),
+            _ => (C::Second, second_pos, "a second of 00-59"),
+        };
+        return Err(cursor.range_error(component, position, expected, e));
+    }
+    Ok(Time::from_hms_nano_unchecked(
+        hour, minute, second, subseconds,
+    ))
+}
+
+/// Parses an ISO 8601 date, optionally followed by a time and a UTC offset, in basic or
+/// extended format, and returns the precision of its smallest component.
+///
+/// Dates of reduced precision are read as their first day, a missing time as midnight and a
+/// missing offset as UTC. The smallest time component may have a decimal fraction after `.` or
+/// `,`. `24:00` is the end of the day, and a leap second is folded into the following minute.
+pub fn parse_iso8601(input: &str) -> Result<(DateTime, Iso8601Precision), ParseFailure> {
+    use ParseComponent as C;
+
+    let mut cursor = Cursor::new(input);
+    let (days, precision) = iso8601_date(&mut cursor)?;
+    if cursor.is_done() {
+        return Ok((
+            DateTime::from_timestamp_parts(days * SECONDS_IN_DAY, 0),
+            precision,
+        ));
+    }
+    if precision != Iso8601Precision::Day {
+        return Err(cursor.error(
+            C::DateSeparator,
+            cursor.pos,
+            "a complete date before the time, or the end of the input",
+        ));
+    }
+    cursor.expect(b"Tt", C::DateTimeSeparator, "'T' or the end of the input")?;
+    let time_pos = cursor.pos;
+    let (seconds, nanos, precision) = iso8601_time(&mut cursor)?;
+    let offset = iso8601_offset(&mut cursor)?;
+    if !cursor.is_done() {
+        return Err(cursor.error(C::Offset, cursor.pos, "the end of the input"));
+    }
+
+    let local_seconds = days * SECONDS_IN_DAY + seconds;
+    if local_seconds >= (days_from_civil(9999, 12, 31) + 1) * SECONDS_IN_DAY {
+        return Err(cursor.range_error(
+            C::Hour,
+            time_pos,
+            "a time before the end of 9999-12-31",
+            HoraeError::OutOfRange,
+        ));
+    }
+    let mut dt = DateTime::from_timestamp_parts(local_seconds - i64::from(offset), nanos);
+    dt.with_utc_offset_seconds(offset);
+    Ok((dt, precision))
+}
+
+/// Reads a calendar, ordinal or week date, or a year, month or week of reduced precision, into
+/// the days since the unix epoch of its first day.
+fn iso8601_date(cursor: &mut Cursor) -> Result<(i64, Iso8601Precision), ParseFailure> {
+    use Iso8601Precision as P;
+    use ParseComponent as C;
+
+    let start = cursor.pos;
+    let year = cursor.digits(4, C::Year, "a four digit year")? as u16;
+    let extended = cursor.eat(b"-").is_some();
+    let month_pos = cursor.pos;
+    let (month, day, precision) = match (cursor.peek(), cursor.digit_run()) {
+        (Some(b'W'), _) => {
+            cursor.pos = start;
+            let (week, extended) = iso_week(cursor)?;
+            let has_week_day = if extended {
+                cursor.peek() == Some(b'-')
+            } else {
+                cursor.peek().is_some_and(|b| b.is_ascii_digit())
+            };
+            if !has_week_day {
+                return Ok((week.first_day_since_epoch(), P::Week));
+            }
+            let date = iso_week_day(cursor, week, extended)?;
+            return Ok((date.days_since_epoch(), P::Day));
+        }
+        (_, 3) => {
+            cursor.pos = start;
+            return Ok((ordinal_date(cursor)?.days_since_epoch(), P::Day));
+        }
+        (None, _) if !extended => (1, 1, P::Year),
+        (_, 2) if extended => {
+            let month = cursor.digits(2, C::Month, "a two digit month")? as u8;
+            if cursor.eat(b"-").is_some() {
+                (
+                    month,
+                    cursor.digits(2, C::Day, "a two digit day")? as u8,
+                    P::Day,
+                )
+            } else {
+                (month, 1, P::Month)
+            }
+        }
+        (_, 4) if !extended => {
+            let month = cursor.digits(2, C::Month, "a two digit month")? as u8;
+            (
+                month,
+                cursor.digits(2, C::Day, "a two digit day")? as u8,
+                P::Day,
+            )
+        }
+        _ if extended => {
+            return Err(cursor.error(
+                C::Month,
+                month_pos,
+                "a two digit month, a three digit day of the year or 'W'",
+            ));
+        }
+        _ => {
+            return Err(cursor.error(
+                C::Month,
+                month_pos,
+                "'-', 'W', a month and day of four digits, a three digit day of the year or the end of the input",
+            ));
+        }
+    };
+
+    if let Err(e) = validate_date(year, month, day) {
+        let (component, position, expected) = match e {
+            HoraeError::InvalidYear(_) => (C::Year, start, "a year of 0001-9999"),
+            HoraeError::InvalidMonth(_) => (C::Month, month_pos, "a month of 01-12"),
+            _ => (
+                C::Day,
+                month_pos + if extended { 3 } else { 2 },
+                "a day that exists in the month",
+            ),
+        };
+        return Err(cursor.range_error(component, position, expected, e));
+    }
+    Ok((days_from_civil(i64::from(year), month, day), precision))
+}
+
+/// Reads a time of `hh`, `hh:mm`, `hh:mm:ss`, `hhmm` or `hhmmss`, where the smallest component
+/// may have a decimal fraction, into the seconds and nanoseconds into the day.
+fn iso8601_time(cursor: &mut Cursor) -> Result<(i64, u32, Iso8601Precision), ParseFailure> {
+    use ParseComponent as C;
+
+    let hour_pos = cursor.pos;
+    let hour = cursor.digits(2, C::Hour, "a two digit hour")?;
+    let extended = cursor.peek() == Some(b':');
+    let mut components = [hour, 0, 0];
+    let mut positions = [hour_pos, 0, 0];
+    let mut given = 1;
+    while given < 3 {
+        let has_next = if extended {
+            cursor.eat(b":").is_some()
+        } else {
+            cursor.peek().is_some_and(|b| b.is_ascii_digit())
+        };
+        if !has_next {
+            break;
+        }
+        positions[given] = cursor.pos;
+        components[given] = if given == 1 {
+            cursor.digits(2, C::Minute, "a two digit minute")?
+        } else {
+            cursor.digits(2, C::Second, "a two digit second")?
+        };
+        given += 1;
+    }
+    let fraction = if cursor.eat(b".,").is_some() {
+        cursor.fraction()?
+    } else {
+        0
+    };
+
+    let [hour, minute, second] = components;
+    if hour > 24 || (hour == 24 && (minute > 0 || second > 0 || fraction > 0)) {
+        return Err(cursor.range_error(
+            C::Hour,
+            hour_pos,
+            "an hour of 00-23, or 24:00 for the end of the day",
+            HoraeError::InvalidHour(hour as u8),
+        ));
+    }
+    if minute > 59 {
+        return Err(cursor.range_error(
+            C::Minute,
+            positions[1],
+            "a minute of 00-59",
+            HoraeError::InvalidMinute(minute as u8),
+        ));
+    }
+    if second > 60 {
+        return Err(cursor.range_error(
+            C::Second,
+            positions[2],
+            "a second of 00-60",
+            HoraeError::InvalidSecond(second as u8),
+        ));
+    }
+
+    let (unit, precision) = match given {
+        1 => (SECONDS_IN_HOUR, Iso8601Precision::Hour),
+        2 => (SECONDS_IN_MINUTE, Iso8601Precision::Minute),
+        _ => (1, Iso8601Precision::Second),
+    };
+    let nanos = i64::from(hour) * SECONDS_IN_HOUR * i64::from(NANOS_IN_SECOND)
+        + i64::from(minute) * SECONDS_IN_MINUTE * i64::from(NANOS_IN_SECOND)
+        + i64::from(second) * i64::from(NANOS_IN_SECOND)
+        + i64::from(fraction) * unit;
+    Ok((
+        nanos / i64::from(NANOS_IN_SECOND),
+        (nanos % i64::from(NANOS_IN_SECOND)) as u32,
+        precision,
+    ))
+}
+
+/// Reads a UTC offset of `Z`, `+hh`, `+hh:mm` or `+hhmm` into seconds, a missing offset is read
+/// as UTC.
+pub fn iso8601_offset(cursor: &mut Cursor) -> Result<i32, ParseFailure> {
+    use ParseComponent as C;
+
+    if cursor.is_done() || cursor.eat(b"Zz").is_some() {
+        return Ok(0);
+    }
+    let sign = match cursor.expect(b"+-", C::Offset, "'Z', '+', '-' or the end of the input")? {
+        b'+' => 1,
+        _ => -1,
+    };
+    let hours_pos = cursor.pos;
+    let hours = cursor.digits(2, C::Offset, "two digit offset hours")?;
+    let minutes_pos = cursor.pos + usize::from(cursor.peek() == Some(b':'));
+    let minutes = if cursor.eat(b":").is_some() || cursor.peek().is_some_and(|b| b.is_ascii_digit())
+    {
+        cursor.digits(2, C::Offset, "two digit offset minutes")?
+    } else {
+        0
+    };
+    if hours > 23 {
+        return Err(cursor.error(C::Offset, hours_pos, "offset hours of 00-23"));
+    }
+    if minutes > 59 {
+        return Err(cursor.error(C::Offset, minutes_pos, "offset minutes of 00-59"));
+    }
+    Ok(sign * (hours as i32 * SECONDS_IN_HOUR as i32 + minutes as i32 * SECONDS_IN_MINUTE as i32))
+}
+
+/// Parses an ISO 8601 ordinal date of `YYYY-DDD` or `YYYYDDD`.
+pub fn parse_ordinal_date(input: &str) -> Result<Date, ParseFailure> {
+    let mut cursor = Cursor::new(input);
+    let date = ordinal_date(&mut cursor)?;
+    if !cursor.is_done() {
+        return Err(cursor.error(ParseComponent::Day, cursor.pos, "the end of the input"));
+    }
+    Ok(date)
+}
+
+/// Reads and validates an ordinal date of `YYYY-DDD` or `YYYYDDD`.
+fn ordinal_date(cursor: &mut Cursor) -> Result<Date, ParseFailure> {
+    use ParseComponent as C;
+
+    let start = cursor.pos;
+    let year = cursor.digits(4, C::Year, "a four digit year")? as u16;
+    cursor.eat(b"-");
+    let ordinal_pos = cursor.pos;
+    let ordinal = cursor.digits(3, C::Day, "a three digit day of the year")? as u16;
+    Date::from_ordinal(year, ordinal).map_err(|e| match e {
+        HoraeError::InvalidYear(_) => cursor.range_error(C::Year, start, "a year of 0001-9999", e),
+        _ => cursor.range_error(C::Day, ordinal_pos, "a day that exists in the year", e),
+    })
+}
+
+/// Parses an ISO 8601 week of `YYYY-Www` or `YYYYWww`.
+pub fn parse_iso_week(input: &str) -> Result<IsoWeek, ParseFailure> {
+    let mut cursor = Cursor::new(input);
+    let (week, _) = iso_week(&mut cursor)?;
+    if !cursor.is_done() {
+        return Err(cursor.error(ParseComponent::Week, cursor.pos, "the end of the input"));
+    }
+    Ok(week)
+}
+
+/// Parses an ISO 8601 week date of `YYYY-Www-D` or `YYYYWwwD`, where `D` is the week day from
+/// Monday as 1 to Sunday as 7.
+pub fn parse_iso_week_date(input: &str) -> Result<Date, ParseFailure> {
+    let mut cursor = Cursor::new(input);
+    let (week, extended) = iso_week(&mut cursor)?;
+    let date = iso_week_day(&mut cursor, week, extended)?;
+    if !cursor.is_done() {
+        return Err(cursor.error(ParseComponent::WeekDay, cursor.pos, "the end of the input"));
+    }
+    Ok(date)
+}
+
+/// Reads and validates the `YYYY-Www` or `YYYYWww` part of an ISO 8601 week, returns whether
+/// it is in the extended format with `-`.
+fn iso_week(cursor: &mut Cursor) -> Result<(IsoWeek, bool), ParseFailure> {
+    use ParseComponent as C;
+
+    let start = cursor.pos;
+    let year = cursor.digits(4, C::Year, "a four digit year")? as u16;
+    let extended = cursor.eat(b"-").is_some();
+    cursor.expect(
+        b"W",
+        C::DateSeparator,
+        if extended { "'W'" } else { "'-' or 'W'" },
+    )?;
+    let week_pos = cursor.pos;
+    let week = cursor.digits(2, C::Week, "a two digit week")? as u8;
+    let week = IsoWeek::new(year, week).map_err(|e| match e {
+        HoraeError::InvalidYear(_) => cursor.range_error(C::Year, start, "a year of 0001-9999", e),
+        _ => cursor.range_error(C::Week, week_pos, "a week that exists in the year", e),
+    })?;
+    Ok((week, extended))
+}
+
+/// Reads the `-D` or `D` week day following an ISO 8601 week into its date.
+fn iso_week_day(cursor: &mut Cursor, week: IsoWeek, extended: bool) -> Result<Date, ParseFailure> {
+    use ParseComponent as C;
+
+    if extended {
+        cursor.expect(b"-", C::DateSeparator, "'-'")?;
+    }
+    let week_day_pos = cursor.pos;
+    let week_day = cursor.digits(1, C::WeekDay, "a week day of 1-7")? as u8;
+    let weekday = Weekday::from_number_from_monday(week_day)
+        .ok_or_else(|| cursor.error(C::WeekDay, week_day_pos, "a week day of 1-7"))?;
+    Date::from_days_since_epoch(week.first_day_since_epoch() + i64::from(weekday as u8))
+        .map_err(|e| cursor.range_error(C::WeekDay, week_day_pos, "a date in 0001-9999", e))
+}
+
+/// Parses an RFC 9557 string, an RFC 3339 string followed by any number of suffix tags.
+///
+/// Unknown elective tags are ignored, unknown or inconsistent critical tags are an error.
+pub fn parse_rfc9557(input: &str) -> Result<DateTime, ParseFailure> {
+    let end_of_rfc3339 = input.find('[').unwrap_or(input.len());
+    let dt = parse_rfc3339(input, end_of_rfc3339)?;
+
+    let mut cursor = Cursor::new(input);
+    cursor.pos = end_of_rfc3339;
+    while !cursor.is_done() {
+        let tag_pos = cursor.pos;
+        cursor.expect(b"[", ParseComponent::SuffixTag, "'[' to open a suffix tag")?;
+        let end = input[tag_pos..]
+            .find(']')
+            .ok_or_else(|| cursor.error(ParseComponent::SuffixTag, tag_pos, "a closing ']'"))?
+            + tag_pos;
+
+        let critical = cursor.eat(b"!").is_some();
+        let content_pos = cursor.pos;
+        let tag_content = &input.as_bytes()[content_pos..end];
+
+        if tag_content.contains(&b'=') {
+            if critical {
+                return Err(cursor.error(
+                    ParseComponent::SuffixTag,
+                    tag_pos,
+                    "a known critical suffix tag",
+                ));
+            }
+        } else if critical {
+            // Timezone name or offset tag
+            let mut tag_cursor = Cursor::with_end(input, end);
+            tag_cursor.pos = content_pos;
+            let tag_offset = tag_cursor.numeric_offset().map_err(|_| {
+                cursor.error(
+                    ParseComponent::SuffixTag,
+                    content_pos,
+                    "a critical offset tag of +HH:MM or -HH:MM",
+                )
+            })?;
+            if !tag_cursor.is_done() {
+                return Err(cursor.error(
+                    ParseComponent::SuffixTag,
+                    tag_cursor.pos,
+                    "a closing ']'",
+                ));
+            }
+            if tag_offset != dt.offset {
+                return Err(cursor.error(
+                    ParseComponent::SuffixTag,
+                    content_pos,
+                    "a critical offset tag matching the offset",
+                ));
+            }
+        }
+        cursor.pos = end + 1;
+    }
+
+    Ok(dt)
+}
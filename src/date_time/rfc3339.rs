@@ -0,0 +1,49 @@
+/// The digits of the fraction of a second written in RFC 3339.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SecondsFormat {
+    /// Whole seconds without a fraction, like `09:09:09`.
+    Seconds,
+    /// Always three digits, like `09:09:09.500`.
+    Millis,
+    /// Always six digits, like `09:09:09.500000`.
+    Micros,
+    /// Always nine digits, like `09:09:09.500000000`.
+    Nanos,
+    /// Up to nine digits with trailing zeros trimmed, and no fraction for whole seconds, like
+    /// `09:09:09.5` or `09:09:09`.
+    #[default]
+    Trimmed,
+}
+
+/// Controls how `Utc::to_rfc3339_with` writes a date and time.
+///
+/// The default trims the fraction of a second and writes UTC as `Z`, like `to_rfc3339`.
+///
+/// # Examples
+/// ```rust
+/// use horae::{Rfc3339Options, SecondsFormat, Utc};
+///
+/// let utc = Utc::from_ymd_hms(2019, 1, 1, 9, 9, 9);
+/// let options = Rfc3339Options {
+///     seconds: SecondsFormat::Millis,
+///     utc_as_z: false,
+/// };
+/// assert_eq!(utc.to_rfc3339_with(options), "2019-01-01T09:09:09.000+00:00");
+/// assert_eq!(utc.to_rfc3339_with(Rfc3339Options::default()), utc.to_rfc3339());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rfc3339Options {
+    /// The digits of the fraction of a second.
+    pub seconds: SecondsFormat,
+    /// Writes UTC as `Z` if true, as `+00:00` if false.
+    pub utc_as_z: bool,
+}
+
+impl Default for Rfc3339Options {
+    fn default() -> Rfc3339Options {
+        Rfc3339Options {
+            seconds: SecondsFormat::Trimmed,
+            utc_as_z: true,
+        }
+    }
+}
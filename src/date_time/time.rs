@@ -1,40 +1,271 @@
-use crate::tokenizer::{Token, Unit, tokenize};
+use crate::{
+    HoraeError, ParseError, SignedDuration,
+    tokenizer::{DEFAULT_PIVOT_YEAR, Format, Token, Unit, parse_with_format, tokenize},
+};
 
-#[derive(Debug, Copy, Clone)]
+use super::{
+    common::{
+        NANOS_IN_SECOND, SECONDS_IN_DAY, SECONDS_IN_HOUR, SECONDS_IN_MINUTE, twelve_hour,
+        validate_time, write_fraction_digits,
+    },
+    parser::parse_time,
+};
+
+const NANOS_IN_DAY: i128 = SECONDS_IN_DAY as i128 * NANOS_IN_SECOND as i128;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// Contains all time information
 ///
 /// Holds the hour, minute, second and subseconds.
+///
+/// A wall-clock time of day without a date or UTC offset.
+/// Instantiate with `Time::from_hms(hour, minute, second)`, parse from `HH:MM[:SS[.fff]]`, or get
+/// the local time of a `Utc` with `Utc::time()`.
+///
+/// # Examples
+/// ```rust
+/// use horae::{SignedDuration, Time};
+///
+/// let opening = Time::from_hms(9, 0, 0).unwrap();
+/// let closing: Time = "17:30".parse().unwrap();
+/// assert!(opening < closing);
+/// assert_eq!(closing.wrapping_add(SignedDuration::from_secs(8 * 3600)).to_string(), "01:30:00.000");
+/// ```
 pub struct Time {
-    pub hour: u8,
-    pub minute: u8,
-    pub second: u8,
-    pub subseconds: u64,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    /// Nanoseconds
+    subseconds: u32,
 }
 
-impl From<(u8, u8, u8, f64)> for Time {
-    fn from((hour, minute, second, subseconds): (u8, u8, u8, f64)) -> Time {
-        let tmp = subseconds.fract() * 1_000_000_000.0;
-        Time {
+impl Time {
+    /// Midnight, 00:00:00.
+    pub const MIDNIGHT: Time = Time {
+        hour: 0,
+        minute: 0,
+        second: 0,
+        subseconds: 0,
+    };
+
+    /// Instantiates a new `Time`, validating every field.
+    ///
+    /// # Errors
+    /// Returns a `HoraeError` naming the first field out of range:
+    /// - hour: 0-23
+    /// - minute: 0-59
+    /// - second: 0-59
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{HoraeError, Time};
+    ///
+    /// assert_eq!(Time::from_hms(9, 9, 9).unwrap().to_string(), "09:09:09.000");
+    /// assert_eq!(Time::from_hms(24, 0, 0), Err(HoraeError::InvalidHour(24)));
+    /// ```
+    pub fn from_hms(hour: u8, minute: u8, second: u8) -> Result<Time, HoraeError> {
+        Time::from_hms_nano(hour, minute, second, 0)
+    }
+
+    /// Instantiates a new `Time` with nanoseconds, validating every field.
+    ///
+    /// # Errors
+    /// Returns a `HoraeError` naming the first field out of range, the nanoseconds have to be
+    /// less than one second.
+    pub fn from_hms_nano(hour: u8, minute: u8, second: u8, nanos: u32) -> Result<Time, HoraeError> {
+        validate_time(hour, minute, second)?;
+        if nanos >= NANOS_IN_SECOND {
+            return Err(HoraeError::InvalidNanosecond(nanos));
+        }
+        Ok(Time {
             hour,
             minute,
             second,
-            subseconds: tmp.floor() as u64,
-        }
+            subseconds: nanos,
+        })
     }
-}
 
-impl From<(u8, u8, u8)> for Time {
-    fn from((hour, minute, second): (u8, u8, u8)) -> Time {
+    /// Instantiates a new `Time` from fields the caller has already validated.
+    pub(crate) fn from_hms_nano_unchecked(hour: u8, minute: u8, second: u8, nanos: u32) -> Time {
         Time {
             hour,
             minute,
             second,
-            subseconds: 0,
+            subseconds: nanos,
         }
     }
-}
 
-impl Time {
+    /// Returns the hour, 0-23.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Time;
+    ///
+    /// let time = Time::from_hms_nano(9, 30, 15, 500).unwrap();
+    /// assert_eq!((time.hour(), time.minute(), time.second()), (9, 30, 15));
+    /// assert_eq!(time.nanosecond(), 500);
+    /// ```
+    #[must_use]
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute, 0-59.
+    #[must_use]
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second, 0-59.
+    #[must_use]
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// Returns the nanoseconds into the second.
+    #[must_use]
+    pub fn nanosecond(&self) -> u32 {
+        self.subseconds
+    }
+
+    /// Instantiates a new `Time` from the whole seconds since midnight.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::OutOfRange` if the seconds are not less than one day.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Time;
+    ///
+    /// assert_eq!(Time::from_seconds_since_midnight(3_661).unwrap().to_string(), "01:01:01.000");
+    /// assert!(Time::from_seconds_since_midnight(86_400).is_err());
+    /// ```
+    pub fn from_seconds_since_midnight(seconds: u32) -> Result<Time, HoraeError> {
+        Time::from_nanos_since_midnight(u64::from(seconds) * u64::from(NANOS_IN_SECOND))
+    }
+
+    /// Instantiates a new `Time` from the nanoseconds since midnight.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::OutOfRange` if the nanoseconds are not less than one day.
+    pub fn from_nanos_since_midnight(nanos: u64) -> Result<Time, HoraeError> {
+        if i128::from(nanos) >= NANOS_IN_DAY {
+            return Err(HoraeError::OutOfRange);
+        }
+        let seconds = (nanos / u64::from(NANOS_IN_SECOND)) as i64;
+        Ok(Time::from_hms_nano_unchecked(
+            (seconds / SECONDS_IN_HOUR) as u8,
+            ((seconds % SECONDS_IN_HOUR) / SECONDS_IN_MINUTE) as u8,
+            (seconds % SECONDS_IN_MINUTE) as u8,
+            (nanos % u64::from(NANOS_IN_SECOND)) as u32,
+        ))
+    }
+
+    /// Returns the whole seconds since midnight.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Time;
+    ///
+    /// assert_eq!(Time::from_hms(1, 1, 1).unwrap().seconds_since_midnight(), 3_661);
+    /// ```
+    #[must_use]
+    pub fn seconds_since_midnight(&self) -> u32 {
+        u32::from(self.hour) * SECONDS_IN_HOUR as u32
+            + u32::from(self.minute) * SECONDS_IN_MINUTE as u32
+            + u32::from(self.second)
+    }
+
+    /// Returns the nanoseconds since midnight.
+    #[must_use]
+    pub fn nanos_since_midnight(&self) -> u64 {
+        u64::from(self.seconds_since_midnight()) * u64::from(NANOS_IN_SECOND)
+            + u64::from(self.subseconds)
+    }
+
+    /// Returns the time moved by the supplied duration, wrapping around midnight.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{SignedDuration, Time};
+    ///
+    /// let time = Time::from_hms(23, 0, 0).unwrap();
+    /// assert_eq!(time.wrapping_add(SignedDuration::from_secs(7_200)).to_string(), "01:00:00.000");
+    /// assert_eq!(time.wrapping_add(SignedDuration::from_secs(-7_200)).to_string(), "21:00:00.000");
+    /// ```
+    #[must_use]
+    pub fn wrapping_add(&self, duration: SignedDuration) -> Time {
+        self.overflowing_add(duration).0
+    }
+
+    /// Returns the time moved by the supplied duration, wrapping around midnight, and the
+    /// number of days that carried over.
+    ///
+    /// The days are negative if the time wrapped backwards past midnight.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{SignedDuration, Time};
+    ///
+    /// let shift_start = Time::from_hms(22, 0, 0).unwrap();
+    /// let (shift_end, days) = shift_start.overflowing_add(SignedDuration::from_secs(10 * 3600));
+    /// assert_eq!(shift_end.to_string(), "08:00:00.000");
+    /// assert_eq!(days, 1);
+    ///
+    /// let (time, days) = shift_start.overflowing_add(SignedDuration::from_secs(-47 * 3600));
+    /// assert_eq!(time.to_string(), "23:00:00.000");
+    /// assert_eq!(days, -2);
+    /// ```
+    #[must_use]
+    pub fn overflowing_add(&self, duration: SignedDuration) -> (Time, i64) {
+        let nanos = i128::from(self.nanos_since_midnight()) + duration.as_nanos();
+        let days = nanos.div_euclid(NANOS_IN_DAY) as i64;
+        let time = Time::from_nanos_since_midnight(nanos.rem_euclid(NANOS_IN_DAY) as u64)
+            .expect("remainder is always less than one day");
+        (time, days)
+    }
+
+    /// Parses a time of day of `HH:MM`, `HH:MM:SS` or `HH:MM:SS.fff`.
+    ///
+    /// The fraction may have any number of digits, digits past nanosecond precision are truncated.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` naming the component that could not be read, and where.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{ParseComponent, Time};
+    ///
+    /// assert_eq!(Time::parse("09:30").unwrap().to_string(), "09:30:00.000");
+    /// assert_eq!(Time::parse("09:30:15.25").unwrap().nanosecond(), 250_000_000);
+    /// assert_eq!(Time::parse("09:61").unwrap_err().component(), ParseComponent::Minute);
+    /// ```
+    pub fn parse(s: &str) -> Result<Time, ParseError> {
+        parse_time(s).map_err(ParseError::from)
+    }
+
+    /// Parses a `Time` from a string written in the supplied format, using the same directives
+    /// as `format`.
+    ///
+    /// Missing components are read as zero. See `Utc::parse_from_format` for the accepted
+    /// directives, date directives are read but ignored.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` naming the component that could not be read, and where.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Time;
+    ///
+    /// let time = Time::parse_from_format("9h05", "%Hh%MM").unwrap();
+    /// assert_eq!(time.to_string(), "09:05:00.000");
+    /// ```
+    pub fn parse_from_format(input: &str, format: &str) -> Result<Time, ParseError> {
+        parse_with_format(input, &Format::new(format)?, DEFAULT_PIVOT_YEAR)
+            .map(|parsed| parsed.time())
+            .map_err(ParseError::from)
+    }
+
     /// Formats the time in the given format
     /// For more information on the available formatting syntax, see the README in the API chapter.
     ///
@@ -46,45 +277,119 @@ impl Time {
     /// assert_eq!(utc_now.time().format("%HH:%MM:%SS"), "09:09:09");
     /// ```
     pub fn format(&self, formatter: &str) -> String {
-        let format_tokens = tokenize(formatter);
+        self.format_tokens(&tokenize(formatter))
+    }
+
+    /// Formats the time in the compiled format, see `Format`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Format, Time};
+    ///
+    /// let format = Format::new("%HHh%MM").unwrap();
+    /// assert_eq!(Time::from_hms(9, 5, 0).unwrap().format_with(&format), "09h05");
+    /// ```
+    pub fn format_with(&self, format: &Format) -> String {
+        self.format_tokens(format.tokens())
+    }
+
+    /// Writes the time in the compiled format into `out`, without allocating.
+    ///
+    /// # Errors
+    /// Returns an error if `out` fails to write.
+    pub fn format_into<W: std::fmt::Write>(
+        &self,
+        out: &mut W,
+        format: &Format,
+    ) -> std::fmt::Result {
+        self.write_tokens(format.tokens(), out)
+    }
+
+    fn format_tokens(&self, tokens: &[Token]) -> String {
         let mut formatted_string = String::new();
-        for token in format_tokens {
+        // Writing into a `String` cannot fail
+        let _ = self.write_tokens(tokens, &mut formatted_string);
+        formatted_string
+    }
+
+    fn write_tokens<W: std::fmt::Write>(&self, tokens: &[Token], out: &mut W) -> std::fmt::Result {
+        for token in tokens {
             match token {
                 Token::Unit(unit) => match unit {
                     Unit::Millisecond => {
-                        formatted_string.push_str(&format!("{:03}", self.subseconds));
+                        write_fraction_digits(out, self.subseconds, 3, false)?;
+                    }
+                    Unit::Microsecond => {
+                        write_fraction_digits(out, self.subseconds, 6, false)?;
+                    }
+                    Unit::Nanosecond => {
+                        write_fraction_digits(out, self.subseconds, 9, false)?;
+                    }
+                    Unit::TrimmedMillisecond => {
+                        write_fraction_digits(out, self.subseconds, 3, true)?;
+                    }
+                    Unit::TrimmedMicrosecond => {
+                        write_fraction_digits(out, self.subseconds, 6, true)?;
+                    }
+                    Unit::TrimmedNanosecond => {
+                        write_fraction_digits(out, self.subseconds, 9, true)?;
                     }
                     Unit::ShortSecond => {
-                        formatted_string.push_str(&format!("{:01}", self.second));
+                        write!(out, "{:01}", self.second)?;
                     }
                     Unit::Second => {
-                        formatted_string.push_str(&format!("{:02}", self.second));
+                        write!(out, "{:02}", self.second)?;
                     }
                     Unit::ShortMinute => {
-                        formatted_string.push_str(&format!("{:01}", self.minute));
+                        write!(out, "{:01}", self.minute)?;
                     }
                     Unit::Minute => {
-                        formatted_string.push_str(&format!("{:02}", self.minute));
+                        write!(out, "{:02}", self.minute)?;
                     }
                     Unit::ShortHour => {
-                        formatted_string.push_str(&format!("{:01}", self.hour));
+                        write!(out, "{:01}", self.hour)?;
                     }
                     Unit::Hour => {
-                        formatted_string.push_str(&format!("{:02}", self.hour));
+                        write!(out, "{:02}", self.hour)?;
+                    }
+                    Unit::SpacePaddedHour => {
+                        write!(out, "{:2}", self.hour)?;
+                    }
+                    Unit::ShortTwelveHour => {
+                        write!(out, "{}", twelve_hour(self.hour))?;
+                    }
+                    Unit::TwelveHour => {
+                        write!(out, "{:02}", twelve_hour(self.hour))?;
+                    }
+                    Unit::SpacePaddedTwelveHour => {
+                        write!(out, "{:2}", twelve_hour(self.hour))?;
+                    }
+                    Unit::SpacePaddedMinute => {
+                        write!(out, "{:2}", self.minute)?;
+                    }
+                    Unit::SpacePaddedSecond => {
+                        write!(out, "{:2}", self.second)?;
+                    }
+                    Unit::UpperMeridiem => {
+                        out.write_str(if self.hour < 12 { "AM" } else { "PM" })?;
+                    }
+                    Unit::LowerMeridiem => {
+                        out.write_str(if self.hour < 12 { "am" } else { "pm" })?;
                     }
                     // Dont want to intruduce an error state now...
                     _ => {
-                        formatted_string
-                            .push_str(" Time only supports millisecond, second, minute, and hour ");
+                        out.write_str(
+                            " Time only supports millisecond, second, minute, and hour ",
+                        )?;
                     }
                 },
                 Token::Separator(separator) => {
-                    formatted_string.push_str(&separator.separator_symbol);
+                    out.write_str(&separator.separator_symbol)?;
                 }
             }
         }
 
-        formatted_string
+        Ok(())
     }
 }
 
@@ -92,10 +397,35 @@ impl Time {
 
 impl std::fmt::Display for Time {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(
-            f,
-            "{:02}:{:02}:{:02}.{:03}",
-            self.hour, self.minute, self.second, self.subseconds
-        )
+        write!(f, "{:02}:{:02}:{:02}.", self.hour, self.minute, self.second)?;
+        write_fraction_digits(f, self.subseconds, 3, false)
+    }
+}
+
+impl std::str::FromStr for Time {
+    type Err = HoraeError;
+
+    /// Parses a time of day of `HH:MM`, `HH:MM:SS` or `HH:MM:SS.fff`, returning the `HoraeError`
+    /// of the field if a component is out of range.
+    fn from_str(s: &str) -> Result<Time, HoraeError> {
+        parse_time(s).map_err(HoraeError::from)
+    }
+}
+
+impl std::ops::Add<SignedDuration> for Time {
+    type Output = Time;
+
+    /// Wraps around midnight, see `Time::wrapping_add`.
+    fn add(self, rhs: SignedDuration) -> Time {
+        self.wrapping_add(rhs)
+    }
+}
+
+impl std::ops::Sub<SignedDuration> for Time {
+    type Output = Time;
+
+    /// Wraps around midnight, see `Time::wrapping_add`.
+    fn sub(self, rhs: SignedDuration) -> Time {
+        self.wrapping_add(-rhs)
     }
 }
@@ -0,0 +1,159 @@
+use crate::{HoraeError, ParseComponent, ParseError};
+
+const NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// A day of the week.
+///
+/// Weeks start on Monday, as in ISO 8601, unless a method says otherwise.
+/// Adding or subtracting days wraps around the week.
+///
+/// # Examples
+/// ```rust
+/// use horae::{Date, Weekday};
+///
+/// let weekday = Date::from_ymd(2024, 3, 1).unwrap().weekday();
+/// assert_eq!(weekday, Weekday::Friday);
+/// assert_eq!(weekday + 3, Weekday::Monday);
+/// assert_eq!(weekday.number_from_sunday(), 6);
+/// assert_eq!("sat".parse::<Weekday>().unwrap(), weekday.succ());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Every week day, starting on Monday.
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// Returns the next day of the week, Monday after Sunday.
+    #[must_use]
+    pub fn succ(self) -> Weekday {
+        self + 1
+    }
+
+    /// Returns the previous day of the week, Sunday before Monday.
+    #[must_use]
+    pub fn pred(self) -> Weekday {
+        self - 1
+    }
+
+    /// Returns the number of the day in a week starting on Monday, Monday is 1, Sunday is 7.
+    #[must_use]
+    pub fn number_from_monday(self) -> u8 {
+        self as u8 + 1
+    }
+
+    /// Returns the number of the day in a week starting on Sunday, Sunday is 1, Saturday is 7.
+    #[must_use]
+    pub fn number_from_sunday(self) -> u8 {
+        (self as u8 + 1) % 7 + 1
+    }
+
+    /// Returns the day of the supplied number in a week starting on Monday, Monday is 1,
+    /// Sunday is 7.
+    ///
+    /// # Returns
+    /// `None` if the number is outside of 1-7.
+    #[must_use]
+    pub fn from_number_from_monday(number: u8) -> Option<Weekday> {
+        Weekday::ALL
+            .get(usize::from(number.checked_sub(1)?))
+            .copied()
+    }
+
+    /// Returns the day of the supplied number in a week starting on Sunday, Sunday is 1,
+    /// Saturday is 7.
+    ///
+    /// # Returns
+    /// `None` if the number is outside of 1-7.
+    #[must_use]
+    pub fn from_number_from_sunday(number: u8) -> Option<Weekday> {
+        Weekday::from_number_from_monday(number).map(|weekday| weekday - 1)
+    }
+
+    /// Returns the full English name, like `Monday`.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        NAMES[self as usize]
+    }
+
+    /// Returns the English name shortened to three letters, like `Mon`.
+    #[must_use]
+    pub fn short_name(self) -> &'static str {
+        &NAMES[self as usize][..3]
+    }
+
+    /// Reads a full or three letter English name in any case.
+    pub(crate) fn from_name(name: &str) -> Option<Weekday> {
+        Weekday::ALL.into_iter().find(|weekday| {
+            name.eq_ignore_ascii_case(weekday.name())
+                || name.eq_ignore_ascii_case(weekday.short_name())
+        })
+    }
+}
+
+impl std::ops::Add<i64> for Weekday {
+    type Output = Weekday;
+
+    /// Returns the day the supplied number of days later, wrapping around the week.
+    fn add(self, days: i64) -> Weekday {
+        Weekday::ALL[(self as i64 + days.rem_euclid(7)) as usize % 7]
+    }
+}
+
+impl std::ops::Sub<i64> for Weekday {
+    type Output = Weekday;
+
+    /// Returns the day the supplied number of days earlier, wrapping around the week.
+    fn sub(self, days: i64) -> Weekday {
+        self + (7 - days.rem_euclid(7))
+    }
+}
+
+impl std::str::FromStr for Weekday {
+    type Err = HoraeError;
+
+    /// Parses a full or three letter English name in any case, like `Monday`, `mon` or `MON`.
+    fn from_str(s: &str) -> Result<Weekday, HoraeError> {
+        Weekday::from_name(s).ok_or_else(|| {
+            HoraeError::Parse(ParseError::new(
+                s,
+                ParseComponent::WeekDay,
+                0,
+                "a week day like Mon or Monday",
+            ))
+        })
+    }
+}
+
+// Display implementation
+
+impl std::fmt::Display for Weekday {
+    /// Formats the full English name, like `Monday`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
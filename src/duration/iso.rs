@@ -0,0 +1,309 @@
+use crate::{
+    HoraeError, ParseComponent, ParseError, SignedDuration,
+    date_time::{
+        common::NANOS_IN_SECOND,
+        parser::{Cursor, ParseFailure},
+    },
+};
+
+const DATE_DESIGNATORS: &[u8] = b"YMWD";
+const TIME_DESIGNATORS: &[u8] = b"HMS";
+
+/// An ISO 8601 duration, like `P1Y2M3DT4H5M6.5S`, `P2W` or `-PT30M`.
+///
+/// Keeps the calendar components (years, months, weeks and days), which have no fixed length,
+/// apart from the exact components (hours, minutes and seconds).
+/// Only the seconds may have a fraction.
+///
+/// Parse with `IsoDuration::parse`, format with `Display`, and apply to a `Utc` with
+/// `Utc::add_iso_duration`.
+///
+/// # Examples
+/// ```rust
+/// use horae::IsoDuration;
+///
+/// let duration = IsoDuration::parse("P1Y2M10DT2H30M").unwrap();
+/// assert_eq!(duration.years, 1);
+/// assert_eq!(duration.months, 2);
+/// assert_eq!(duration.days, 10);
+/// assert_eq!(duration.hours, 2);
+/// assert_eq!(duration.minutes, 30);
+/// assert_eq!(duration.to_string(), "P1Y2M10DT2H30M");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct IsoDuration {
+    /// Whether the whole duration points into the past.
+    pub negative: bool,
+    pub years: u32,
+    pub months: u32,
+    pub weeks: u32,
+    pub days: u32,
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+    /// Nanoseconds of the fractional second.
+    pub nanos: u32,
+}
+
+impl IsoDuration {
+    /// Parses an ISO 8601 duration of `PnYnMnWnDTnHnMnS`.
+    ///
+    /// Any component may be left out, but at least one has to be present, and at least one
+    /// after a `T`. A leading `-` makes the duration negative. The seconds may have a fraction
+    /// separated by `.` or `,`, digits past nanosecond precision are truncated.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` naming the component that could not be read, and where.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{IsoDuration, ParseComponent};
+    ///
+    /// let duration = IsoDuration::parse("-PT1.5S").unwrap();
+    /// assert!(duration.negative);
+    /// assert_eq!(duration.seconds, 1);
+    /// assert_eq!(duration.nanos, 500_000_000);
+    ///
+    /// let error = IsoDuration::parse("P1H").unwrap_err();
+    /// assert_eq!(error.component(), ParseComponent::Designator);
+    /// assert_eq!(error.position(), 2);
+    /// ```
+    pub fn parse(s: &str) -> Result<IsoDuration, ParseError> {
+        parse_iso_duration(s).map_err(ParseError::from)
+    }
+
+    /// Returns `true` if every component is zero.
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        *self
+            == IsoDuration {
+                negative: self.negative,
+                ..IsoDuration::default()
+            }
+    }
+
+    /// Returns the duration as an exact `SignedDuration`, counting a week as 7 days and a day
+    /// as 24 hours.
+    ///
+    /// # Returns
+    /// `None` if the duration has years or months, which have no fixed length.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{IsoDuration, SignedDuration};
+    ///
+    /// let timeout = IsoDuration::parse("PT1M30S").unwrap();
+    /// assert_eq!(timeout.to_signed_duration(), Some(SignedDuration::from_secs(90)));
+    /// assert_eq!(IsoDuration::parse("P1M").unwrap().to_signed_duration(), None);
+    /// ```
+    #[must_use]
+    pub fn to_signed_duration(&self) -> Option<SignedDuration> {
+        if self.years != 0 || self.months != 0 {
+            return None;
+        }
+        let duration =
+            SignedDuration::from_secs((i64::from(self.weeks) * 7 + i64::from(self.days)) * 86_400)
+                + self.exact();
+        Some(if self.negative { -duration } else { duration })
+    }
+
+    /// Returns the calendar days of the duration, counting a week as 7 days, with the sign of
+    /// the duration.
+    pub(crate) fn signed_days(&self) -> i64 {
+        let days = i64::from(self.weeks) * 7 + i64::from(self.days);
+        if self.negative { -days } else { days }
+    }
+
+    /// Returns the calendar months of the duration, counting a year as 12 months, with the sign
+    /// of the duration.
+    pub(crate) fn signed_months(&self) -> i64 {
+        let months = i64::from(self.years) * 12 + i64::from(self.months);
+        if self.negative { -months } else { months }
+    }
+
+    /// Returns the hours, minutes and seconds of the duration, with the sign of the duration.
+    pub(crate) fn signed_exact(&self) -> SignedDuration {
+        if self.negative {
+            -self.exact()
+        } else {
+            self.exact()
+        }
+    }
+
+    fn exact(&self) -> SignedDuration {
+        SignedDuration::from_secs(
+            i64::from(self.hours) * 3_600 + i64::from(self.minutes) * 60 + i64::from(self.seconds),
+        ) + SignedDuration::from_nanos(i128::from(self.nanos))
+    }
+}
+
+impl std::str::FromStr for IsoDuration {
+    type Err = HoraeError;
+
+    fn from_str(s: &str) -> Result<IsoDuration, HoraeError> {
+        parse_iso_duration(s).map_err(HoraeError::from)
+    }
+}
+
+impl std::ops::Neg for IsoDuration {
+    type Output = IsoDuration;
+
+    fn neg(self) -> IsoDuration {
+        IsoDuration {
+            negative: !self.negative,
+            ..self
+        }
+    }
+}
+
+// Display implementation
+
+impl std::fmt::Display for IsoDuration {
+    /// Formats the duration leaving out every zero component, a zero duration is `PT0S`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "P")?;
+        if self.is_zero() {
+            return write!(f, "T0S");
+        }
+        for (value, designator) in [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ] {
+            if value != 0 {
+                write!(f, "{value}{designator}")?;
+            }
+        }
+        if self.exact() == SignedDuration::ZERO {
+            return Ok(());
+        }
+        write!(f, "T")?;
+        if self.hours != 0 {
+            write!(f, "{}H", self.hours)?;
+        }
+        if self.minutes != 0 {
+            write!(f, "{}M", self.minutes)?;
+        }
+        // Nanos past a whole second are carried into the seconds
+        let seconds = u64::from(self.seconds) + u64::from(self.nanos / NANOS_IN_SECOND);
+        let nanos = self.nanos % NANOS_IN_SECOND;
+        if nanos != 0 {
+            let fraction = format!("{nanos:09}");
+            write!(f, "{seconds}.{}S", fraction.trim_end_matches('0'))?;
+        } else if seconds != 0 {
+            write!(f, "{seconds}S")?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_iso_duration(input: &str) -> Result<IsoDuration, ParseFailure> {
+    use ParseComponent as C;
+
+    let mut cursor = Cursor::new(input);
+    let mut out = IsoDuration {
+        negative: cursor.eat(b"+-") == Some(b'-'),
+        ..IsoDuration::default()
+    };
+    cursor.expect(b"Pp", C::Designator, "'P'")?;
+
+    let mut in_time = false;
+    let mut any_component = false;
+    let mut any_time_component = false;
+    // Position of the last read designator in the order of its part
+    let mut last = 0;
+    while !cursor.is_done() {
+        if !in_time && cursor.eat(b"Tt").is_some() {
+            in_time = true;
+            last = 0;
+            continue;
+        }
+
+        let number_pos = cursor.pos;
+        let mut number: u64 = 0;
+        while let Some(b) = cursor.eat(b"0123456789") {
+            number = number
+                .saturating_mul(10)
+                .saturating_add(u64::from(b - b'0'));
+        }
+        if cursor.pos == number_pos {
+            let expected = if in_time {
+                "a number"
+            } else {
+                "a number or 'T'"
+            };
+            return Err(cursor.error(C::Designator, number_pos, expected));
+        }
+        let fraction_pos = cursor.pos;
+        let fraction = if cursor.eat(b".,").is_some() {
+            Some(cursor.fraction()?)
+        } else {
+            None
+        };
+
+        let designator_pos = cursor.pos;
+        let (designators, expected) = if in_time {
+            (TIME_DESIGNATORS, "'H', 'M' or 'S'")
+        } else {
+            (DATE_DESIGNATORS, "'Y', 'M', 'W' or 'D'")
+        };
+        let designator = cursor
+            .peek()
+            .map(|b| b.to_ascii_uppercase())
+            .filter(|b| designators.contains(b))
+            .ok_or_else(|| cursor.error(C::Designator, designator_pos, expected))?;
+        cursor.pos += 1;
+
+        let order = designators
+            .iter()
+            .position(|b| *b == designator)
+            .unwrap_or(0)
+            + 1;
+        if order <= last {
+            let expected = if in_time {
+                "designators in the order H, M, S"
+            } else {
+                "designators in the order Y, M, W, D"
+            };
+            return Err(cursor.error(C::Designator, designator_pos, expected));
+        }
+        last = order;
+
+        let (component, field) = match (in_time, designator) {
+            (false, b'Y') => (C::Year, &mut out.years),
+            (false, b'M') => (C::Month, &mut out.months),
+            (false, b'W') => (C::Week, &mut out.weeks),
+            (false, _) => (C::Day, &mut out.days),
+            (true, b'H') => (C::Hour, &mut out.hours),
+            (true, b'M') => (C::Minute, &mut out.minutes),
+            (true, _) => (C::Second, &mut out.seconds),
+        };
+        if let Some(nanos) = fraction {
+            if component != C::Second {
+                return Err(cursor.error(C::Fraction, fraction_pos, "a fraction only on seconds"));
+            }
+            out.nanos = nanos;
+        }
+        *field = u32::try_from(number)
+            .map_err(|_| cursor.error(component, number_pos, "a number of at most 4294967295"))?;
+
+        any_component = true;
+        any_time_component |= in_time;
+    }
+
+    if in_time && !any_time_component {
+        return Err(cursor.error(
+            C::Designator,
+            cursor.pos,
+            "at least one of hours, minutes or seconds after 'T'",
+        ));
+    }
+    if !any_component {
+        return Err(cursor.error(C::Designator, cursor.pos, "at least one component"));
+    }
+    Ok(out)
+}
@@ -0,0 +1,286 @@
+use std::time::Duration;
+
+use crate::HoraeError;
+
+mod iso;
+
+pub use iso::IsoDuration;
+
+const NANOS_IN_SECOND: i128 = 1_000_000_000;
+const NANOS_IN_MILLISECOND: i128 = 1_000_000;
+const NANOS_IN_MICROSECOND: i128 = 1_000;
+
+/// A span of time that can be negative, exact to the nanosecond.
+///
+/// Returned by subtracting one `Utc` from another, and can be added to or subtracted from a
+/// `Utc`.
+/// Converts from any `std::time::Duration`, and back into one if it is not negative.
+///
+/// # Examples
+/// ```rust
+/// use horae::{SignedDuration, Utc};
+///
+/// let earlier = Utc::from_ymd_hms(2019, 1, 1, 9, 9, 9);
+/// let later = Utc::from_ymd_hms(2019, 1, 1, 9, 9, 11);
+///
+/// assert_eq!(later - earlier, SignedDuration::from_secs(2));
+/// assert_eq!(earlier - later, SignedDuration::from_secs(-2));
+/// assert_eq!(earlier + SignedDuration::from_secs(2), later);
+/// assert_eq!(later + SignedDuration::from_secs(-2), earlier);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SignedDuration {
+    nanos: i128,
+}
+
+impl SignedDuration {
+    /// A duration of zero.
+    pub const ZERO: SignedDuration = SignedDuration { nanos: 0 };
+
+    /// Creates a new `SignedDuration` from whole seconds and additional nanoseconds.
+    ///
+    /// Both parts are added, so `new(-1, -500_000_000)` is minus one and a half seconds.
+    #[must_use]
+    pub const fn new(seconds: i64, nanos: i32) -> SignedDuration {
+        SignedDuration {
+            nanos: seconds as i128 * NANOS_IN_SECOND + nanos as i128,
+        }
+    }
+
+    /// Creates a new `SignedDuration` from whole seconds.
+    #[must_use]
+    pub const fn from_secs(seconds: i64) -> SignedDuration {
+        SignedDuration::new(seconds, 0)
+    }
+
+    /// Creates a new `SignedDuration` from milliseconds.
+    #[must_use]
+    pub const fn from_millis(millis: i64) -> SignedDuration {
+        SignedDuration {
+            nanos: millis as i128 * NANOS_IN_MILLISECOND,
+        }
+    }
+
+    /// Creates a new `SignedDuration` from microseconds.
+    #[must_use]
+    pub const fn from_micros(micros: i64) -> SignedDuration {
+        SignedDuration {
+            nanos: micros as i128 * NANOS_IN_MICROSECOND,
+        }
+    }
+
+    /// Creates a new `SignedDuration` from nanoseconds.
+    #[must_use]
+    pub const fn from_nanos(nanos: i128) -> SignedDuration {
+        SignedDuration { nanos }
+    }
+
+    /// Creates a new `SignedDuration` from seconds as an `f64`, rounded to the nearest
+    /// nanosecond.
+    ///
+    /// Lossy, an `f64` cannot hold nanosecond precision for large durations.
+    #[must_use]
+    pub fn from_secs_f64(seconds: f64) -> SignedDuration {
+        SignedDuration {
+            nanos: (seconds * NANOS_IN_SECOND as f64).round() as i128,
+        }
+    }
+
+    /// Returns the whole seconds of the duration, truncated towards zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::SignedDuration;
+    ///
+    /// assert_eq!(SignedDuration::from_millis(-1_500).as_secs(), -1);
+    /// ```
+    #[must_use]
+    pub const fn as_secs(&self) -> i64 {
+        (self.nanos / NANOS_IN_SECOND) as i64
+    }
+
+    /// Returns the nanoseconds of the duration that are not whole seconds.
+    ///
+    /// Carries the sign of the duration.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::SignedDuration;
+    ///
+    /// assert_eq!(SignedDuration::from_millis(-1_500).subsec_nanos(), -500_000_000);
+    /// ```
+    #[must_use]
+    pub const fn subsec_nanos(&self) -> i32 {
+        (self.nanos % NANOS_IN_SECOND) as i32
+    }
+
+    /// Returns the total whole milliseconds of the duration, truncated towards zero.
+    #[must_use]
+    pub const fn as_millis(&self) -> i128 {
+        self.nanos / NANOS_IN_MILLISECOND
+    }
+
+    /// Returns the total whole microseconds of the duration, truncated towards zero.
+    #[must_use]
+    pub const fn as_micros(&self) -> i128 {
+        self.nanos / NANOS_IN_MICROSECOND
+    }
+
+    /// Returns the total nanoseconds of the duration.
+    #[must_use]
+    pub const fn as_nanos(&self) -> i128 {
+        self.nanos
+    }
+
+    /// Returns the duration in seconds as an `f64`.
+    ///
+    /// Lossy, an `f64` cannot hold nanosecond precision for large durations.
+    #[must_use]
+    pub fn as_secs_f64(&self) -> f64 {
+        self.nanos as f64 / NANOS_IN_SECOND as f64
+    }
+
+    /// Returns `true` if the duration is less than zero.
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        self.nanos < 0
+    }
+
+    /// Returns `true` if the duration is greater than zero.
+    #[must_use]
+    pub const fn is_positive(&self) -> bool {
+        self.nanos > 0
+    }
+
+    /// Returns `true` if the duration is zero.
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.nanos == 0
+    }
+
+    /// Returns the absolute value of the duration.
+    #[must_use]
+    pub const fn abs(&self) -> SignedDuration {
+        SignedDuration {
+            nanos: self.nanos.abs(),
+        }
+    }
+
+    /// Returns the absolute value of the duration as a `std::time::Duration`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::SignedDuration;
+    ///
+    /// let duration = SignedDuration::from_secs(-2).unsigned_abs();
+    /// assert_eq!(duration, std::time::Duration::from_secs(2));
+    /// ```
+    #[must_use]
+    pub const fn unsigned_abs(&self) -> Duration {
+        let nanos = self.nanos.unsigned_abs();
+        Duration::new(
+            (nanos / NANOS_IN_SECOND as u128) as u64,
+            (nanos % NANOS_IN_SECOND as u128) as u32,
+        )
+    }
+
+    /// Adds two durations, returning `None` on overflow.
+    #[must_use]
+    pub const fn checked_add(self, rhs: SignedDuration) -> Option<SignedDuration> {
+        match self.nanos.checked_add(rhs.nanos) {
+            Some(nanos) => Some(SignedDuration { nanos }),
+            None => None,
+        }
+    }
+
+    /// Subtracts two durations, returning `None` on overflow.
+    #[must_use]
+    pub const fn checked_sub(self, rhs: SignedDuration) -> Option<SignedDuration> {
+        match self.nanos.checked_sub(rhs.nanos) {
+            Some(nanos) => Some(SignedDuration { nanos }),
+            None => None,
+        }
+    }
+}
+
+impl From<Duration> for SignedDuration {
+    fn from(duration: Duration) -> SignedDuration {
+        SignedDuration {
+            nanos: duration.as_nanos() as i128,
+        }
+    }
+}
+
+impl TryFrom<SignedDuration> for Duration {
+    type Error = HoraeError;
+
+    /// Fails with `HoraeError::NegativeDuration` if the duration is negative.
+    fn try_from(duration: SignedDuration) -> Result<Duration, HoraeError> {
+        if duration.is_negative() {
+            Err(HoraeError::NegativeDuration)
+        } else {
+            Ok(duration.unsigned_abs())
+        }
+    }
+}
+
+impl std::ops::Neg for SignedDuration {
+    type Output = SignedDuration;
+
+    fn neg(self) -> SignedDuration {
+        SignedDuration { nanos: -self.nanos }
+    }
+}
+
+impl std::ops::Add for SignedDuration {
+    type Output = SignedDuration;
+
+    fn add(self, rhs: SignedDuration) -> SignedDuration {
+        SignedDuration {
+            nanos: self.nanos + rhs.nanos,
+        }
+    }
+}
+
+impl std::ops::Sub for SignedDuration {
+    type Output = SignedDuration;
+
+    fn sub(self, rhs: SignedDuration) -> SignedDuration {
+        SignedDuration {
+            nanos: self.nanos - rhs.nanos,
+        }
+    }
+}
+
+impl std::ops::AddAssign for SignedDuration {
+    fn add_assign(&mut self, rhs: SignedDuration) {
+        self.nanos += rhs.nanos;
+    }
+}
+
+impl std::ops::SubAssign for SignedDuration {
+    fn sub_assign(&mut self, rhs: SignedDuration) {
+        self.nanos -= rhs.nanos;
+    }
+}
+
+impl std::ops::Mul<i64> for SignedDuration {
+    type Output = SignedDuration;
+
+    fn mul(self, rhs: i64) -> SignedDuration {
+        SignedDuration {
+            nanos: self.nanos * i128::from(rhs),
+        }
+    }
+}
+
+impl std::ops::Div<i64> for SignedDuration {
+    type Output = SignedDuration;
+
+    /// Divides the duration, truncating towards zero.
+    fn div(self, rhs: i64) -> SignedDuration {
+        SignedDuration {
+            nanos: self.nanos / i128::from(rhs),
+        }
+    }
+}
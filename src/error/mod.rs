@@ -0,0 +1,258 @@
+/// All errors Horae can return.
+///
+/// Invalid components name the offending field and hold the rejected value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HoraeError {
+    /// The year is outside of the supported range of 1-9999.
+    InvalidYear(u16),
+    /// The month is outside of 1-12.
+    InvalidMonth(u8),
+    /// The day does not exist in the month of the year.
+    InvalidDay { year: u16, month: u8, day: u8 },
+    /// The week does not exist in the ISO 8601 week-numbering year, which has 52 or 53 weeks.
+    InvalidWeek { year: u16, week: u8 },
+    /// The day of the year is outside of 1-365, or 1-366 in leap years.
+    InvalidOrdinal { year: u16, ordinal: u16 },
+    /// The hour is outside of 0-23.
+    InvalidHour(u8),
+    /// The minute is outside of 0-59.
+    InvalidMinute(u8),
+    /// The second is outside of 0-59, or 0-60 where leap seconds are allowed.
+    InvalidSecond(u8),
+    /// The nanoseconds are not less than one second.
+    InvalidNanosecond(u32),
+    /// The UTC offset in hours is not finite or not within -24 to +24 hours.
+    InvalidUtcOffset(f64),
+    /// The supplied value is not an `XffValue::DateTime`.
+    NotADateTime,
+    /// The result of date arithmetic is outside of the supported range of years 1-9999.
+    OutOfRange,
+    /// The step of a range or the width of a bucket is not positive, or the step is not
+    /// supported by the range.
+    InvalidStep,
+    /// A negative duration cannot be converted into a `std::time::Duration`.
+    NegativeDuration,
+    /// The supplied string could not be parsed, names the component that failed and where.
+    Parse(ParseError),
+}
+
+impl std::fmt::Display for HoraeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            HoraeError::InvalidYear(year) => {
+                write!(f, "invalid year {year}, expected 1-9999")
+            }
+            HoraeError::InvalidMonth(month) => {
+                write!(f, "invalid month {month}, expected 1-12")
+            }
+            HoraeError::InvalidDay { year, month, day } => {
+                write!(f, "invalid day {day}, {year:04}-{month:02} has no such day")
+            }
+            HoraeError::InvalidWeek { year, week } => {
+                write!(
+                    f,
+                    "invalid week {week}, ISO year {year:04} has no such week"
+                )
+            }
+            HoraeError::InvalidOrdinal { year, ordinal } => {
+                write!(
+                    f,
+                    "invalid day of the year {ordinal}, {year:04} has no such day"
+                )
+            }
+            HoraeError::InvalidHour(hour) => write!(f, "invalid hour {hour}, expected 0-23"),
+            HoraeError::InvalidMinute(minute) => {
+                write!(f, "invalid minute {minute}, expected 0-59")
+            }
+            HoraeError::InvalidSecond(second) => {
+                write!(
+                    f,
+                    "invalid second {second}, expected 0-59, or 60 for a leap second"
+                )
+            }
+            HoraeError::InvalidNanosecond(nanos) => {
+                write!(f, "invalid nanosecond {nanos}, expected 0-999999999")
+            }
+            HoraeError::InvalidUtcOffset(offset) => {
+                write!(f, "invalid UTC offset {offset}, expected -24 to +24 hours")
+            }
+            HoraeError::NotADateTime => write!(f, "value is not an `XffValue::DateTime`"),
+            HoraeError::OutOfRange => {
+                write!(f, "result is outside of the supported years 1-9999")
+            }
+            HoraeError::InvalidStep => write!(
+                f,
+                "invalid step, expected a positive step of days, weeks, months or years, or a positive duration for instants"
+            ),
+            HoraeError::NegativeDuration => {
+                write!(
+                    f,
+                    "negative duration cannot be converted into `std::time::Duration`"
+                )
+            }
+            HoraeError::Parse(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for HoraeError {}
+
+impl From<ParseError> for HoraeError {
+    fn from(error: ParseError) -> HoraeError {
+        HoraeError::Parse(error)
+    }
+}
+
+/// The component of a parsed string that could not be read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseComponent {
+    Year,
+    Month,
+    Week,
+    Day,
+    DateSeparator,
+    DateTimeSeparator,
+    Hour,
+    Minute,
+    Second,
+    TimeSeparator,
+    Fraction,
+    Offset,
+    SuffixTag,
+    /// A duration designator, like the `P`, `T` or unit letters of an ISO 8601 duration.
+    Designator,
+    /// A `NAME=VALUE` part of a recurrence rule.
+    RulePart,
+    /// A content line of an iCalendar recurrence set, like `DTSTART` or `EXDATE`.
+    Property,
+    /// A day of the week, like the last field of a cron expression.
+    WeekDay,
+    /// A whitespace separated field of a cron expression.
+    Field,
+    /// A line of a holiday rule set, like `Christmas Day: 12-25`.
+    Holiday,
+    /// Text between the directives of a format string, like the `/` of `%dd/%mm`.
+    Literal,
+    /// A `%` directive of a format string, like the `%dd` of `%dd/%mm`.
+    Directive,
+    /// A Unix timestamp, like the `%ts` of a format string.
+    Timestamp,
+}
+
+impl std::fmt::Display for ParseComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let name = match self {
+            ParseComponent::Year => "year",
+            ParseComponent::Month => "month",
+            ParseComponent::Week => "week",
+            ParseComponent::Day => "day",
+            ParseComponent::DateSeparator => "date separator",
+            ParseComponent::DateTimeSeparator => "date-time separator",
+            ParseComponent::Hour => "hour",
+            ParseComponent::Minute => "minute",
+            ParseComponent::Second => "second",
+            ParseComponent::TimeSeparator => "time separator",
+            ParseComponent::Fraction => "fraction",
+            ParseComponent::Offset => "offset",
+            ParseComponent::SuffixTag => "suffix tag",
+            ParseComponent::Designator => "designator",
+            ParseComponent::RulePart => "rule part",
+            ParseComponent::Property => "property",
+            ParseComponent::WeekDay => "week day",
+            ParseComponent::Field => "field",
+            ParseComponent::Holiday => "holiday",
+            ParseComponent::Literal => "literal",
+            ParseComponent::Directive => "directive",
+            ParseComponent::Timestamp => "timestamp",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Describes why and where a string could not be parsed.
+///
+/// The `Display` implementation renders the input with a caret under the offending column.
+///
+/// # Example
+/// ```rust
+/// use horae::{ParseComponent, Utc};
+///
+/// let error = Utc::parse_rfc3339("2023-02-31T00:00:00Z").unwrap_err();
+/// assert_eq!(error.component(), ParseComponent::Day);
+/// assert_eq!(error.position(), 8);
+/// assert_eq!(
+///     error.to_string(),
+///     "invalid day at byte 8, expected a day that exists in the month\n\
+///      2023-02-31T00:00:00Z\n\
+///      \x20       ^"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    component: ParseComponent,
+    position: usize,
+    expected: &'static str,
+    input: String,
+}
+
+impl ParseError {
+    pub(crate) fn new(
+        input: &str,
+        component: ParseComponent,
+        position: usize,
+        expected: &'static str,
+    ) -> ParseError {
+        ParseError {
+            component,
+            position,
+            expected,
+            input: input.to_string(),
+        }
+    }
+
+    /// Returns the component that could not be read.
+    #[must_use]
+    pub fn component(&self) -> ParseComponent {
+        self.component
+    }
+
+    /// Returns the byte offset into the input at which the component starts.
+    #[must_use]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns a description of what was expected at the position.
+    #[must_use]
+    pub fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// Returns the complete input that was parsed.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        // The caret is placed by characters, not bytes, to line up with non-ASCII input
+        let column = self
+            .input
+            .char_indices()
+            .take_while(|(i, _)| *i < self.position)
+            .count();
+        write!(
+            f,
+            "invalid {} at byte {}, expected {}\n{}\n{}^",
+            self.component,
+            self.position,
+            self.expected,
+            self.input,
+            " ".repeat(column)
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
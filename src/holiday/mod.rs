@@ -0,0 +1,439 @@
+use crate::{
+    Date, HoraeError, Month, ParseError, Weekday,
+    date_time::common::{days_from_civil, days_in_month_of_year, ordinal_suffix, week_day},
+};
+
+mod parser;
+
+/// Which day a holiday is observed on if it falls on a weekend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Observance {
+    /// Always observed on the day itself.
+    Actual,
+    /// A Saturday is observed on the Friday before, a Sunday on the Monday after.
+    NearestWeekday,
+    /// A Saturday or Sunday is observed on the Monday after.
+    FollowingMonday,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum RuleDate {
+    Fixed {
+        month: u8,
+        day: u8,
+    },
+    NthWeekDay {
+        nth: i8,
+        weekday: Weekday,
+        month: Month,
+    },
+    /// Days from Easter Sunday
+    Easter(i16),
+    /// Days from Orthodox Easter Sunday
+    OrthodoxEaster(i16),
+}
+
+/// A named holiday that falls on a date computed for every year.
+///
+/// A rule is a fixed date, the nth or last week day of a month, or a number of days from
+/// Easter or Orthodox Easter Sunday, together with an `Observance`.
+///
+/// Rules are written as `NAME: DATE [observed nearest|monday]`, where `DATE` is one of
+/// `12-25`, `4th Thu of Nov`, `last Mon of May`, `2nd last Sun of Oct`, `easter -2` or
+/// `orthodox easter`. Names of week days and months may be written in full, in any case.
+///
+/// # Examples
+/// ```rust
+/// use horae::{Date, HolidayRule, Month, Observance, Weekday};
+///
+/// let thanksgiving =
+///     HolidayRule::nth_week_day("Thanksgiving", 4, Weekday::Thursday, Month::November);
+/// assert_eq!(thanksgiving.to_string(), "Thanksgiving: 4th Thu of Nov");
+/// assert_eq!(thanksgiving.date_in(2024), Date::from_ymd(2024, 11, 28).ok());
+///
+/// let mut christmas = HolidayRule::parse("Christmas Day: 12-25").unwrap();
+/// christmas.with_observance(Observance::NearestWeekday);
+/// assert_eq!(christmas.observed_in(2022), Date::from_ymd(2022, 12, 26).ok());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HolidayRule {
+    name: String,
+    date: RuleDate,
+    observance: Observance,
+}
+
+impl HolidayRule {
+    /// Instantiates a rule for the same month and day every year.
+    ///
+    /// February 29 only falls in leap years.
+    #[must_use]
+    pub fn fixed(name: &str, month: u8, day: u8) -> HolidayRule {
+        HolidayRule::new(name, RuleDate::Fixed { month, day })
+    }
+
+    /// Instantiates a rule for the nth week day of a month, counted from the end of the month
+    /// if negative, so -1 is the last.
+    ///
+    /// A fifth week day only falls in some months.
+    #[must_use]
+    pub fn nth_week_day(name: &str, nth: i8, weekday: Weekday, month: Month) -> HolidayRule {
+        HolidayRule::new(
+            name,
+            RuleDate::NthWeekDay {
+                nth,
+                weekday,
+                month,
+            },
+        )
+    }
+
+    /// Instantiates a rule for a number of days from Easter Sunday, negative for days before.
+    #[must_use]
+    pub fn easter(name: &str, offset: i16) -> HolidayRule {
+        HolidayRule::new(name, RuleDate::Easter(offset))
+    }
+
+    /// Instantiates a rule for a number of days from Orthodox Easter Sunday, negative for days
+    /// before.
+    #[must_use]
+    pub fn orthodox_easter(name: &str, offset: i16) -> HolidayRule {
+        HolidayRule::new(name, RuleDate::OrthodoxEaster(offset))
+    }
+
+    fn new(name: &str, date: RuleDate) -> HolidayRule {
+        HolidayRule {
+            name: name.to_string(),
+            date,
+            observance: Observance::Actual,
+        }
+    }
+
+    /// Parses a single rule of `NAME: DATE [observed nearest|monday]`.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` naming the component that could not be read, and where.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{HolidayRule, ParseComponent};
+    ///
+    /// let rule = HolidayRule::parse("Good Friday: easter -2").unwrap();
+    /// assert_eq!(rule.date_in(2024).unwrap().to_string(), "2024-03-29");
+    ///
+    /// let error = HolidayRule::parse("Memorial Day: last Mon of Mai").unwrap_err();
+    /// assert_eq!(error.component(), ParseComponent::Month);
+    /// assert_eq!(error.position(), 26);
+    /// ```
+    pub fn parse(s: &str) -> Result<HolidayRule, ParseError> {
+        parser::parse_holiday_rule(s, 0, s.len()).map_err(ParseError::from)
+    }
+
+    /// Sets the day the holiday is observed on if it falls on a weekend.
+    pub fn with_observance(&mut self, observance: Observance) {
+        self.observance = observance;
+    }
+
+    /// Returns the name of the holiday.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the day the holiday is observed on if it falls on a weekend.
+    #[must_use]
+    pub fn observance(&self) -> Observance {
+        self.observance
+    }
+
+    /// Returns the date of the holiday in the supplied year.
+    ///
+    /// # Returns
+    /// `None` if the holiday does not fall in the year, like February 29 in a year that is
+    /// not a leap year, or if the year is outside of the supported years.
+    #[must_use]
+    pub fn date_in(&self, year: u16) -> Option<Date> {
+        if !(1..=9999).contains(&year) {
+            return None;
+        }
+        let days = match self.date {
+            RuleDate::Fixed { month, day } => {
+                if !(1..=12).contains(&month) || day < 1 || day > days_in_month_of_year(year, month)
+                {
+                    return None;
+                }
+                days_from_civil(i64::from(year), month, day)
+            }
+            RuleDate::NthWeekDay {
+                nth,
+                weekday,
+                month,
+            } => nth_week_day(year, month, weekday, nth)?,
+            RuleDate::Easter(offset) => easter(year) + i64::from(offset),
+            RuleDate::OrthodoxEaster(offset) => orthodox_easter(year) + i64::from(offset),
+        };
+        Date::from_days_since_epoch(days).ok()
+    }
+
+    /// Returns the date the holiday is observed on in the supplied year, moved off a weekend
+    /// by its `Observance`.
+    ///
+    /// The observed date of a holiday on January 1 or December 31 may fall into the
+    /// neighbouring year.
+    ///
+    /// # Returns
+    /// `None` if the holiday does not fall in the year.
+    #[must_use]
+    pub fn observed_in(&self, year: u16) -> Option<Date> {
+        let date = self.date_in(year)?;
+        let shift = match (self.observance, date.weekday()) {
+            (Observance::NearestWeekday, Weekday::Saturday) => -1,
+            (Observance::FollowingMonday, Weekday::Saturday) => 2,
+            (Observance::NearestWeekday | Observance::FollowingMonday, Weekday::Sunday) => 1,
+            _ => 0,
+        };
+        date.add_days(shift).ok()
+    }
+}
+
+impl std::str::FromStr for HolidayRule {
+    type Err = HoraeError;
+
+    fn from_str(s: &str) -> Result<HolidayRule, HoraeError> {
+        parser::parse_holiday_rule(s, 0, s.len()).map_err(HoraeError::from)
+    }
+}
+
+// Display implementation
+
+impl std::fmt::Display for HolidayRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: ", self.name)?;
+        match self.date {
+            RuleDate::Fixed { month, day } => write!(f, "{month:02}-{day:02}")?,
+            RuleDate::NthWeekDay {
+                nth,
+                weekday,
+                month,
+            } => {
+                match nth {
+                    -1 => write!(f, "last")?,
+                    ..-1 => write!(f, "{} last", ordinal(nth.unsigned_abs()))?,
+                    _ => write!(f, "{}", ordinal(nth.unsigned_abs()))?,
+                }
+                write!(f, " {} of {}", weekday.short_name(), month.short_name())?;
+            }
+            RuleDate::Easter(offset) => {
+                write!(f, "easter")?;
+                write_offset(f, offset)?;
+            }
+            RuleDate::OrthodoxEaster(offset) => {
+                write!(f, "orthodox easter")?;
+                write_offset(f, offset)?;
+            }
+        }
+        match self.observance {
+            Observance::Actual => Ok(()),
+            Observance::NearestWeekday => write!(f, " observed nearest"),
+            Observance::FollowingMonday => write!(f, " observed monday"),
+        }
+    }
+}
+
+/// A named set of holiday rules, like the public holidays of a country.
+///
+/// Sets are written one rule per line, optionally starting with the name of the set in
+/// brackets. Empty lines and lines starting with `#` are skipped.
+///
+/// # Examples
+/// ```rust
+/// use horae::{BusinessCalendar, Date, HolidaySet};
+///
+/// let set = HolidaySet::parse(
+///     "[Germany]
+///      New Year's Day: 01-01
+///      Good Friday: easter -2
+///      Easter Monday: easter +1
+///      Christmas Day: 12-25",
+/// )
+/// .unwrap();
+/// assert_eq!(set.name(), "Germany");
+///
+/// let mut calendar = BusinessCalendar::new();
+/// calendar.add_holidays(set.dates_in(2024));
+/// assert!(!calendar.is_business_day(Date::from_ymd(2024, 4, 1).unwrap()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct HolidaySet {
+    name: String,
+    rules: Vec<HolidayRule>,
+}
+
+impl HolidaySet {
+    /// Instantiates an empty set of the supplied name.
+    #[must_use]
+    pub fn new(name: &str) -> HolidaySet {
+        HolidaySet {
+            name: name.to_string(),
+            rules: Vec::new(),
+        }
+    }
+
+    /// Parses a set of one rule per line, optionally starting with its name like `[Germany]`.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` naming the component that could not be read, and where in the
+    /// whole input.
+    pub fn parse(s: &str) -> Result<HolidaySet, ParseError> {
+        parser::parse_holiday_set(s).map_err(ParseError::from)
+    }
+
+    /// Returns the name of the set, empty if it has none.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Adds a rule to the set.
+    pub fn add_rule(&mut self, rule: HolidayRule) {
+        self.rules.push(rule);
+    }
+
+    /// Returns the rules of the set.
+    #[must_use]
+    pub fn rules(&self) -> &[HolidayRule] {
+        &self.rules
+    }
+
+    /// Returns the observed date and the name of every holiday of the supplied year, in order
+    /// of their dates.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::HolidaySet;
+    ///
+    /// let set = HolidaySet::parse(
+    ///     "Independence Day: 07-04 observed nearest
+    ///      Thanksgiving: 4th thursday of november",
+    /// )
+    /// .unwrap();
+    /// let holidays: Vec<String> = set
+    ///     .holidays_in(2026)
+    ///     .iter()
+    ///     .map(|(date, name)| format!("{date} {name}"))
+    ///     .collect();
+    /// assert_eq!(holidays, ["2026-07-03 Independence Day", "2026-11-26 Thanksgiving"]);
+    /// ```
+    #[must_use]
+    pub fn holidays_in(&self, year: u16) -> Vec<(Date, &str)> {
+        let mut holidays: Vec<(Date, &str)> = self
+            .rules
+            .iter()
+            .filter_map(|rule| Some((rule.observed_in(year)?, rule.name())))
+            .collect();
+        holidays.sort_by_key(|(date, _)| *date);
+        holidays
+    }
+
+    /// Returns the observed dates of every holiday of the supplied year, in order and without
+    /// duplicates.
+    #[must_use]
+    pub fn dates_in(&self, year: u16) -> Vec<Date> {
+        let mut dates: Vec<Date> = self
+            .holidays_in(year)
+            .into_iter()
+            .map(|(date, _)| date)
+            .collect();
+        dates.dedup();
+        dates
+    }
+}
+
+impl std::str::FromStr for HolidaySet {
+    type Err = HoraeError;
+
+    fn from_str(s: &str) -> Result<HolidaySet, HoraeError> {
+        parser::parse_holiday_set(s).map_err(HoraeError::from)
+    }
+}
+
+impl std::fmt::Display for HolidaySet {
+    /// Formats the set as it is parsed, one rule per line.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut lines = Vec::new();
+        if !self.name.is_empty() {
+            lines.push(format!("[{}]", self.name));
+        }
+        lines.extend(self.rules.iter().map(|rule| rule.to_string()));
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+/// Writes a trailing day offset like ` +1`, nothing for 0.
+fn write_offset(f: &mut std::fmt::Formatter, offset: i16) -> std::fmt::Result {
+    if offset == 0 {
+        Ok(())
+    } else {
+        write!(f, " {offset:+}")
+    }
+}
+
+/// Returns `1st`, `2nd`, `3rd`, `4th` and so on.
+fn ordinal(n: u8) -> String {
+    format!("{n}{}", ordinal_suffix(n))
+}
+
+/// Returns the days since the unix epoch of the nth week day of the month, from the end if
+/// negative.
+fn nth_week_day(year: u16, month: Month, weekday: Weekday, nth: i8) -> Option<i64> {
+    if nth == 0 {
+        return None;
+    }
+    let first = days_from_civil(i64::from(year), month.number(), 1);
+    let last = first + i64::from(month.days_in_year(year)) - 1;
+    let week_day_number = i64::from(weekday.number_from_monday());
+    let weeks = i64::from(nth.unsigned_abs()) - 1;
+    let day = if nth > 0 {
+        first + (week_day_number - i64::from(week_day(first))).rem_euclid(7) + weeks * 7
+    } else {
+        last - (i64::from(week_day(last)) - week_day_number).rem_euclid(7) - weeks * 7
+    };
+    (first..=last).contains(&day).then_some(day)
+}
+
+/// Returns the days since the unix epoch of Easter Sunday of the year in the Gregorian
+/// calendar.
+fn easter(year: u16) -> i64 {
+    let y = i64::from(year);
+    let a = y % 19;
+    let b = y / 100;
+    let c = y % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    days_from_civil(y, month as u8, day as u8)
+}
+
+/// Returns the days since the unix epoch of Orthodox Easter Sunday of the year, computed in
+/// the Julian calendar and converted to the Gregorian calendar.
+fn orthodox_easter(year: u16) -> i64 {
+    let y = i64::from(year);
+    let a = y % 4;
+    let b = y % 7;
+    let c = y % 19;
+    let d = (19 * c + 15) % 30;
+    let e = (2 * a + 4 * b - d + 34) % 7;
+    let month = (d + e + 114) / 31;
+    let day = (d + e + 114) % 31 + 1;
+    // The Julian calendar runs behind by the skipped century leap days, less the two of the
+    // first centuries
+    let julian_lag = y / 100 - y / 400 - 2;
+    days_from_civil(y, month as u8, day as u8) + julian_lag
+}
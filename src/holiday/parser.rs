@@ -0,0 +1,240 @@
+use crate::{
+    Month, ParseComponent, Weekday,
+    date_time::{
+        common::days_in_month_of_year,
+        parser::{Cursor, ParseFailure},
+    },
+};
+
+use super::{HolidayRule, HolidaySet, Observance, RuleDate};
+
+const EXPECTED_DATE: &str =
+    "a date like 12-25, 4th Thu of Nov, last Mon of May, easter -2 or orthodox easter";
+
+/// Parses a set of one rule per line, optionally starting with its name in brackets.
+pub fn parse_holiday_set(input: &str) -> Result<HolidaySet, ParseFailure> {
+    let cursor = Cursor::new(input);
+    let mut set = HolidaySet::default();
+    let mut line_start = 0;
+    for line in input.split('\n') {
+        let start = line_start + (line.len() - line.trim_start().len());
+        let end = line_start + line.trim_end().len();
+        line_start += line.len() + 1;
+        let text = &input[start..end.max(start)];
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+        if text.starts_with('[') {
+            if !set.rules.is_empty() || !set.name.is_empty() || !text.ends_with(']') {
+                return Err(cursor.error(
+                    ParseComponent::Holiday,
+                    start,
+                    "the name of the set like [Germany] before every rule",
+                ));
+            }
+            set.name = text[1..text.len() - 1].trim().to_string();
+            continue;
+        }
+        set.rules.push(parse_holiday_rule(input, start, end)?);
+    }
+    Ok(set)
+}
+
+/// Parses a rule of `NAME: DATE [observed nearest|monday]` from `start` up to `end`.
+pub fn parse_holiday_rule(
+    input: &str,
+    start: usize,
+    end: usize,
+) -> Result<HolidayRule, ParseFailure> {
+    let cursor = Cursor::new(input);
+    let text = &input[start..end];
+    let name_end = match text.find(':') {
+        Some(colon) if !text[..colon].trim().is_empty() => colon,
+        _ => {
+            return Err(cursor.error(
+                ParseComponent::Holiday,
+                start,
+                "a rule like Christmas Day: 12-25",
+            ));
+        }
+    };
+    let name = text[..name_end].trim();
+
+    let mut tokens = Vec::new();
+    let mut pos = start + name_end + 1;
+    for token in input[pos..end].split_ascii_whitespace() {
+        let token_start = pos + input[pos..end].find(token).unwrap_or(0);
+        pos = token_start + token.len();
+        tokens.push((token_start, token));
+    }
+    // Upper case copies for case insensitive matching
+    let upper: Vec<String> = tokens
+        .iter()
+        .map(|(_, token)| token.to_ascii_uppercase())
+        .collect();
+    let word = |i: usize| upper.get(i).map_or("", |token| token.as_str());
+    let position = |i: usize| tokens.get(i).map_or(end, |(start, _)| *start);
+    let date_error = |i: usize| cursor.error(ParseComponent::Holiday, position(i), EXPECTED_DATE);
+
+    let mut next;
+    let date = match word(0) {
+        "EASTER" => {
+            next = 1;
+            RuleDate::Easter(easter_offset(&cursor, &tokens, &mut next)?)
+        }
+        "ORTHODOX" => {
+            if word(1) != "EASTER" {
+                return Err(date_error(1));
+            }
+            next = 2;
+            RuleDate::OrthodoxEaster(easter_offset(&cursor, &tokens, &mut next)?)
+        }
+        first if first.contains('-') => {
+            next = 1;
+            fixed_date(&cursor, position(0), first)?
+        }
+        first => {
+            let mut nth = match first {
+                "LAST" => -1,
+                _ => ordinal(first).ok_or_else(|| date_error(0))?,
+            };
+            next = 1;
+            if nth > 0 && word(1) == "LAST" {
+                nth = -nth;
+                next = 2;
+            }
+            let weekday = Weekday::from_name(word(next)).ok_or_else(|| {
+                cursor.error(
+                    ParseComponent::WeekDay,
+                    position(next),
+                    "a week day like Mon or Monday",
+                )
+            })?;
+            if word(next + 1) != "OF" {
+                return Err(cursor.error(
+                    ParseComponent::Holiday,
+                    position(next + 1),
+                    "'of' between the week day and the month",
+                ));
+            }
+            let month = Month::from_name(word(next + 2)).ok_or_else(|| {
+                cursor.error(
+                    ParseComponent::Month,
+                    position(next + 2),
+                    "a month like Nov or November",
+                )
+            })?;
+            next += 3;
+            RuleDate::NthWeekDay {
+                nth,
+                weekday,
+                month,
+            }
+        }
+    };
+
+    let observance = match (word(next), word(next + 1)) {
+        ("", _) => Observance::Actual,
+        ("OBSERVED", "NEAREST") => Observance::NearestWeekday,
+        ("OBSERVED", "MONDAY") => Observance::FollowingMonday,
+        ("OBSERVED", _) => {
+            return Err(cursor.error(
+                ParseComponent::Holiday,
+                position(next + 1),
+                "'nearest' or 'monday' after 'observed'",
+            ));
+        }
+        _ => {
+            return Err(cursor.error(
+                ParseComponent::Holiday,
+                position(next),
+                "the end of the rule or 'observed'",
+            ));
+        }
+    };
+    if observance != Observance::Actual {
+        next += 2;
+    }
+    if next < tokens.len() {
+        return Err(cursor.error(
+            ParseComponent::Holiday,
+            position(next),
+            "the end of the rule",
+        ));
+    }
+
+    Ok(HolidayRule {
+        name: name.to_string(),
+        date,
+        observance,
+    })
+}
+
+/// Reads an optional day offset like `+1` or `-2` after `easter`, advancing `next` past it.
+fn easter_offset(
+    cursor: &Cursor,
+    tokens: &[(usize, &str)],
+    next: &mut usize,
+) -> Result<i16, ParseFailure> {
+    let Some((start, token)) = tokens.get(*next) else {
+        return Ok(0);
+    };
+    if !token.starts_with(['+', '-']) {
+        return Ok(0);
+    }
+    let offset = number(&token[1..], 3).ok_or_else(|| {
+        cursor.error(
+            ParseComponent::Day,
+            *start,
+            "a day offset of up to 999 like +1 or -2",
+        )
+    })? as i16;
+    *next += 1;
+    Ok(if token.starts_with('-') {
+        -offset
+    } else {
+        offset
+    })
+}
+
+/// Reads a fixed date of `MM-DD`, February 29 is allowed.
+fn fixed_date(cursor: &Cursor, start: usize, text: &str) -> Result<RuleDate, ParseFailure> {
+    let (month_text, day_text) = text.split_once('-').unwrap_or((text, ""));
+    let month = number(month_text, 2)
+        .map(|month| month as u8)
+        .filter(|month| (1..=12).contains(month))
+        .ok_or_else(|| cursor.error(ParseComponent::Month, start, "a month of 01-12"))?;
+    let day = number(day_text, 2)
+        .map(|day| day as u8)
+        // 2000 is a leap year, so February 29 is accepted
+        .filter(|day| *day >= 1 && *day <= days_in_month_of_year(2000, month))
+        .ok_or_else(|| {
+            cursor.error(
+                ParseComponent::Day,
+                start + month_text.len() + 1,
+                "a day that exists in the month",
+            )
+        })?;
+    Ok(RuleDate::Fixed { month, day })
+}
+
+/// Reads `1st` to `5th`.
+fn ordinal(word: &str) -> Option<i8> {
+    let nth = match word {
+        "1ST" => 1,
+        "2ND" => 2,
+        "3RD" => 3,
+        "4TH" => 4,
+        "5TH" => 5,
+        _ => return None,
+    };
+    Some(nth)
+}
+
+/// Reads a decimal number of up to `len` digits.
+fn number(text: &str, len: usize) -> Option<u16> {
+    if text.is_empty() || text.len() > len || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    text.parse().ok()
+}
@@ -4,14 +4,45 @@
 use std::time::Duration;
 
 use aequa::XffValue;
-use date_time::{DateTime, date::Date, time::Time};
+use date_time::DateTime;
+use date_time::common::{NANOS_IN_SECOND, SECONDS_IN_DAY};
+use tokenizer::{DEFAULT_PIVOT_YEAR, parse_with_format};
 
+mod business;
+mod calendar;
+mod cron;
 mod date_time;
+mod duration;
+mod error;
+mod holiday;
+mod range;
+mod recurrence;
 mod time_zones;
 mod tokenizer;
+mod unit;
 
+pub use crate::business::{BusinessCalendar, RollConvention};
+pub use crate::calendar::CalendarEvent;
+pub use crate::cron::{CronIter, CronSchedule};
+pub use crate::date_time::buffer::Rfc3339Buffer;
+pub use crate::date_time::date::{Date, MonthOverflow};
+pub use crate::date_time::iso_week::IsoWeek;
+pub use crate::date_time::iso8601::{Iso8601Format, Iso8601Precision};
+pub use crate::date_time::month::Month;
+pub use crate::date_time::rfc3339::{Rfc3339Options, SecondsFormat};
+pub use crate::date_time::time::Time;
+pub use crate::date_time::weekday::Weekday;
+pub use crate::duration::{IsoDuration, SignedDuration};
+pub use crate::error::{HoraeError, ParseComponent, ParseError};
+pub use crate::holiday::{HolidayRule, HolidaySet, Observance};
+pub use crate::range::{DateRange, Step, UtcRange};
+pub use crate::recurrence::{
+    Frequency, NthWeekday, Occurrences, RRule, RecurrenceSet, SetOccurrences, Until,
+};
 pub use crate::time_zones::TimeZone;
 pub use crate::time_zones::detect_local_offset as detect_local_utc_offset;
+pub use crate::tokenizer::Format;
+pub use crate::unit::TimeUnit;
 
 #[derive(Debug, Clone, Copy)]
 /// Basic building block for date and time.
@@ -91,9 +122,9 @@ impl Utc {
     /// Requires either `aequa`, `athena` or either `nabu` or `mawu` as a dependency to be able to
     /// work with the result.
     ///
-    /// # Returns
-    /// `None` if the `XffValue` is not of type `XffValue::DateTime`.
-    pub fn from_xffvalue(value: XffValue) -> Option<Utc> {
+    /// # Errors
+    /// `HoraeError::NotADateTime` if the `XffValue` is not of type `XffValue::DateTime`.
+    pub fn from_xffvalue(value: XffValue) -> Result<Utc, HoraeError> {
         DateTime::from_xffvalue(value).map(|date_time| Utc { date_time })
     }
 
@@ -147,13 +178,15 @@ impl Utc {
     /// # Panics
     /// Will panic if the date is invalid.
     /// Valid ranges are:
-    /// - year: 1970-9999
+    /// - year: 1-9999
     /// - month: 1-12
-    /// - day: 1-31
+    /// - day: 1 up to the number of days in the month, respecting leap years
     /// - hour: 0-23
     /// - minute: 0-59
     /// - second: 0-59
     ///
+    /// Use `try_from_ymd_hms` to receive a `HoraeError` instead.
+    ///
     /// # Example
     /// ```rust
     /// use horae::Utc;
@@ -169,6 +202,38 @@ impl Utc {
         }
     }
 
+    /// Instantiates a new `Utc` with the specified date and time, validating every field.
+    ///
+    /// The day is checked against the real length of the month, including leap years.
+    ///
+    /// # Errors
+    /// Returns a `HoraeError` naming the first field out of range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use horae::{HoraeError, Utc};
+    ///
+    /// let utc = Utc::try_from_ymd_hms(2024, 2, 29, 9, 9, 9).unwrap();
+    /// assert_eq!(utc.to_string(), "2024-02-29 09:09:09.000");
+    ///
+    /// assert_eq!(
+    ///     Utc::try_from_ymd_hms(2023, 2, 29, 9, 9, 9),
+    ///     Err(HoraeError::InvalidDay { year: 2023, month: 2, day: 29 })
+    /// );
+    /// assert_eq!(Utc::try_from_ymd_hms(2023, 1, 1, 24, 0, 0), Err(HoraeError::InvalidHour(24)));
+    /// ```
+    pub fn try_from_ymd_hms(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<Utc, HoraeError> {
+        DateTime::try_from_ymd_hms(year, month, day, hour, minute, second)
+            .map(|date_time| Utc { date_time })
+    }
+
     /// Instantiates a new `Utc` with the specified date, time and timezone.
     ///
     /// The date and time are assumed to be local to the specified timezone.
@@ -176,13 +241,15 @@ impl Utc {
     /// # Panics
     /// Will panic if the date is invalid.
     /// Valid ranges are:
-    /// - year: 1970-9999
+    /// - year: 1-9999
     /// - month: 1-12
-    /// - day: 1-31
+    /// - day: 1 up to the number of days in the month, respecting leap years
     /// - hour: 0-23
     /// - minute: 0-59
     /// - second: 0-59
     ///
+    /// Use `try_from_ymd_hms_timezone` to receive a `HoraeError` instead.
+    ///
     /// # Example
     /// ```rust
     /// use horae::{Utc, TimeZone};
@@ -210,6 +277,44 @@ impl Utc {
         }
     }
 
+    /// Instantiates a new `Utc` with the specified date, time and timezone, validating every
+    /// field.
+    ///
+    /// The date and time are assumed to be local to the specified timezone.
+    ///
+    /// # Errors
+    /// Returns a `HoraeError` naming the first field out of range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use horae::{HoraeError, TimeZone, Utc};
+    ///
+    /// let local = Utc::try_from_ymd_hms_timezone(2019, 1, 1, 9, 9, 9, TimeZone::CentralEuropeanSummerTime);
+    /// assert_eq!(local.unwrap().to_string(), "2019-01-01 09:09:09.000");
+    ///
+    /// let invalid = Utc::try_from_ymd_hms_timezone(2019, 4, 31, 9, 9, 9, TimeZone::CentralEuropeanSummerTime);
+    /// assert_eq!(invalid, Err(HoraeError::InvalidDay { year: 2019, month: 4, day: 31 }));
+    /// ```
+    pub fn try_from_ymd_hms_timezone(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        timezone: TimeZone,
+    ) -> Result<Utc, HoraeError> {
+        Utc::try_from_ymd_hms_offset(
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            timezone.get_utc_offset(),
+        )
+    }
+
     /// Instantiates a new `Utc` with the specified date, time and UTC offset in hours.
     ///
     /// The date and time are assumed to be local to the specified offset.
@@ -217,13 +322,15 @@ impl Utc {
     /// # Panics
     /// Will panic if the date is invalid.
     /// Valid ranges are:
-    /// - year: 1970-9999
+    /// - year: 1-9999
     /// - month: 1-12
-    /// - day: 1-31
+    /// - day: 1 up to the number of days in the month, respecting leap years
     /// - hour: 0-23
     /// - minute: 0-59
     /// - second: 0-59
     ///
+    /// Use `try_from_ymd_hms_offset` to receive a `HoraeError` instead.
+    ///
     /// # Example
     /// ```rust
     /// use horae::Utc;
@@ -249,6 +356,38 @@ impl Utc {
         }
     }
 
+    /// Instantiates a new `Utc` with the specified date, time and UTC offset in hours,
+    /// validating every field.
+    ///
+    /// The date and time are assumed to be local to the specified offset.
+    ///
+    /// # Errors
+    /// Returns a `HoraeError` naming the first field out of range, or
+    /// `HoraeError::InvalidUtcOffset` if the offset is not within -24 to +24 hours.
+    ///
+    /// # Example
+    /// ```rust
+    /// use horae::{HoraeError, Utc};
+    ///
+    /// let local = Utc::try_from_ymd_hms_offset(2019, 1, 1, 9, 9, 9, 5.5).unwrap(); // IST
+    /// assert_eq!(local.to_string(), "2019-01-01 09:09:09.000");
+    ///
+    /// let invalid = Utc::try_from_ymd_hms_offset(2019, 1, 1, 9, 9, 9, 25.0);
+    /// assert_eq!(invalid, Err(HoraeError::InvalidUtcOffset(25.0)));
+    /// ```
+    pub fn try_from_ymd_hms_offset(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        offset: f64,
+    ) -> Result<Utc, HoraeError> {
+        DateTime::try_from_ymd_hms_offset(year, month, day, hour, minute, second, offset)
+            .map(|date_time| Utc { date_time })
+    }
+
     /// Returns the current `Time` of the `Utc` instance.
     ///
     /// Useful for formatting or reading parts of `Time`.
@@ -259,10 +398,10 @@ impl Utc {
     ///
     /// let utc_now = Utc::from_ymd_hms(2019, 1, 1, 9, 9, 9);
     /// assert_eq!(utc_now.time().format("%HH:%MM:%SS"), "09:09:09");
-    /// assert_eq!(utc_now.time().hour, 9);
-    /// assert_eq!(utc_now.time().minute, 9);
-    /// assert_eq!(utc_now.time().second, 9);
-    /// assert_eq!(utc_now.time().subseconds, 0);
+    /// assert_eq!(utc_now.time().hour(), 9);
+    /// assert_eq!(utc_now.time().minute(), 9);
+    /// assert_eq!(utc_now.time().second(), 9);
+    /// assert_eq!(utc_now.time().nanosecond(), 0);
     /// ```
     #[must_use]
     pub fn time(&self) -> Time {
@@ -279,9 +418,9 @@ impl Utc {
     ///
     /// let utc_now = Utc::from_ymd_hms(2019, 1, 1, 9, 9, 9);
     /// assert_eq!(utc_now.date().format("%yyyy-%mm-%dd"), "2019-01-01");
-    /// assert_eq!(utc_now.date().year, 2019);
-    /// assert_eq!(utc_now.date().month, 1);
-    /// assert_eq!(utc_now.date().day, 1);
+    /// assert_eq!(utc_now.date().year(), 2019);
+    /// assert_eq!(utc_now.date().month(), 1);
+    /// assert_eq!(utc_now.date().day(), 1);
     /// ```
     #[must_use]
     pub fn date(&self) -> Date {
@@ -306,6 +445,111 @@ impl Utc {
         self.date_time.format(formatter)
     }
 
+    /// Returns the formatted string of the `Utc` instance according to the compiled format.
+    ///
+    /// Compiling the format once with `Format::new` avoids reading the format string again on
+    /// every call, and rejects unknown directives up front.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Format, Utc};
+    ///
+    /// let format = Format::new("%yyyy-%mm-%dd %HH:%MM:%SS").unwrap();
+    /// let utc = Utc::from_ymd_hms(2019, 1, 1, 9, 9, 9);
+    /// assert_eq!(utc.format_with(&format), "2019-01-01 09:09:09");
+    /// ```
+    #[must_use]
+    pub fn format_with(&self, format: &Format) -> String {
+        self.date_time.format_with(format)
+    }
+
+    /// Writes the `Utc` instance according to the compiled format into `out`, without
+    /// allocating.
+    ///
+    /// # Errors
+    /// Returns an error if `out` fails to write.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::fmt::Write;
+    /// use horae::{Format, Utc};
+    ///
+    /// let format = Format::new("%HH:%MM:%SS").unwrap();
+    /// let mut line = String::from("[");
+    /// Utc::from_ymd_hms(2019, 1, 1, 9, 9, 9).format_into(&mut line, &format).unwrap();
+    /// line.push_str("] started");
+    /// assert_eq!(line, "[09:09:09] started");
+    /// ```
+    pub fn format_into<W: std::fmt::Write>(
+        &self,
+        out: &mut W,
+        format: &Format,
+    ) -> std::fmt::Result {
+        self.date_time.format_into(out, format)
+    }
+
+    /// Parses a `Utc` from a string written in the supplied format, using the same directives as
+    /// `format`.
+    ///
+    /// The literal text between directives has to match exactly. Names of months and week days
+    /// are read in full or by their first three letters, in any case. `%tz` reads the UTC offset
+    /// it writes, or `UTC`, `Z`, `+05:30`, `+0530` or `+05`, a missing offset is read as UTC.
+    /// Two-digit years `%yy` are read in 1969-2068, see `parse_from_format_with_pivot`.
+    ///
+    /// A missing time is read as midnight, a missing month or day as the first. The date may
+    /// also be given by a day of the year `%ddd`, or an ISO year `%GGGG` and week `%wnn`, and any
+    /// week day, week or day of the year that is read has to match the date. A Unix timestamp
+    /// `%ts` or `%tms` takes precedence over all other components but the UTC offset.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` naming the component that could not be read, and where. An unknown
+    /// directive is reported as a `ParseComponent::Directive` of the format string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{ParseComponent, Utc};
+    ///
+    /// let utc = Utc::parse_from_format("31/12/2019 23:59", "%dd/%mm/%yyyy %HH:%MM").unwrap();
+    /// assert_eq!(utc.to_rfc3339(), "2019-12-31T23:59:00Z");
+    ///
+    /// let format = "%wd, %d %mmm %yy %HH:%MM %tz";
+    /// let utc = Utc::parse_from_format("Tue, 1 Jan 19 09:09 GMT+05:30", format).unwrap();
+    /// assert_eq!(utc.to_rfc3339(), "2019-01-01T09:09:00+05:30");
+    /// assert_eq!(Utc::parse_from_format(&utc.format(format), format), Ok(utc));
+    ///
+    /// let error = Utc::parse_from_format("Wed, 1 Jan 19 09:09 UTC", format).unwrap_err();
+    /// assert_eq!(error.component(), ParseComponent::WeekDay);
+    /// ```
+    pub fn parse_from_format(input: &str, format: &str) -> Result<Utc, ParseError> {
+        Utc::parse_from_format_with_pivot(input, format, DEFAULT_PIVOT_YEAR)
+    }
+
+    /// Parses a `Utc` like `parse_from_format`, reading two-digit years as the year ending in
+    /// them in the hundred years starting at `pivot_year`.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` naming the component that could not be read, and where.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Utc;
+    ///
+    /// let utc = Utc::parse_from_format_with_pivot("01/01/99", "%dd/%mm/%yy", 2000).unwrap();
+    /// assert_eq!(utc.date().year(), 2099);
+    /// let utc = Utc::parse_from_format_with_pivot("01/01/99", "%dd/%mm/%yy", 1950).unwrap();
+    /// assert_eq!(utc.date().year(), 1999);
+    /// ```
+    pub fn parse_from_format_with_pivot(
+        input: &str,
+        format: &str,
+        pivot_year: u16,
+    ) -> Result<Utc, ParseError> {
+        parse_with_format(input, &Format::new(format)?, pivot_year)
+            .and_then(|parsed| parsed.date_time())
+            .map(|date_time| Utc { date_time })
+            .map_err(ParseError::from)
+    }
+
     /// Returns the current UTC offset in hours.
     ///
     /// # Examples
@@ -320,6 +564,41 @@ impl Utc {
         self.date_time.get_utc_offset()
     }
 
+    /// Returns the current UTC offset in seconds.
+    pub(crate) fn utc_offset_seconds(&self) -> i32 {
+        self.date_time.get_utc_offset_seconds()
+    }
+
+    /// Returns the whole seconds since the unix epoch of the local wall-clock time.
+    pub(crate) fn local_seconds(&self) -> i64 {
+        self.unix_timestamp_secs() + i64::from(self.utc_offset_seconds())
+    }
+
+    /// Instantiates a new `Utc` from the whole seconds since the unix epoch of a local
+    /// wall-clock time in the supplied UTC offset in seconds.
+    pub(crate) fn from_local_seconds(local_seconds: i64, nanos: u32, offset: i32) -> Utc {
+        let mut date_time =
+            DateTime::from_timestamp_parts(local_seconds - i64::from(offset), nanos);
+        date_time.with_utc_offset_seconds(offset);
+        Utc { date_time }
+    }
+
+    /// Returns the week day of the local date in the held UTC offset.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Utc, Weekday};
+    ///
+    /// let mut utc = Utc::from_ymd_hms(2024, 3, 3, 23, 0, 0);
+    /// assert_eq!(utc.weekday(), Weekday::Sunday);
+    /// utc.with_utc_offset(2.0);
+    /// assert_eq!(utc.weekday(), Weekday::Monday);
+    /// ```
+    #[must_use]
+    pub fn weekday(&self) -> Weekday {
+        self.date().weekday()
+    }
+
     /// Returns the week number of the date according to ISO 8601.
     ///
     /// # Examples
@@ -334,8 +613,27 @@ impl Utc {
         self.date_time.get_weeknumber()
     }
 
+    /// Returns the ISO 8601 week and week-numbering year of the local date in the held UTC
+    /// offset.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{IsoWeek, Utc};
+    ///
+    /// let utc = Utc::from_ymd_hms(2024, 12, 30, 9, 0, 0);
+    /// assert_eq!(utc.iso_week(), IsoWeek::new(2025, 1).unwrap());
+    /// ```
+    #[must_use]
+    pub fn iso_week(&self) -> IsoWeek {
+        self.date().iso_week()
+    }
+
     /// Returns the unix timestamp of the `Utc` instance.
     ///
+    /// This is a lossy convenience, an `f64` cannot hold nanosecond precision for modern dates.
+    /// Use `unix_timestamp_secs` and `subsec_nanos`, or `unix_timestamp_nanos` for the exact
+    /// value.
+    ///
     /// # Examples
     /// ```rust
     /// use horae::Utc;
@@ -345,12 +643,75 @@ impl Utc {
     /// ```
     #[must_use]
     pub fn unix_timestamp(&self) -> f64 {
-        self.date_time.unix_timestamp
+        self.date_time.unix_timestamp_f64()
+    }
+
+    /// Returns the milliseconds since the unix epoch of the `Utc` instance.
+    ///
+    /// Negative for dates before the unix epoch.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Utc;
+    ///
+    /// let utc = Utc::from_ymd_hms(1969, 12, 31, 23, 59, 59);
+    /// assert_eq!(utc.unix_timestamp_millis(), -1000);
+    /// ```
+    #[must_use]
+    pub fn unix_timestamp_millis(&self) -> i64 {
+        self.date_time.unix_timestamp_millis()
+    }
+
+    /// Returns the whole seconds since the unix epoch of the `Utc` instance.
+    ///
+    /// Together with `subsec_nanos` this is the exact instant held.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Utc;
+    ///
+    /// let utc = Utc::from_timestamp_parts(1546333749, 123_456_789);
+    /// assert_eq!(utc.unix_timestamp_secs(), 1546333749);
+    /// assert_eq!(utc.subsec_nanos(), 123_456_789);
+    /// ```
+    #[must_use]
+    pub fn unix_timestamp_secs(&self) -> i64 {
+        self.date_time.unix_timestamp_secs()
+    }
+
+    /// Returns the nanoseconds into the current second of the `Utc` instance.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Utc;
+    ///
+    /// let utc = Utc::from_timestamp_parts(1546333749, 500);
+    /// assert_eq!(utc.subsec_nanos(), 500);
+    /// ```
+    #[must_use]
+    pub fn subsec_nanos(&self) -> u32 {
+        self.date_time.subsec_nanos()
+    }
+
+    /// Returns the exact nanoseconds since the unix epoch of the `Utc` instance.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Utc;
+    ///
+    /// let utc = Utc::from_timestamp_parts(1546333749, 1);
+    /// assert_eq!(utc.unix_timestamp_nanos(), 1_546_333_749_000_000_001);
+    /// ```
+    #[must_use]
+    pub fn unix_timestamp_nanos(&self) -> i128 {
+        self.date_time.unix_timestamp_nanos()
     }
 
     /// Returns the unix timestamp of the `Utc` instance as a `u64`.
     /// It holds the milliseconds since epoch.
     ///
+    /// Dates before the unix epoch saturate to 0, use `unix_timestamp_millis` for those.
+    ///
     /// # Examples
     /// ```rust
     /// use horae::Utc;
@@ -365,6 +726,13 @@ impl Utc {
 
     /// Instantiates a new `Utc` from a unix timestamp.
     ///
+    /// The timestamp is rounded to the nearest nanosecond, precision already lost in the `f64`
+    /// cannot be recovered. Use `from_timestamp_parts` or `from_timestamp_nanos` for exact
+    /// values.
+    ///
+    /// The timestamp is not checked: `NaN` is read as the epoch, and the date of a timestamp
+    /// outside of the years 1-9999 is meaningless. Use `try_from_timestamp` to reject them.
+    ///
     /// # Examples
     /// ```rust
     /// use horae::Utc;
@@ -374,9 +742,422 @@ impl Utc {
     /// ```
     #[must_use]
     pub fn from_timestamp(timestamp: f64) -> Utc {
-        let mut date_time = DateTime::from_timestamp(timestamp);
-        date_time.with_utc_offset(0.0);
-        Utc { date_time }
+        Utc {
+            date_time: DateTime::from_timestamp(timestamp),
+        }
+    }
+
+    /// Instantiates a new `Utc` from the whole seconds since the unix epoch and the nanoseconds
+    /// into that second, without any loss of precision.
+    ///
+    /// Nanoseconds of a second or more carry over into the seconds.
+    ///
+    /// The timestamp is not checked, the date of a timestamp outside of the years 1-9999 is
+    /// meaningless. Use `try_from_timestamp_parts` to reject them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Utc;
+    ///
+    /// let utc = Utc::from_timestamp_parts(1546333749, 123_456_789);
+    /// assert_eq!(utc.to_rfc3339(), "2019-01-01T09:09:09.123456789Z");
+    /// ```
+    #[must_use]
+    pub fn from_timestamp_parts(seconds: i64, nanos: u32) -> Utc {
+        Utc {
+            date_time: DateTime::from_timestamp_parts(seconds, nanos),
+        }
+    }
+
+    /// Instantiates a new `Utc` from the nanoseconds since the unix epoch, without any loss of
+    /// precision.
+    ///
+    /// The timestamp is not checked, the date of a timestamp outside of the years 1-9999 is
+    /// meaningless. Use `try_from_timestamp_nanos` to reject them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Utc;
+    ///
+    /// let utc = Utc::from_timestamp_nanos(1_546_333_749_000_000_001);
+    /// assert_eq!(utc.to_rfc3339(), "2019-01-01T09:09:09.000000001Z");
+    /// ```
+    #[must_use]
+    pub fn from_timestamp_nanos(nanos: i128) -> Utc {
+        Utc {
+            date_time: DateTime::from_timestamp_nanos(nanos),
+        }
+    }
+
+    /// Instantiates a new `Utc` from a unix timestamp like `from_timestamp`, checking that it is
+    /// within the supported years.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::OutOfRange` if the timestamp is `NaN`, infinite or outside of the
+    /// years 1-9999.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{HoraeError, Utc};
+    ///
+    /// assert_eq!(Utc::try_from_timestamp(1546333749.0), Ok(Utc::from_timestamp(1546333749.0)));
+    /// assert_eq!(Utc::try_from_timestamp(-1e15), Err(HoraeError::OutOfRange));
+    /// assert_eq!(Utc::try_from_timestamp(f64::NAN), Err(HoraeError::OutOfRange));
+    /// ```
+    pub fn try_from_timestamp(timestamp: f64) -> Result<Utc, HoraeError> {
+        // Far outside of the supported years, but still exact when read as seconds
+        if timestamp.is_nan() || timestamp.abs() >= 1e15 {
+            return Err(HoraeError::OutOfRange);
+        }
+        Utc::try_from_timestamp_nanos(Utc::from_timestamp(timestamp).unix_timestamp_nanos())
+    }
+
+    /// Instantiates a new `Utc` from the whole seconds and nanoseconds since the unix epoch like
+    /// `from_timestamp_parts`, checking that they are within the supported years.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::OutOfRange` if the timestamp is outside of the years 1-9999.
+    pub fn try_from_timestamp_parts(seconds: i64, nanos: u32) -> Result<Utc, HoraeError> {
+        let seconds = seconds
+            .checked_add(i64::from(nanos / NANOS_IN_SECOND))
+            .ok_or(HoraeError::OutOfRange)?;
+        Date::from_days_since_epoch(seconds.div_euclid(SECONDS_IN_DAY))?;
+        Ok(Utc::from_timestamp_parts(seconds, nanos % NANOS_IN_SECOND))
+    }
+
+    /// Instantiates a new `Utc` from the nanoseconds since the unix epoch like
+    /// `from_timestamp_nanos`, checking that they are within the supported years.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::OutOfRange` if the timestamp is outside of the years 1-9999.
+    pub fn try_from_timestamp_nanos(nanos: i128) -> Result<Utc, HoraeError> {
+        let nanos_in_second = i128::from(NANOS_IN_SECOND);
+        let seconds =
+            i64::try_from(nanos.div_euclid(nanos_in_second)).map_err(|_| HoraeError::OutOfRange)?;
+        Utc::try_from_timestamp_parts(seconds, nanos.rem_euclid(nanos_in_second) as u32)
+    }
+
+    /// Returns the duration that passed from `earlier` to this `Utc` instance.
+    ///
+    /// # Returns
+    /// `None` if `earlier` is actually later than this instance.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Utc;
+    ///
+    /// let earlier = Utc::from_ymd_hms(2019, 1, 1, 9, 9, 9);
+    /// let later = Utc::from_ymd_hms(2019, 1, 1, 9, 9, 11);
+    /// assert_eq!(later.checked_duration_since(earlier), Some(std::time::Duration::from_secs(2)));
+    /// assert_eq!(earlier.checked_duration_since(later), None);
+    /// ```
+    #[must_use]
+    pub fn checked_duration_since(&self, earlier: Utc) -> Option<Duration> {
+        Duration::try_from(*self - earlier).ok()
+    }
+
+    /// Returns the absolute duration between this and another `Utc` instance, regardless of
+    /// which is later.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Utc;
+    ///
+    /// let earlier = Utc::from_ymd_hms(2019, 1, 1, 9, 9, 9);
+    /// let later = Utc::from_ymd_hms(2019, 1, 1, 9, 9, 11);
+    /// assert_eq!(earlier.abs_diff(later), std::time::Duration::from_secs(2));
+    /// assert_eq!(later.abs_diff(earlier), std::time::Duration::from_secs(2));
+    /// ```
+    #[must_use]
+    pub fn abs_diff(&self, other: Utc) -> Duration {
+        (*self - other).unsigned_abs()
+    }
+
+    /// Returns a new `Utc` moved by the supplied number of calendar months, keeping the local
+    /// time and UTC offset.
+    ///
+    /// Operates on the local date in the held UTC offset. If the day does not exist in the new
+    /// month, it is resolved according to `overflow`.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::InvalidDay` if the day does not exist and `overflow` is
+    /// `MonthOverflow::Fail`, or `HoraeError::OutOfRange` if the result is outside of the
+    /// supported years.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{HoraeError, MonthOverflow, Utc};
+    ///
+    /// let utc = Utc::from_ymd_hms(2024, 1, 31, 9, 9, 9);
+    /// assert_eq!(utc.add_months(1, MonthOverflow::Clamp).unwrap().to_string(), "2024-02-29 09:09:09.000");
+    /// assert_eq!(utc.add_months(1, MonthOverflow::Rollover).unwrap().to_string(), "2024-03-02 09:09:09.000");
+    /// assert_eq!(
+    ///     utc.add_months(1, MonthOverflow::Fail),
+    ///     Err(HoraeError::InvalidDay { year: 2024, month: 2, day: 31 })
+    /// );
+    /// assert_eq!(utc.add_months(-2, MonthOverflow::Clamp).unwrap().to_string(), "2023-11-30 09:09:09.000");
+    /// ```
+    pub fn add_months(&self, months: i32, overflow: MonthOverflow) -> Result<Utc, HoraeError> {
+        let date = self.date_time.date().add_months(months, overflow)?;
+        Ok(Utc {
+            date_time: self.date_time.with_local_date(date),
+        })
+    }
+
+    /// Returns a new `Utc` moved by the supplied number of calendar years, keeping the local
+    /// time and UTC offset.
+    ///
+    /// Operates on the local date in the held UTC offset. February 29 is resolved according to
+    /// `overflow` if the new year is not a leap year.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::InvalidDay` if the day does not exist and `overflow` is
+    /// `MonthOverflow::Fail`, or `HoraeError::OutOfRange` if the result is outside of the
+    /// supported years.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{MonthOverflow, Utc};
+    ///
+    /// let utc = Utc::from_ymd_hms(2024, 2, 29, 9, 9, 9);
+    /// assert_eq!(utc.add_years(1, MonthOverflow::Clamp).unwrap().to_string(), "2025-02-28 09:09:09.000");
+    /// assert_eq!(utc.add_years(1, MonthOverflow::Rollover).unwrap().to_string(), "2025-03-01 09:09:09.000");
+    /// assert_eq!(utc.add_years(4, MonthOverflow::Fail).unwrap().to_string(), "2028-02-29 09:09:09.000");
+    /// ```
+    pub fn add_years(&self, years: i32, overflow: MonthOverflow) -> Result<Utc, HoraeError> {
+        let months = years.checked_mul(12).ok_or(HoraeError::OutOfRange)?;
+        self.add_months(months, overflow)
+    }
+
+    /// Returns a new `Utc` moved by the supplied ISO 8601 duration.
+    ///
+    /// The calendar components are applied first, years and months with `add_months` and
+    /// weeks and days with `add_days`, then the exact hours, minutes and seconds are added.
+    /// A negative duration moves every component backwards in the same order.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::InvalidDay` if the day does not exist after adding the months and
+    /// `overflow` is `MonthOverflow::Fail`, or `HoraeError::OutOfRange` if the result is
+    /// outside of the supported years.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{IsoDuration, MonthOverflow, Utc};
+    ///
+    /// let utc = Utc::from_ymd_hms(2024, 1, 31, 22, 0, 0);
+    /// let duration = IsoDuration::parse("P1MT3H").unwrap();
+    /// let later = utc.add_iso_duration(&duration, MonthOverflow::Clamp).unwrap();
+    /// assert_eq!(later.to_string(), "2024-03-01 01:00:00.000");
+    /// ```
+    pub fn add_iso_duration(
+        &self,
+        duration: &IsoDuration,
+        overflow: MonthOverflow,
+    ) -> Result<Utc, HoraeError> {
+        let months = i32::try_from(duration.signed_months()).map_err(|_| HoraeError::OutOfRange)?;
+        self.add_months(months, overflow)?
+            .add_days(duration.signed_days())?
+            .checked_add_duration(duration.signed_exact())
+    }
+
+    /// Returns a new `Utc` moved by the supplied duration, keeping the UTC offset, or
+    /// `HoraeError::OutOfRange` if the local date is outside of the supported years.
+    fn checked_add_duration(&self, duration: SignedDuration) -> Result<Utc, HoraeError> {
+        let local_nanos = i128::from(self.local_seconds()) * i128::from(NANOS_IN_SECOND)
+            + i128::from(self.subsec_nanos());
+        let local_nanos = local_nanos
+            .checked_add(duration.as_nanos())
+            .ok_or(HoraeError::OutOfRange)?;
+        let days = local_nanos.div_euclid(i128::from(SECONDS_IN_DAY) * i128::from(NANOS_IN_SECOND));
+        Date::from_days_since_epoch(i64::try_from(days).map_err(|_| HoraeError::OutOfRange)?)?;
+        Ok(*self + duration)
+    }
+
+    /// Returns an iterator over the instants from `start` up to, but not including, `end`,
+    /// spaced by `step`.
+    ///
+    /// Every instant keeps the UTC offset of `start`, calendar steps move its local date.
+    /// The range is empty if `end` is not after `start`.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::InvalidStep` if the step is not positive, or
+    /// `HoraeError::OutOfRange` if the range has more than `u64::MAX` instants.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Step, Utc};
+    ///
+    /// let start = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let end = Utc::from_ymd_hms(2024, 1, 1, 1, 0, 0);
+    /// let range = Utc::range(start, end, Step::seconds(15 * 60)).unwrap();
+    /// let times: Vec<String> = range.map(|utc| utc.time().format("%HH:%MM")).collect();
+    /// assert_eq!(times, ["00:00", "00:15", "00:30", "00:45"]);
+    /// ```
+    pub fn range(start: Utc, end: Utc, step: Step) -> Result<UtcRange, HoraeError> {
+        UtcRange::new(start, end, step, false)
+    }
+
+    /// Returns an iterator over the instants from `start` up to and including `end`, spaced by
+    /// `step`.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::InvalidStep` if the step is not positive, or
+    /// `HoraeError::OutOfRange` if the range has more than `u64::MAX` instants.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Step, Utc};
+    ///
+    /// let start = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let end = Utc::from_ymd_hms(2024, 1, 15, 0, 0, 0);
+    /// assert_eq!(Utc::range_inclusive(start, end, Step::Weeks(1)).unwrap().len(), 3);
+    /// ```
+    pub fn range_inclusive(start: Utc, end: Utc, step: Step) -> Result<UtcRange, HoraeError> {
+        UtcRange::new(start, end, step, true)
+    }
+
+    /// Returns a new `Utc` moved by the supplied number of calendar days, keeping the local
+    /// time and UTC offset.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::OutOfRange` if the result is outside of the supported years.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Utc;
+    ///
+    /// let utc = Utc::from_ymd_hms(2024, 2, 28, 9, 9, 9);
+    /// assert_eq!(utc.add_days(2).unwrap().to_string(), "2024-03-01 09:09:09.000");
+    /// assert_eq!(utc.add_days(-59).unwrap().to_string(), "2023-12-31 09:09:09.000");
+    /// ```
+    pub fn add_days(&self, days: i64) -> Result<Utc, HoraeError> {
+        let seconds = days
+            .checked_mul(SECONDS_IN_DAY)
+            .ok_or(HoraeError::OutOfRange)?;
+        self.checked_add_duration(SignedDuration::from_secs(seconds))
+    }
+
+    /// Returns a new `Utc` moved by the supplied number of weeks, keeping the local time and
+    /// UTC offset.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::OutOfRange` if the result is outside of the supported years.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Utc;
+    ///
+    /// let utc = Utc::from_ymd_hms(2024, 12, 25, 9, 9, 9);
+    /// assert_eq!(utc.add_weeks(1).unwrap().to_string(), "2025-01-01 09:09:09.000");
+    /// ```
+    pub fn add_weeks(&self, weeks: i64) -> Result<Utc, HoraeError> {
+        self.add_days(weeks.checked_mul(7).ok_or(HoraeError::OutOfRange)?)
+    }
+
+    /// Returns a new `Utc` at the start of the unit containing it, in the held UTC offset.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{TimeUnit, Utc};
+    ///
+    /// let mut utc = Utc::from_ymd_hms(2024, 5, 15, 1, 30, 0);
+    /// assert_eq!(utc.truncate(TimeUnit::Day).to_string(), "2024-05-15 00:00:00.000");
+    /// assert_eq!(utc.truncate(TimeUnit::Quarter).to_string(), "2024-04-01 00:00:00.000");
+    ///
+    /// // Local midnight, not UTC midnight
+    /// utc.with_utc_offset(2.0);
+    /// let day = utc.truncate(TimeUnit::Day);
+    /// assert_eq!(day.to_string(), "2024-05-15 00:00:00.000");
+    /// assert_eq!(day, Utc::from_ymd_hms(2024, 5, 14, 22, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn truncate(&self, unit: TimeUnit) -> Utc {
+        let (start, _) = unit.bounds(self.local_seconds());
+        Utc::from_local_seconds(start, 0, self.utc_offset_seconds())
+    }
+
+    /// Returns a new `Utc` at the start of the unit nearest to it, in the held UTC offset.
+    /// Halfway between two starts rounds up.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{TimeUnit, Utc};
+    ///
+    /// let utc = Utc::from_ymd_hms(2024, 5, 15, 12, 0, 0);
+    /// assert_eq!(utc.round(TimeUnit::Day).to_string(), "2024-05-16 00:00:00.000");
+    /// assert_eq!(utc.round(TimeUnit::Month).to_string(), "2024-05-01 00:00:00.000");
+    /// ```
+    #[must_use]
+    pub fn round(&self, unit: TimeUnit) -> Utc {
+        let local_seconds = self.local_seconds();
+        let (start, end) = unit.bounds(local_seconds);
+        let nanos = |seconds: i64| i128::from(seconds) * i128::from(NANOS_IN_SECOND);
+        let position = nanos(local_seconds) + i128::from(self.subsec_nanos());
+        let nearest = if position - nanos(start) < nanos(end) - position {
+            start
+        } else {
+            end
+        };
+        Utc::from_local_seconds(nearest, 0, self.utc_offset_seconds())
+    }
+
+    /// Returns a new `Utc` at the start of the next unit, or itself if it already is the start
+    /// of a unit, in the held UTC offset.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{TimeUnit, Utc};
+    ///
+    /// let utc = Utc::from_ymd_hms(2024, 5, 15, 12, 0, 0);
+    /// assert_eq!(utc.ceil(TimeUnit::Hour), utc);
+    /// assert_eq!(utc.ceil(TimeUnit::Week).to_string(), "2024-05-20 00:00:00.000");
+    /// ```
+    #[must_use]
+    pub fn ceil(&self, unit: TimeUnit) -> Utc {
+        let local_seconds = self.local_seconds();
+        let (start, end) = unit.bounds(local_seconds);
+        if start == local_seconds && self.subsec_nanos() == 0 {
+            *self
+        } else {
+            Utc::from_local_seconds(end, 0, self.utc_offset_seconds())
+        }
+    }
+
+    /// Returns the start of the bucket of the supplied width containing the `Utc`, with buckets
+    /// aligned to `origin`.
+    ///
+    /// Buckets are exact durations counted from `origin`, before or after the `Utc`.
+    /// The returned `Utc` keeps the held UTC offset.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::InvalidStep` if the width is not positive.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{SignedDuration, Utc};
+    ///
+    /// let utc = Utc::from_ymd_hms(2024, 5, 15, 10, 52, 0);
+    /// let origin = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let bucket = utc.duration_bucket(SignedDuration::from_secs(15 * 60), origin).unwrap();
+    /// assert_eq!(bucket.to_string(), "2024-05-15 10:45:00.000");
+    ///
+    /// // Six hour buckets starting at 03:00
+    /// let origin = Utc::from_ymd_hms(2024, 1, 1, 3, 0, 0);
+    /// let bucket = utc.duration_bucket(SignedDuration::from_secs(6 * 3600), origin).unwrap();
+    /// assert_eq!(bucket.to_string(), "2024-05-15 09:00:00.000");
+    /// ```
+    pub fn duration_bucket(&self, width: SignedDuration, origin: Utc) -> Result<Utc, HoraeError> {
+        if !width.is_positive() {
+            return Err(HoraeError::InvalidStep);
+        }
+        let since_origin = self.unix_timestamp_nanos() - origin.unix_timestamp_nanos();
+        let start = origin.unix_timestamp_nanos()
+            + since_origin.div_euclid(width.as_nanos()) * width.as_nanos();
+        let mut bucket = Utc::from_timestamp_nanos(start);
+        bucket
+            .date_time
+            .with_utc_offset_seconds(self.utc_offset_seconds());
+        Ok(bucket)
     }
 
     /// Returns the formatted string of the `Utc` instance according to RFC 3339.
@@ -385,12 +1166,201 @@ impl Utc {
         self.date_time.to_rfc3339()
     }
 
-    /// Parses an RFC 3339 string into a `Utc`.
+    /// Returns the formatted string of the `Utc` instance according to RFC 3339, with the
+    /// fraction digits and the writing of UTC given by `options`.
+    ///
+    /// Fixed fraction digits keep timestamps in columns of the same width.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Rfc3339Options, SecondsFormat, Utc};
+    ///
+    /// let utc = Utc::from_timestamp_nanos(1_546_333_749_120_000_000);
+    /// let options = |seconds| Rfc3339Options { seconds, utc_as_z: true };
+    /// assert_eq!(utc.to_rfc3339_with(options(SecondsFormat::Seconds)), "2019-01-01T09:09:09Z");
+    /// assert_eq!(utc.to_rfc3339_with(options(SecondsFormat::Millis)), "2019-01-01T09:09:09.120Z");
+    /// assert_eq!(utc.to_rfc3339_with(options(SecondsFormat::Micros)), "2019-01-01T09:09:09.120000Z");
+    /// assert_eq!(utc.to_rfc3339_with(options(SecondsFormat::Nanos)), "2019-01-01T09:09:09.120000000Z");
+    /// assert_eq!(utc.to_rfc3339_with(options(SecondsFormat::Trimmed)), "2019-01-01T09:09:09.12Z");
+    ///
+    /// let options = Rfc3339Options { seconds: SecondsFormat::Seconds, utc_as_z: false };
+    /// assert_eq!(utc.to_rfc3339_with(options), "2019-01-01T09:09:09+00:00");
+    /// ```
+    #[must_use]
+    pub fn to_rfc3339_with(&self, options: Rfc3339Options) -> String {
+        self.date_time.to_rfc3339_with(options)
+    }
+
+    /// Returns the `Utc` instance according to RFC 3339 in a buffer on the stack, without
+    /// allocating.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::Utc;
+    ///
+    /// let mut utc = Utc::from_timestamp_nanos(1_546_333_749_500_000_000);
+    /// utc.with_utc_offset(5.5);
+    /// let buffer = utc.to_rfc3339_buffer();
+    /// assert_eq!(&*buffer, "2019-01-01T14:39:09.5+05:30");
+    /// assert_eq!(&*buffer, utc.to_rfc3339());
+    /// ```
+    #[must_use]
+    pub fn to_rfc3339_buffer(&self) -> Rfc3339Buffer {
+        self.to_rfc3339_buffer_with(Rfc3339Options::default())
+    }
+
+    /// Returns the `Utc` instance according to RFC 3339 in a buffer on the stack like
+    /// `to_rfc3339_buffer`, with the fraction digits and the writing of UTC given by `options`.
     #[must_use]
-    pub fn from_rfc3339(s: &str) -> Option<Utc> {
+    pub fn to_rfc3339_buffer_with(&self, options: Rfc3339Options) -> Rfc3339Buffer {
+        let mut buffer = Rfc3339Buffer::new();
+        // The buffer is sized for the longest string `fmt_rfc3339` writes
+        let _ = self.date_time.fmt_rfc3339(&mut buffer, options);
+        buffer
+    }
+
+    /// Writes the `Utc` instance according to RFC 3339 into `out`, without allocating.
+    ///
+    /// # Errors
+    /// Returns the error of `out` if it fails to write.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::io::Write;
+    /// use horae::Utc;
+    ///
+    /// let mut log = Vec::new();
+    /// Utc::from_ymd_hms(2019, 1, 1, 9, 9, 9).write_rfc3339(&mut log).unwrap();
+    /// log.write_all(b" started\n").unwrap();
+    /// assert_eq!(log, b"2019-01-01T09:09:09Z started\n");
+    /// ```
+    pub fn write_rfc3339<W: std::io::Write>(&self, out: &mut W) -> std::io::Result<()> {
+        self.write_rfc3339_with(out, Rfc3339Options::default())
+    }
+
+    /// Writes the `Utc` instance according to RFC 3339 into `out` like `write_rfc3339`, with the
+    /// fraction digits and the writing of UTC given by `options`.
+    ///
+    /// # Errors
+    /// Returns the error of `out` if it fails to write.
+    pub fn write_rfc3339_with<W: std::io::Write>(
+        &self,
+        out: &mut W,
+        options: Rfc3339Options,
+    ) -> std::io::Result<()> {
+        let mut buffer = Rfc3339Buffer::new();
+        self.date_time
+            .fmt_rfc3339(&mut buffer, options)
+            .map_err(std::io::Error::other)?;
+        out.write_all(buffer.as_bytes())
+    }
+
+    /// Parses an RFC 3339 string into a `Utc`.
+    ///
+    /// Seconds and the offset may be omitted, a missing offset is read as UTC.
+    /// A leap second (`:60`) is folded into the first second of the following minute.
+    ///
+    /// # Errors
+    /// Returns `HoraeError::Parse` naming the component that could not be read, or the
+    /// `HoraeError` of the first field out of range.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{HoraeError, Utc};
+    ///
+    /// let utc = Utc::from_rfc3339("1985-04-12T23:20:50.52Z").unwrap();
+    /// assert_eq!(utc.to_rfc3339(), "1985-04-12T23:20:50.52Z");
+    ///
+    /// assert_eq!(
+    ///     Utc::from_rfc3339("2023-02-31T00:00:00Z"),
+    ///     Err(HoraeError::InvalidDay { year: 2023, month: 2, day: 31 })
+    /// );
+    /// assert!(matches!(Utc::from_rfc3339("2023-02-1x"), Err(HoraeError::Parse(_))));
+    /// ```
+    pub fn from_rfc3339(s: &str) -> Result<Utc, HoraeError> {
         DateTime::from_rfc3339(s).map(|date_time| Utc { date_time })
     }
 
+    /// Parses an RFC 3339 string into a `Utc`, returning a positioned `ParseError` on failure.
+    ///
+    /// Accepts the same input as `from_rfc3339`. The error names the component that failed,
+    /// gives its byte offset, and renders a caret under the offending column when displayed.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` for any invalid or out of range component.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{ParseComponent, Utc};
+    ///
+    /// let utc = Utc::parse_rfc3339("1985-04-12T23:20:50.52Z").unwrap();
+    /// assert_eq!(utc.to_rfc3339(), "1985-04-12T23:20:50.52Z");
+    ///
+    /// let error = Utc::parse_rfc3339("1985-04-12T23:20:50.52+1:00").unwrap_err();
+    /// assert_eq!(error.component(), ParseComponent::Offset);
+    /// assert_eq!(error.position(), 23);
+    /// println!("{error}");
+    /// // invalid offset at byte 23, expected two digit offset hours
+    /// // 1985-04-12T23:20:50.52+1:00
+    /// //                        ^
+    /// ```
+    pub fn parse_rfc3339(s: &str) -> Result<Utc, ParseError> {
+        DateTime::parse_rfc3339(s).map(|date_time| Utc { date_time })
+    }
+
+    /// Returns the formatted string of the `Utc` instance according to ISO 8601, in basic or
+    /// extended format.
+    ///
+    /// The extended format is the same as `to_rfc3339`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Iso8601Format, Utc};
+    ///
+    /// let mut utc = Utc::from_ymd_hms(2019, 1, 1, 3, 39, 9);
+    /// utc.with_utc_offset(5.5);
+    /// assert_eq!(utc.to_iso8601(Iso8601Format::Basic), "20190101T090909+0530");
+    /// assert_eq!(utc.to_iso8601(Iso8601Format::Extended), "2019-01-01T09:09:09+05:30");
+    /// ```
+    #[must_use]
+    pub fn to_iso8601(&self, format: Iso8601Format) -> String {
+        self.date_time.to_iso8601(format)
+    }
+
+    /// Parses an ISO 8601 string into a `Utc`, and returns the precision it was given in.
+    ///
+    /// Accepts calendar (`2019-01-01`), ordinal (`2019-001`) and week (`2019-W01-2`) dates in
+    /// basic or extended format, and dates of reduced precision (`2019-01`, `2019`), which are
+    /// read as their first day. The date may be followed by `T` and a time of hours, minutes or
+    /// seconds, whose smallest component may have a decimal fraction after `.` or `,`, and a UTC
+    /// offset of `Z`, `+hh`, `+hh:mm` or `+hhmm`.
+    ///
+    /// A missing time is read as midnight and a missing offset as UTC. `24:00` is the end of the
+    /// day, and a leap second (`:60`) is folded into the first second of the following minute.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` naming the component that could not be read, and where.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Iso8601Precision, Utc};
+    ///
+    /// let (utc, precision) = Utc::parse_iso8601("20190101T090909Z").unwrap();
+    /// assert_eq!(utc.to_rfc3339(), "2019-01-01T09:09:09Z");
+    /// assert_eq!(precision, Iso8601Precision::Second);
+    ///
+    /// let (utc, precision) = Utc::parse_iso8601("2019-01-01T09:30,5+0530").unwrap();
+    /// assert_eq!(utc.to_rfc3339(), "2019-01-01T09:30:30+05:30");
+    /// assert_eq!(precision, Iso8601Precision::Minute);
+    ///
+    /// let (utc, precision) = Utc::parse_iso8601("2019-01").unwrap();
+    /// assert_eq!(utc.to_rfc3339(), "2019-01-01T00:00:00Z");
+    /// assert_eq!(precision, Iso8601Precision::Month);
+    /// ```
+    pub fn parse_iso8601(s: &str) -> Result<(Utc, Iso8601Precision), ParseError> {
+        DateTime::parse_iso8601(s).map(|(date_time, precision)| (Utc { date_time }, precision))
+    }
+
     /// Returns the formatted string of the `Utc` instance according to RFC 9557.
     #[must_use]
     pub fn to_rfc9557(&self) -> String {
@@ -398,10 +1368,35 @@ impl Utc {
     }
 
     /// Parses an RFC 9557 string into a `Utc`.
-    #[must_use]
-    pub fn from_rfc9557(s: &str) -> Option<Utc> {
+    ///
+    /// Unknown elective suffix tags are ignored.
+    ///
+    /// # Errors
+    /// Returns the errors of `from_rfc3339`, or `HoraeError::Parse` if a critical suffix tag
+    /// is unknown or inconsistent with the offset.
+    pub fn from_rfc9557(s: &str) -> Result<Utc, HoraeError> {
         DateTime::from_rfc9557(s).map(|date_time| Utc { date_time })
     }
+
+    /// Parses an RFC 9557 string into a `Utc`, returning a positioned `ParseError` on failure.
+    ///
+    /// Accepts the same input as `from_rfc9557`.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` for any invalid component, or an unknown or inconsistent critical
+    /// suffix tag.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{ParseComponent, Utc};
+    ///
+    /// let error = Utc::parse_rfc9557("1996-12-19T16:39:57-08:00[!u-ca=hebrew]").unwrap_err();
+    /// assert_eq!(error.component(), ParseComponent::SuffixTag);
+    /// assert_eq!(error.position(), 25);
+    /// ```
+    pub fn parse_rfc9557(s: &str) -> Result<Utc, ParseError> {
+        DateTime::parse_rfc9557(s).map(|date_time| Utc { date_time })
+    }
 }
 
 impl From<f64> for Utc {
@@ -418,18 +1413,18 @@ impl From<Utc> for f64 {
 
 impl From<std::time::SystemTime> for Utc {
     fn from(st: std::time::SystemTime) -> Self {
-        match st.duration_since(std::time::UNIX_EPOCH) {
-            Ok(d) => Utc::from_timestamp(d.as_secs_f64()),
-            Err(_) => Utc::from_timestamp(0.0),
+        Utc {
+            date_time: DateTime::from_system_time(st),
         }
     }
 }
 
 impl std::ops::Sub<Utc> for Utc {
-    type Output = Duration;
+    type Output = SignedDuration;
 
-    fn sub(self, rhs: Utc) -> Duration {
-        Duration::from_secs_f64(self.unix_timestamp() - rhs.unix_timestamp())
+    /// Returns the signed duration from `rhs` to `self`, negative if `rhs` is later.
+    fn sub(self, rhs: Utc) -> SignedDuration {
+        SignedDuration::from_nanos(self.unix_timestamp_nanos() - rhs.unix_timestamp_nanos())
     }
 }
 
@@ -439,10 +1434,9 @@ impl std::ops::Add<Duration> for Utc {
     type Output = Utc;
 
     fn add(self, rhs: Duration) -> Utc {
-        let new_timestamp = self.date_time.unix_timestamp + rhs.as_secs_f64();
-        let mut date_time = DateTime::from_timestamp(new_timestamp);
-        date_time.with_utc_offset(self.date_time.timezone);
-        Utc { date_time }
+        Utc {
+            date_time: self.date_time.add_nanos(rhs.as_nanos() as i128),
+        }
     }
 }
 
@@ -452,10 +1446,31 @@ impl std::ops::Sub<Duration> for Utc {
     type Output = Utc;
 
     fn sub(self, rhs: Duration) -> Utc {
-        let new_timestamp = self.date_time.unix_timestamp - rhs.as_secs_f64();
-        let mut date_time = DateTime::from_timestamp(new_timestamp);
-        date_time.with_utc_offset(self.date_time.timezone);
-        Utc { date_time }
+        Utc {
+            date_time: self.date_time.add_nanos(-(rhs.as_nanos() as i128)),
+        }
+    }
+}
+
+// Signed duration implementation
+
+impl std::ops::Add<SignedDuration> for Utc {
+    type Output = Utc;
+
+    fn add(self, rhs: SignedDuration) -> Utc {
+        Utc {
+            date_time: self.date_time.add_nanos(rhs.as_nanos()),
+        }
+    }
+}
+
+impl std::ops::Sub<SignedDuration> for Utc {
+    type Output = Utc;
+
+    fn sub(self, rhs: SignedDuration) -> Utc {
+        Utc {
+            date_time: self.date_time.add_nanos(-rhs.as_nanos()),
+        }
     }
 }
 
@@ -473,8 +1488,22 @@ impl PartialEq for Utc {
     }
 }
 
+impl Eq for Utc {}
+
 impl PartialOrd for Utc {
     fn partial_cmp(&self, other: &Utc) -> Option<std::cmp::Ordering> {
-        self.date_time.partial_cmp(&other.date_time)
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Utc {
+    fn cmp(&self, other: &Utc) -> std::cmp::Ordering {
+        self.date_time.cmp(&other.date_time)
+    }
+}
+
+impl std::hash::Hash for Utc {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.date_time.hash(state);
     }
 }
@@ -0,0 +1,312 @@
+use std::iter::FusedIterator;
+
+use crate::{Date, HoraeError, MonthOverflow, SignedDuration, Utc};
+
+/// The distance between two elements of a `UtcRange` or `DateRange`.
+///
+/// Calendar steps of months and years are always counted from the start of the range, so a
+/// range starting on January 31 yields February 29, March 31, April 30 and so on.
+/// A day that does not exist in the reached month is clamped to the last day of the month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Step {
+    /// An exact duration, only for `UtcRange`. Has to be positive.
+    Exact(SignedDuration),
+    Days(u32),
+    Weeks(u32),
+    Months(u32),
+    Years(u32),
+}
+
+impl Step {
+    /// Returns a step of the supplied number of whole seconds.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{SignedDuration, Step};
+    ///
+    /// assert_eq!(Step::seconds(90), Step::Exact(SignedDuration::from_secs(90)));
+    /// ```
+    #[must_use]
+    pub fn seconds(seconds: u32) -> Step {
+        Step::Exact(SignedDuration::from_secs(i64::from(seconds)))
+    }
+
+    /// Returns the step in days, if it is a step of whole days or weeks.
+    fn days(&self) -> Option<i64> {
+        match self {
+            Step::Days(days) => Some(i64::from(*days)),
+            Step::Weeks(weeks) => Some(i64::from(*weeks) * 7),
+            _ => None,
+        }
+    }
+
+    /// Returns the step in months, if it is a step of whole months or years.
+    fn months(&self) -> Option<i64> {
+        match self {
+            Step::Months(months) => Some(i64::from(*months)),
+            Step::Years(years) => Some(i64::from(*years) * 12),
+            _ => None,
+        }
+    }
+
+    fn is_positive(&self) -> bool {
+        match self {
+            Step::Exact(duration) => duration.is_positive(),
+            Step::Days(n) | Step::Weeks(n) | Step::Months(n) | Step::Years(n) => *n > 0,
+        }
+    }
+}
+
+/// An iterator over the instants from a start to an end `Utc`, spaced by a `Step`.
+///
+/// Created with `Utc::range` or `Utc::range_inclusive`.
+/// Every instant is computed from the start, keeping its UTC offset, calendar steps move the
+/// local date in that offset.
+///
+/// # Examples
+/// ```rust
+/// use horae::{Step, Utc};
+///
+/// let start = Utc::from_ymd_hms(2024, 1, 31, 9, 0, 0);
+/// let end = Utc::from_ymd_hms(2024, 5, 31, 9, 0, 0);
+/// let range = Utc::range(start, end, Step::Months(1)).unwrap();
+/// assert_eq!(range.len(), 4);
+///
+/// let dates: Vec<String> = range.rev().map(|utc| utc.date().to_string()).collect();
+/// assert_eq!(dates, ["2024-04-30", "2024-03-31", "2024-02-29", "2024-01-31"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct UtcRange {
+    start: Utc,
+    step: Step,
+    front: u64,
+    back: u64,
+}
+
+impl UtcRange {
+    pub(crate) fn new(
+        start: Utc,
+        end: Utc,
+        step: Step,
+        inclusive: bool,
+    ) -> Result<UtcRange, HoraeError> {
+        if !step.is_positive() {
+            return Err(HoraeError::InvalidStep);
+        }
+        let mut out = UtcRange {
+            start,
+            step,
+            front: 0,
+            back: 0,
+        };
+        let in_range = |utc: Utc| if inclusive { utc <= end } else { utc < end };
+        out.back = match step.months() {
+            Some(months) => {
+                let (from, to) = (start.date(), end.date());
+                let month_span = (i64::from(to.year()) * 12 + i64::from(to.month()))
+                    - (i64::from(from.year()) * 12 + i64::from(from.month()));
+                let estimate = (month_span / months).max(0) as u64;
+                count(estimate, |i| out.get(i).is_some_and(in_range))
+            }
+            None => {
+                let span = end.unix_timestamp_nanos() - start.unix_timestamp_nanos();
+                let step = out.exact_step().as_nanos();
+                let count = if span < 0 {
+                    0
+                } else if inclusive {
+                    span / step + 1
+                } else {
+                    span / step + i128::from(span % step != 0)
+                };
+                u64::try_from(count).map_err(|_| HoraeError::OutOfRange)?
+            }
+        };
+        Ok(out)
+    }
+
+    fn exact_step(&self) -> SignedDuration {
+        match (self.step, self.step.days()) {
+            (Step::Exact(duration), _) => duration,
+            (_, Some(days)) => SignedDuration::from_secs(days * 86_400),
+            _ => SignedDuration::ZERO,
+        }
+    }
+
+    /// Returns the instant at the supplied index, `None` if it is outside of the supported
+    /// years.
+    fn get(&self, index: u64) -> Option<Utc> {
+        match self.step.months() {
+            Some(months) => {
+                let months = i32::try_from(i64::try_from(index).ok()?.checked_mul(months)?).ok()?;
+                self.start.add_months(months, MonthOverflow::Clamp).ok()
+            }
+            None => Some(
+                self.start
+                    + SignedDuration::from_nanos(i128::from(index) * self.exact_step().as_nanos()),
+            ),
+        }
+    }
+}
+
+impl Iterator for UtcRange {
+    type Item = Utc;
+
+    fn next(&mut self) -> Option<Utc> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.front += 1;
+        self.get(self.front - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Utc> {
+        self.front = self.front.saturating_add(n as u64).min(self.back);
+        self.next()
+    }
+}
+
+impl DoubleEndedIterator for UtcRange {
+    fn next_back(&mut self) -> Option<Utc> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.get(self.back)
+    }
+}
+
+impl ExactSizeIterator for UtcRange {}
+
+impl FusedIterator for UtcRange {}
+
+/// An iterator over the dates from a start to an end `Date`, spaced by a calendar `Step`.
+///
+/// Created with `Date::range` or `Date::range_inclusive`.
+///
+/// # Examples
+/// ```rust
+/// use horae::{Date, Step};
+///
+/// let start = Date::from_ymd(2024, 2, 26).unwrap();
+/// let end = Date::from_ymd(2024, 3, 1).unwrap();
+/// let days: Vec<String> = Date::range_inclusive(start, end, Step::Days(1))
+///     .unwrap()
+///     .map(|date| date.to_string())
+///     .collect();
+/// assert_eq!(days, ["2024-02-26", "2024-02-27", "2024-02-28", "2024-02-29", "2024-03-01"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DateRange {
+    start: Date,
+    step: Step,
+    front: u64,
+    back: u64,
+}
+
+impl DateRange {
+    pub(crate) fn new(
+        start: Date,
+        end: Date,
+        step: Step,
+        inclusive: bool,
+    ) -> Result<DateRange, HoraeError> {
+        if !step.is_positive() || matches!(step, Step::Exact(_)) {
+            return Err(HoraeError::InvalidStep);
+        }
+        let mut out = DateRange {
+            start,
+            step,
+            front: 0,
+            back: 0,
+        };
+        let in_range = |date: Date| if inclusive { date <= end } else { date < end };
+        out.back = match (step.days(), step.months()) {
+            (Some(days), _) => {
+                let span = end - start;
+                if span < 0 {
+                    0
+                } else if inclusive {
+                    (span / days + 1) as u64
+                } else {
+                    ((span + days - 1) / days) as u64
+                }
+            }
+            (_, Some(months)) => {
+                let month_span = (i64::from(end.year()) * 12 + i64::from(end.month()))
+                    - (i64::from(start.year()) * 12 + i64::from(start.month()));
+                let estimate = (month_span / months).max(0) as u64;
+                count(estimate, |i| out.get(i).is_some_and(in_range))
+            }
+            _ => 0,
+        };
+        Ok(out)
+    }
+
+    /// Returns the date at the supplied index, `None` if it is outside of the supported years.
+    fn get(&self, index: u64) -> Option<Date> {
+        let index = i64::try_from(index).ok()?;
+        match (self.step.days(), self.step.months()) {
+            (Some(days), _) => self.start.add_days(index.checked_mul(days)?).ok(),
+            (_, Some(months)) => {
+                let months = i32::try_from(index.checked_mul(months)?).ok()?;
+                self.start.add_months(months, MonthOverflow::Clamp).ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Iterator for DateRange {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Date> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.front += 1;
+        self.get(self.front - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Date> {
+        self.front = self.front.saturating_add(n as u64).min(self.back);
+        self.next()
+    }
+}
+
+impl DoubleEndedIterator for DateRange {
+    fn next_back(&mut self) -> Option<Date> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.get(self.back)
+    }
+}
+
+impl ExactSizeIterator for DateRange {}
+
+impl FusedIterator for DateRange {}
+
+/// Counts the leading indices that are in range, starting the search at `estimate`.
+///
+/// Calendar steps have no fixed length, the estimate from the month span is off by at most one.
+fn count(estimate: u64, in_range: impl Fn(u64) -> bool) -> u64 {
+    let mut len = estimate;
+    while len > 0 && !in_range(len - 1) {
+        len -= 1;
+    }
+    while in_range(len) {
+        len += 1;
+    }
+    len
+}
@@ -0,0 +1,531 @@
+use std::iter::{FusedIterator, Peekable};
+
+use crate::{
+    Utc,
+    date_time::common::{
+        SECONDS_IN_DAY, SECONDS_IN_HOUR, SECONDS_IN_MINUTE, civil_from_days, days_from_civil,
+        days_in_month_of_year, is_this_year_leap_year, week_day,
+    },
+};
+
+use super::{Frequency, NthWeekday, RRule, RecurrenceSet, Until};
+
+/// An iterator over the occurrences of an `RRule`.
+///
+/// Created with `RRule::occurrences`.
+/// Evaluates one period of the rule's frequency at a time, every occurrence keeps the
+/// nanoseconds and UTC offset of the start.
+#[derive(Debug, Clone)]
+pub struct Occurrences {
+    frequency: Frequency,
+    /// Periods between two evaluated periods, in the unit of the period index
+    step: i64,
+    count: Option<u32>,
+    /// The last possible occurrence as local seconds and nanoseconds
+    until: Option<(i64, u32)>,
+    seconds: Vec<u8>,
+    minutes: Vec<u8>,
+    hours: Vec<u8>,
+    week_days: Vec<NthWeekday>,
+    /// Whether `BYDAY` ordinals count within the month or year
+    ordinals: bool,
+    month_days: Vec<i8>,
+    year_days: Vec<i16>,
+    week_nos: Vec<i8>,
+    months: Vec<u8>,
+    set_pos: Vec<i16>,
+    week_start: u8,
+    /// The start as local seconds
+    start: i64,
+    nanos: u32,
+    offset: i32,
+    first_period: i64,
+    period: i64,
+    /// Occurrences of the current period as local seconds, the next one last
+    pending: Vec<i64>,
+    emitted: u32,
+    done: bool,
+}
+
+impl Occurrences {
+    pub(super) fn new(rule: &RRule, start: Utc) -> Occurrences {
+        let local = start.local_seconds();
+        let nanos = start.subsec_nanos();
+        let offset = start.utc_offset_seconds();
+        let start_date = start.date();
+        let start_time = start.time();
+        let start_day = local.div_euclid(SECONDS_IN_DAY);
+
+        let mut never = false;
+        let mut keep = |values: &mut Vec<u8>, max: u8, min: u8| {
+            let was_empty = values.is_empty();
+            values.retain(|value| (min..=max).contains(value));
+            values.sort_unstable();
+            values.dedup();
+            never |= !was_empty && values.is_empty();
+        };
+        let mut seconds = rule.by_second.clone();
+        let mut minutes = rule.by_minute.clone();
+        let mut hours = rule.by_hour.clone();
+        let mut months = rule.by_month.clone();
+        keep(&mut seconds, 59, 0);
+        keep(&mut minutes, 59, 0);
+        keep(&mut hours, 23, 0);
+        keep(&mut months, 12, 1);
+
+        let mut week_days = rule.by_day.clone();
+        let mut month_days = rule.by_month_day.clone();
+        let mut year_days = rule.by_year_day.clone();
+        let mut week_nos = rule.by_week_no.clone();
+        let mut set_pos = rule.by_set_pos.clone();
+        never |= drop_invalid(&mut week_days, |day| day.nth.abs() <= 53);
+        never |= drop_invalid(&mut month_days, |day| *day != 0 && day.abs() <= 31);
+        never |= drop_invalid(&mut year_days, |day| *day != 0 && day.abs() <= 366);
+        never |= drop_invalid(&mut week_nos, |week| *week != 0 && week.abs() <= 53);
+        never |= drop_invalid(&mut set_pos, |pos| *pos != 0 && pos.abs() <= 366);
+
+        let frequency = rule.frequency;
+        // Without any day rule part, the start supplies the day
+        if week_nos.is_empty()
+            && year_days.is_empty()
+            && month_days.is_empty()
+            && week_days.is_empty()
+        {
+            match frequency {
+                Frequency::Yearly => {
+                    if months.is_empty() {
+                        months.push(start_date.month());
+                    }
+                    month_days.push(start_date.day() as i8);
+                }
+                Frequency::Monthly => month_days.push(start_date.day() as i8),
+                Frequency::Weekly => week_days.push(NthWeekday::every(start_date.weekday())),
+                _ => {}
+            }
+        }
+        // Time units coarser than the frequency are supplied by the start as well
+        if hours.is_empty() && frequency < Frequency::Hourly {
+            hours.push(start_time.hour());
+        }
+        if minutes.is_empty() && frequency < Frequency::Minutely {
+            minutes.push(start_time.minute());
+        }
+        if seconds.is_empty() && frequency < Frequency::Secondly {
+            seconds.push(start_time.second());
+        }
+
+        let until = rule.until.map(|until| match until {
+            Until::Instant(utc) => (
+                utc.unix_timestamp_secs() + i64::from(offset),
+                utc.subsec_nanos(),
+            ),
+            Until::Local(date, time) => (
+                date.days_since_epoch() * SECONDS_IN_DAY + i64::from(time.seconds_since_midnight()),
+                time.nanosecond(),
+            ),
+            Until::Date(date) => (
+                date.days_since_epoch() * SECONDS_IN_DAY + SECONDS_IN_DAY - 1,
+                999_999_999,
+            ),
+        });
+
+        let week_start = rule.week_start.number_from_monday();
+        let first_period = match frequency {
+            Frequency::Yearly => i64::from(start_date.year()),
+            Frequency::Monthly => {
+                i64::from(start_date.year()) * 12 + i64::from(start_date.month()) - 1
+            }
+            Frequency::Weekly => start_day - i64::from((week_day(start_day) + 7 - week_start) % 7),
+            Frequency::Daily => start_day,
+            Frequency::Hourly => local.div_euclid(SECONDS_IN_HOUR),
+            Frequency::Minutely => local.div_euclid(SECONDS_IN_MINUTE),
+            Frequency::Secondly => local,
+        };
+        let interval = i64::from(rule.interval.max(1));
+        let step = if frequency == Frequency::Weekly {
+            interval * 7
+        } else {
+            interval
+        };
+
+        Occurrences {
+            frequency,
+            step,
+            count: rule.count,
+            until,
+            seconds,
+            minutes,
+            hours,
+            ordinals: frequency == Frequency::Monthly
+                || (frequency == Frequency::Yearly && week_nos.is_empty()),
+            week_days,
+            month_days,
+            year_days,
+            week_nos,
+            months,
+            set_pos,
+            week_start,
+            start: local,
+            nanos,
+            offset,
+            first_period,
+            period: first_period,
+            pending: Vec::new(),
+            emitted: 0,
+            done: never || rule.count == Some(0),
+        }
+    }
+
+    /// Returns the length of a sub-daily period in seconds.
+    fn period_seconds(&self) -> Option<i64> {
+        match self.frequency {
+            Frequency::Hourly => Some(SECONDS_IN_HOUR),
+            Frequency::Minutely => Some(SECONDS_IN_MINUTE),
+            Frequency::Secondly => Some(1),
+            _ => None,
+        }
+    }
+
+    /// Returns the days of the current period, `None` once it is past the supported years.
+    fn period_days(&self) -> Option<std::ops::Range<i64>> {
+        let last_day = days_from_civil(9999, 12, 31);
+        let days = match self.frequency {
+            Frequency::Yearly => {
+                if self.period > 9999 {
+                    return None;
+                }
+                days_from_civil(self.period, 1, 1)..days_from_civil(self.period + 1, 1, 1)
+            }
+            Frequency::Monthly => {
+                let year = self.period.div_euclid(12);
+                if year > 9999 {
+                    return None;
+                }
+                let month = self.period.rem_euclid(12) as u8 + 1;
+                let first = days_from_civil(year, month, 1);
+                first..first + i64::from(days_in_month_of_year(year as u16, month))
+            }
+            Frequency::Weekly => self.period..self.period + 7,
+            Frequency::Daily => self.period..self.period + 1,
+            _ => {
+                let day =
+                    (self.period * self.period_seconds().unwrap_or(1)).div_euclid(SECONDS_IN_DAY);
+                day..day + 1
+            }
+        };
+        if days.start > last_day {
+            return None;
+        }
+        Some(days.start..days.end.min(last_day + 1))
+    }
+
+    /// Returns the occurrences of the current period in order, as local seconds.
+    ///
+    /// For sub-daily frequencies, returns `Err` with the index of the next period that can
+    /// match, if a coarser rule part rules out the current one.
+    fn evaluate_period(&self, days: std::ops::Range<i64>) -> Result<Vec<i64>, i64> {
+        let matching_days: Vec<i64> = days.filter(|day| self.day_matches(*day)).collect();
+
+        let mut out = Vec::new();
+        match self.period_seconds() {
+            None => {
+                for day in matching_days {
+                    for hour in &self.hours {
+                        for minute in &self.minutes {
+                            for second in &self.seconds {
+                                out.push(
+                                    day * SECONDS_IN_DAY
+                                        + i64::from(*hour) * SECONDS_IN_HOUR
+                                        + i64::from(*minute) * SECONDS_IN_MINUTE
+                                        + i64::from(*second),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+            Some(unit) => {
+                let begin = self.period * unit;
+                let skip_to = |granularity: i64| {
+                    Err((begin.div_euclid(granularity) + 1) * granularity / unit)
+                };
+                if matching_days.is_empty() {
+                    return skip_to(SECONDS_IN_DAY);
+                }
+                let second_of_day = begin.rem_euclid(SECONDS_IN_DAY);
+                let hour = (second_of_day / SECONDS_IN_HOUR) as u8;
+                let minute = (second_of_day % SECONDS_IN_HOUR / SECONDS_IN_MINUTE) as u8;
+                let second = (second_of_day % SECONDS_IN_MINUTE) as u8;
+                if !self.hours.is_empty() && !self.hours.contains(&hour) {
+                    return skip_to(SECONDS_IN_HOUR);
+                }
+                let minutes = if unit <= SECONDS_IN_MINUTE {
+                    if !self.minutes.is_empty() && !self.minutes.contains(&minute) {
+                        return skip_to(SECONDS_IN_MINUTE);
+                    }
+                    vec![minute]
+                } else {
+                    self.minutes.clone()
+                };
+                let seconds = if unit == 1 {
+                    if !self.seconds.is_empty() && !self.seconds.contains(&second) {
+                        return skip_to(1);
+                    }
+                    vec![second]
+                } else {
+                    self.seconds.clone()
+                };
+                let hour_start = begin - begin.rem_euclid(SECONDS_IN_HOUR);
+                for minute in &minutes {
+                    for second in &seconds {
+                        out.push(
+                            hour_start
+                                + i64::from(*minute) * SECONDS_IN_MINUTE
+                                + i64::from(*second),
+                        );
+                    }
+                }
+            }
+        }
+
+        if self.set_pos.is_empty() {
+            return Ok(out);
+        }
+        let len = out.len() as i64;
+        let mut selected: Vec<i64> = self
+            .set_pos
+            .iter()
+            .filter_map(|pos| {
+                let index = if *pos > 0 {
+                    i64::from(*pos) - 1
+                } else {
+                    len + i64::from(*pos)
+                };
+                usize::try_from(index)
+                    .ok()
+                    .and_then(|i| out.get(i))
+                    .copied()
+            })
+            .collect();
+        selected.sort_unstable();
+        selected.dedup();
+        Ok(selected)
+    }
+
+    /// Returns `true` if the day passes every day rule part.
+    fn day_matches(&self, day: i64) -> bool {
+        let (year, month, day_of_month) = civil_from_days(day);
+        if !self.months.is_empty() && !self.months.contains(&month) {
+            return false;
+        }
+        let days_in_month = i64::from(days_in_month_of_year(year as u16, month));
+        if !self.month_days.is_empty()
+            && !self.month_days.iter().any(|wanted| {
+                i64::from(*wanted) == i64::from(day_of_month)
+                    || i64::from(*wanted) == i64::from(day_of_month) - days_in_month - 1
+            })
+        {
+            return false;
+        }
+
+        let first_of_year = days_from_civil(year, 1, 1);
+        let days_in_year: i64 = if is_this_year_leap_year(year as u16) {
+            366
+        } else {
+            365
+        };
+        let ordinal = day - first_of_year + 1;
+        if !self.year_days.is_empty()
+            && !self.year_days.iter().any(|wanted| {
+                i64::from(*wanted) == ordinal || i64::from(*wanted) == ordinal - days_in_year - 1
+            })
+        {
+            return false;
+        }
+
+        if !self.week_nos.is_empty() {
+            let (week, weeks_in_year) = self.week_number(day, year);
+            if !self.week_nos.iter().any(|wanted| {
+                i64::from(*wanted) == week || i64::from(*wanted) == week - weeks_in_year - 1
+            }) {
+                return false;
+            }
+        }
+
+        if !self.week_days.is_empty() {
+            let weekday = week_day(day);
+            // Position of the day among the same week days of its month or year
+            let (from_start, from_end) =
+                if !self.months.is_empty() || self.frequency == Frequency::Monthly {
+                    (
+                        (i64::from(day_of_month) - 1) / 7 + 1,
+                        (days_in_month - i64::from(day_of_month)) / 7 + 1,
+                    )
+                } else {
+                    ((ordinal - 1) / 7 + 1, (days_in_year - ordinal) / 7 + 1)
+                };
+            if !self.week_days.iter().any(|wanted| {
+                wanted.weekday.number_from_monday() == weekday
+                    && (wanted.nth == 0
+                        || !self.ordinals
+                        || i64::from(wanted.nth) == from_start
+                        || i64::from(wanted.nth) == -from_end)
+            }) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Returns the week number of the day and the number of weeks in its week-numbering year.
+    ///
+    /// Week 1 is the first week starting on `week_start` with at least 4 days in the year.
+    fn week_number(&self, day: i64, year: i64) -> (i64, i64) {
+        let week_one = |year: i64| {
+            let first = days_from_civil(year, 1, 1);
+            let into_week = i64::from((week_day(first) + 7 - self.week_start) % 7);
+            if into_week <= 3 {
+                first - into_week
+            } else {
+                first + 7 - into_week
+            }
+        };
+        let mut week_year = year;
+        if day < week_one(year) {
+            week_year -= 1;
+        } else if day >= week_one(year + 1) {
+            week_year += 1;
+        }
+        let start = week_one(week_year);
+        ((day - start) / 7 + 1, (week_one(week_year + 1) - start) / 7)
+    }
+
+    /// Moves to the first period at or after `target` that the interval reaches.
+    fn advance_to(&mut self, target: i64) {
+        let periods = (target - self.first_period + self.step - 1).div_euclid(self.step);
+        self.period = (self.first_period + periods * self.step).max(self.period + self.step);
+    }
+}
+
+impl Iterator for Occurrences {
+    type Item = Utc;
+
+    fn next(&mut self) -> Option<Utc> {
+        while !self.done {
+            if let Some(local) = self.pending.pop() {
+                if self.until.is_some_and(|until| (local, self.nanos) > until) {
+                    self.done = true;
+                    return None;
+                }
+                self.emitted += 1;
+                if self.count.is_some_and(|count| self.emitted >= count) {
+                    self.done = true;
+                }
+                return Some(Utc::from_local_seconds(local, self.nanos, self.offset));
+            }
+
+            let Some(days) = self.period_days() else {
+                self.done = true;
+                return None;
+            };
+            match self.evaluate_period(days) {
+                Ok(occurrences) => {
+                    self.pending = occurrences
+                        .into_iter()
+                        .filter(|local| *local >= self.start)
+                        .rev()
+                        .collect();
+                    self.period += self.step;
+                }
+                Err(next_period) => self.advance_to(next_period),
+            }
+        }
+        None
+    }
+}
+
+impl FusedIterator for Occurrences {}
+
+/// An iterator over the occurrences of a `RecurrenceSet`.
+///
+/// Created with `RecurrenceSet::occurrences`.
+#[derive(Debug, Clone)]
+pub struct SetOccurrences {
+    rules: Vec<Peekable<Occurrences>>,
+    /// The start and the extra dates in order
+    dates: Vec<Utc>,
+    next_date: usize,
+    excluded_dates: Vec<Utc>,
+    last: Option<Utc>,
+}
+
+impl SetOccurrences {
+    pub(super) fn new(set: &RecurrenceSet) -> SetOccurrences {
+        let mut dates = set.dates.clone();
+        dates.push(set.start);
+        dates.sort();
+        let mut excluded_dates = set.excluded_dates.clone();
+        excluded_dates.sort();
+        SetOccurrences {
+            rules: set
+                .rules
+                .iter()
+                .map(|rule| rule.occurrences(set.start).peekable())
+                .collect(),
+            dates,
+            next_date: 0,
+            excluded_dates,
+            last: None,
+        }
+    }
+}
+
+impl Iterator for SetOccurrences {
+    type Item = Utc;
+
+    fn next(&mut self) -> Option<Utc> {
+        loop {
+            let date = self.dates.get(self.next_date).copied();
+            let (rule, earliest) = self
+                .rules
+                .iter_mut()
+                .enumerate()
+                .filter_map(|(i, rule)| rule.peek().map(|utc| (i, *utc)))
+                .min_by_key(|(_, utc)| *utc)
+                .map_or((None, None), |(i, utc)| (Some(i), Some(utc)));
+
+            let next = match (date, earliest, rule) {
+                (Some(date), Some(earliest), Some(i)) if earliest < date => {
+                    self.rules[i].next();
+                    earliest
+                }
+                (Some(date), _, _) => {
+                    self.next_date += 1;
+                    date
+                }
+                (None, Some(earliest), Some(i)) => {
+                    self.rules[i].next();
+                    earliest
+                }
+                _ => return None,
+            };
+
+            if self.last == Some(next) {
+                continue;
+            }
+            self.last = Some(next);
+            if self.excluded_dates.binary_search(&next).is_err() {
+                return Some(next);
+            }
+        }
+    }
+}
+
+impl FusedIterator for SetOccurrences {}
+
+/// Drops the values not passing `valid`, returns `true` if that emptied the list.
+fn drop_invalid<T>(values: &mut Vec<T>, valid: impl Fn(&T) -> bool) -> bool {
+    let was_empty = values.is_empty();
+    values.retain(valid);
+    !was_empty && values.is_empty()
+}
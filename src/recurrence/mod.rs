@@ -0,0 +1,366 @@
+use crate::{Date, HoraeError, ParseError, Time, Utc, Weekday};
+
+mod iter;
+mod parser;
+
+pub use iter::{Occurrences, SetOccurrences};
+
+const WEEKDAY_CODES: [&str; 7] = ["MO", "TU", "WE", "TH", "FR", "SA", "SU"];
+
+/// How often a recurrence rule repeats, the `FREQ` rule part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Frequency {
+    Yearly,
+    Monthly,
+    Weekly,
+    Daily,
+    Hourly,
+    Minutely,
+    Secondly,
+}
+
+impl Frequency {
+    fn name(&self) -> &'static str {
+        match self {
+            Frequency::Yearly => "YEARLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Daily => "DAILY",
+            Frequency::Hourly => "HOURLY",
+            Frequency::Minutely => "MINUTELY",
+            Frequency::Secondly => "SECONDLY",
+        }
+    }
+}
+
+/// A `BYDAY` entry, a week day with an optional ordinal.
+///
+/// An ordinal of 0 means every such week day in the period, `1` the first, `-1` the last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NthWeekday {
+    pub nth: i16,
+    pub weekday: Weekday,
+}
+
+impl NthWeekday {
+    /// Returns an entry matching every such week day in the period.
+    #[must_use]
+    pub fn every(weekday: Weekday) -> NthWeekday {
+        NthWeekday { nth: 0, weekday }
+    }
+
+    /// Returns an entry matching the nth such week day in the period, counted from the end if
+    /// negative.
+    #[must_use]
+    pub fn nth(nth: i16, weekday: Weekday) -> NthWeekday {
+        NthWeekday { nth, weekday }
+    }
+}
+
+/// The `UNTIL` rule part, the last possible occurrence of a recurrence rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Until {
+    /// An exact instant, written in UTC with a trailing `Z`.
+    Instant(Utc),
+    /// A local date and time in the UTC offset of the start of the recurrence.
+    Local(Date, Time),
+    /// A local date in the UTC offset of the start of the recurrence, including the whole day.
+    Date(Date),
+}
+
+/// An RFC 5545 recurrence rule, like `FREQ=MONTHLY;BYDAY=-1FR`.
+///
+/// Parse with `RRule::parse`, or build with `RRule::new` and set the fields.
+/// Evaluate against a start with `RRule::occurrences`, which returns an iterator of `Utc`.
+/// All rule parts are evaluated on the local date and time in the UTC offset of the start.
+///
+/// As RFC 5545 requires, dates that do not exist are skipped rather than clamped, so a rule
+/// for February 29 only repeats in leap years.
+///
+/// # Examples
+/// ```rust
+/// use horae::{RRule, Utc};
+///
+/// let rule = RRule::parse("FREQ=MONTHLY;INTERVAL=2;BYDAY=TU;BYSETPOS=2").unwrap();
+/// let start = Utc::from_ymd_hms(2024, 1, 1, 9, 0, 0);
+/// let dates: Vec<String> = rule
+///     .occurrences(start)
+///     .take(3)
+///     .map(|utc| utc.date().to_string())
+///     .collect();
+/// assert_eq!(dates, ["2024-01-09", "2024-03-12", "2024-05-14"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RRule {
+    pub frequency: Frequency,
+    /// Repeat every `interval` periods, 0 is read as 1.
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<Until>,
+    pub by_second: Vec<u8>,
+    pub by_minute: Vec<u8>,
+    pub by_hour: Vec<u8>,
+    pub by_day: Vec<NthWeekday>,
+    pub by_month_day: Vec<i8>,
+    pub by_year_day: Vec<i16>,
+    pub by_week_no: Vec<i8>,
+    pub by_month: Vec<u8>,
+    pub by_set_pos: Vec<i16>,
+    /// The day weeks start on.
+    pub week_start: Weekday,
+}
+
+impl RRule {
+    /// Instantiates a new `RRule` of the supplied frequency, repeating forever with an interval
+    /// of 1 and weeks starting on Monday.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Frequency, NthWeekday, RRule, Weekday};
+    ///
+    /// let mut rule = RRule::new(Frequency::Weekly);
+    /// rule.by_day = vec![NthWeekday::every(Weekday::Monday), NthWeekday::every(Weekday::Wednesday)];
+    /// rule.count = Some(4);
+    /// assert_eq!(rule.to_string(), "FREQ=WEEKLY;COUNT=4;BYDAY=MO,WE");
+    /// ```
+    #[must_use]
+    pub fn new(frequency: Frequency) -> RRule {
+        RRule {
+            frequency,
+            interval: 1,
+            count: None,
+            until: None,
+            by_second: Vec::new(),
+            by_minute: Vec::new(),
+            by_hour: Vec::new(),
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_year_day: Vec::new(),
+            by_week_no: Vec::new(),
+            by_month: Vec::new(),
+            by_set_pos: Vec::new(),
+            week_start: Weekday::Monday,
+        }
+    }
+
+    /// Parses an RFC 5545 recurrence rule, with or without a leading `RRULE:`.
+    ///
+    /// Rule part names and values are case-insensitive. Rule parts RFC 5545 does not allow
+    /// together, like `COUNT` and `UNTIL`, or `BYWEEKNO` on anything but a yearly rule, are an
+    /// error.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` naming the component that could not be read, and where.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use horae::{Frequency, ParseComponent, RRule};
+    ///
+    /// let rule = RRule::parse("RRULE:FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=29").unwrap();
+    /// assert_eq!(rule.frequency, Frequency::Yearly);
+    /// assert_eq!(rule.by_month_day, [29]);
+    ///
+    /// let error = RRule::parse("FREQ=DAILY;BYHOUR=24").unwrap_err();
+    /// assert_eq!(error.component(), ParseComponent::RulePart);
+    /// assert_eq!(error.position(), 18);
+    /// ```
+    pub fn parse(s: &str) -> Result<RRule, ParseError> {
+        parser::parse_rrule(s).map_err(ParseError::from)
+    }
+
+    /// Returns an iterator over the occurrences of the rule starting at `start`, in its UTC
+    /// offset.
+    ///
+    /// `start` supplies every time and date component the rule does not specify, and is only
+    /// an occurrence itself if the rule matches it. The iterator ends after `count`
+    /// occurrences, after `until`, or at the end of the supported years.
+    #[must_use]
+    pub fn occurrences(&self, start: Utc) -> Occurrences {
+        Occurrences::new(self, start)
+    }
+}
+
+impl std::str::FromStr for RRule {
+    type Err = HoraeError;
+
+    fn from_str(s: &str) -> Result<RRule, HoraeError> {
+        parser::parse_rrule(s).map_err(HoraeError::from)
+    }
+}
+
+// Display implementation
+
+impl std::fmt::Display for RRule {
+    /// Formats the rule in RFC 5545 syntax, with the rule parts in a fixed order and default
+    /// values left out.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "FREQ={}", self.frequency.name())?;
+        match self.until {
+            Some(Until::Instant(utc)) => {
+                let mut utc = utc;
+                utc.with_utc_offset(0.0);
+                write!(f, ";UNTIL=")?;
+                write_date_time(f, utc.date(), Some(utc.time()))?;
+                write!(f, "Z")?;
+            }
+            Some(Until::Local(date, time)) => {
+                write!(f, ";UNTIL=")?;
+                write_date_time(f, date, Some(time))?;
+            }
+            Some(Until::Date(date)) => {
+                write!(f, ";UNTIL=")?;
+                write_date_time(f, date, None)?;
+            }
+            None => {}
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={count}")?;
+        }
+        if self.interval > 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        write_list(f, "BYSECOND", &self.by_second)?;
+        write_list(f, "BYMINUTE", &self.by_minute)?;
+        write_list(f, "BYHOUR", &self.by_hour)?;
+        if !self.by_day.is_empty() {
+            write!(f, ";BYDAY=")?;
+            for (i, day) in self.by_day.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ",")?;
+                }
+                if day.nth != 0 {
+                    write!(f, "{}", day.nth)?;
+                }
+                write!(f, "{}", weekday_code(day.weekday))?;
+            }
+        }
+        write_list(f, "BYMONTHDAY", &self.by_month_day)?;
+        write_list(f, "BYYEARDAY", &self.by_year_day)?;
+        write_list(f, "BYWEEKNO", &self.by_week_no)?;
+        write_list(f, "BYMONTH", &self.by_month)?;
+        write_list(f, "BYSETPOS", &self.by_set_pos)?;
+        if self.week_start != Weekday::Monday {
+            write!(f, ";WKST={}", weekday_code(self.week_start))?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes an iCalendar `YYYYMMDD` date or `YYYYMMDDTHHMMSS` date-time.
+fn write_date_time(
+    f: &mut std::fmt::Formatter,
+    date: Date,
+    time: Option<Time>,
+) -> std::fmt::Result {
+    write!(f, "{:04}{:02}{:02}", date.year(), date.month(), date.day())?;
+    if let Some(time) = time {
+        write!(
+            f,
+            "T{:02}{:02}{:02}",
+            time.hour(),
+            time.minute(),
+            time.second()
+        )?;
+    }
+    Ok(())
+}
+
+fn write_list<T: std::fmt::Display>(
+    f: &mut std::fmt::Formatter,
+    name: &str,
+    values: &[T],
+) -> std::fmt::Result {
+    if values.is_empty() {
+        return Ok(());
+    }
+    write!(f, ";{name}=")?;
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            write!(f, ",")?;
+        }
+        write!(f, "{value}")?;
+    }
+    Ok(())
+}
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    WEEKDAY_CODES[usize::from(weekday.number_from_monday() - 1)]
+}
+
+/// A complete RFC 5545 recurrence set, a start with any number of rules, extra dates and
+/// excluded dates.
+///
+/// The occurrences are the start, every occurrence of every rule and every extra date, in
+/// order. Instants produced more than once are only returned once, and excluded instants are
+/// never returned.
+///
+/// # Examples
+/// ```rust
+/// use horae::RecurrenceSet;
+///
+/// let set = RecurrenceSet::parse(
+///     "DTSTART:20240101T090000Z\n\
+///      RRULE:FREQ=DAILY;COUNT=5\n\
+///      EXDATE:20240103T090000Z\n\
+///      RDATE:20240110T120000Z",
+/// )
+/// .unwrap();
+/// let dates: Vec<String> = set.occurrences().map(|utc| utc.to_string()).collect();
+/// assert_eq!(
+///     dates,
+///     [
+///         "2024-01-01 09:00:00.000",
+///         "2024-01-02 09:00:00.000",
+///         "2024-01-04 09:00:00.000",
+///         "2024-01-05 09:00:00.000",
+///         "2024-01-10 12:00:00.000",
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceSet {
+    pub start: Utc,
+    pub rules: Vec<RRule>,
+    pub dates: Vec<Utc>,
+    pub excluded_dates: Vec<Utc>,
+}
+
+impl RecurrenceSet {
+    /// Instantiates a new `RecurrenceSet` with only the start as an occurrence.
+    #[must_use]
+    pub fn new(start: Utc) -> RecurrenceSet {
+        RecurrenceSet {
+            start,
+            rules: Vec::new(),
+            dates: Vec::new(),
+            excluded_dates: Vec::new(),
+        }
+    }
+
+    /// Parses the `DTSTART`, `RRULE`, `RDATE` and `EXDATE` content lines of an iCalendar
+    /// component, one per line.
+    ///
+    /// Date-times ending in `Z` are UTC, date-times without it and dates, marked with
+    /// `VALUE=DATE`, are read as UTC as well. `TZID` parameters are not supported.
+    /// `DTSTART` is required exactly once.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` naming the component that could not be read, and where.
+    pub fn parse(s: &str) -> Result<RecurrenceSet, ParseError> {
+        parser::parse_recurrence_set(s).map_err(ParseError::from)
+    }
+
+    /// Returns an iterator over all occurrences of the set, in order.
+    #[must_use]
+    pub fn occurrences(&self) -> SetOccurrences {
+        SetOccurrences::new(self)
+    }
+}
+
+impl std::str::FromStr for RecurrenceSet {
+    type Err = HoraeError;
+
+    fn from_str(s: &str) -> Result<RecurrenceSet, HoraeError> {
+        parser::parse_recurrence_set(s).map_err(HoraeError::from)
+    }
+}
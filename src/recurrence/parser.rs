@@ -0,0 +1,447 @@
+use crate::{
+    Date, HoraeError, ParseComponent, Time, Utc, Weekday,
+    date_time::{
+        common::{validate_date, validate_time},
+        parser::{Cursor, ParseFailure},
+    },
+};
+
+use super::{Frequency, NthWeekday, RRule, RecurrenceSet, Until, WEEKDAY_CODES};
+
+/// Parses an RFC 5545 recurrence rule, with or without a leading `RRULE:`.
+pub fn parse_rrule(input: &str) -> Result<RRule, ParseFailure> {
+    let start = if input
+        .as_bytes()
+        .get(..6)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(b"RRULE:"))
+    {
+        6
+    } else {
+        0
+    };
+    parse_rule_parts(input, start, input.len())
+}
+
+/// Parses the `;` separated rule parts of `input` from `start` up to `end`.
+fn parse_rule_parts(input: &str, start: usize, end: usize) -> Result<RRule, ParseFailure> {
+    use ParseComponent as C;
+
+    let cursor = Cursor::new(input);
+    let bytes = input.as_bytes();
+    let mut frequency = None;
+    let mut rule = RRule::new(Frequency::Yearly);
+    // Names and positions of all read rule parts
+    let mut parts: Vec<(String, usize)> = Vec::new();
+
+    let mut pos = start;
+    loop {
+        let part_end = bytes[pos..end]
+            .iter()
+            .position(|b| *b == b';')
+            .map_or(end, |i| pos + i);
+        let eq = bytes[pos..part_end]
+            .iter()
+            .position(|b| *b == b'=')
+            .map(|i| pos + i)
+            .ok_or_else(|| cursor.error(C::RulePart, pos, "a NAME=VALUE rule part"))?;
+        let name = input[pos..eq].to_ascii_uppercase();
+        if parts.iter().any(|(seen, _)| *seen == name) {
+            return Err(cursor.error(C::RulePart, pos, "every rule part at most once"));
+        }
+        let value = List {
+            input,
+            start: eq + 1,
+            end: part_end,
+        };
+
+        match name.as_str() {
+            "FREQ" => {
+                let frequency_name = input[value.start..value.end].to_ascii_uppercase();
+                frequency = Some(match frequency_name.as_str() {
+                    "YEARLY" => Frequency::Yearly,
+                    "MONTHLY" => Frequency::Monthly,
+                    "WEEKLY" => Frequency::Weekly,
+                    "DAILY" => Frequency::Daily,
+                    "HOURLY" => Frequency::Hourly,
+                    "MINUTELY" => Frequency::Minutely,
+                    "SECONDLY" => Frequency::Secondly,
+                    _ => {
+                        return Err(cursor.error(
+                            C::RulePart,
+                            value.start,
+                            "a frequency of YEARLY, MONTHLY, WEEKLY, DAILY, HOURLY, MINUTELY or SECONDLY",
+                        ));
+                    }
+                });
+            }
+            "INTERVAL" => {
+                rule.interval =
+                    value.single(1, i64::from(u32::MAX), "an interval of at least 1")? as u32
+            }
+            "COUNT" => rule.count = Some(value.single(0, i64::from(u32::MAX), "a count")? as u32),
+            "UNTIL" => {
+                let (date, time, is_utc) = parse_date_time(input, value.start, value.end)?;
+                rule.until = Some(match (time, is_utc) {
+                    (None, _) => Until::Date(date),
+                    (Some(time), true) => Until::Instant(date.at_time(time)),
+                    (Some(time), false) => Until::Local(date, time),
+                });
+            }
+            "BYSECOND" => rule.by_second = value.unsigned(59, "seconds of 0-59")?,
+            "BYMINUTE" => rule.by_minute = value.unsigned(59, "minutes of 0-59")?,
+            "BYHOUR" => rule.by_hour = value.unsigned(23, "hours of 0-23")?,
+            "BYDAY" => rule.by_day = value.weekdays()?,
+            "BYMONTHDAY" => {
+                rule.by_month_day = value
+                    .signed(31, "month days of 1-31 or -31 to -1")?
+                    .into_iter()
+                    .map(|day| day as i8)
+                    .collect();
+            }
+            "BYYEARDAY" => {
+                rule.by_year_day = value
+                    .signed(366, "year days of 1-366 or -366 to -1")?
+                    .into_iter()
+                    .map(|day| day as i16)
+                    .collect();
+            }
+            "BYWEEKNO" => {
+                rule.by_week_no = value
+                    .signed(53, "week numbers of 1-53 or -53 to -1")?
+                    .into_iter()
+                    .map(|week| week as i8)
+                    .collect();
+            }
+            "BYMONTH" => {
+                rule.by_month = value.unsigned(12, "months of 1-12")?;
+                if rule.by_month.contains(&0) {
+                    return Err(cursor.error(C::RulePart, value.start, "months of 1-12"));
+                }
+            }
+            "BYSETPOS" => {
+                rule.by_set_pos = value
+                    .signed(366, "positions of 1-366 or -366 to -1")?
+                    .into_iter()
+                    .map(|position| position as i16)
+                    .collect();
+            }
+            "WKST" => {
+                rule.week_start = weekday(&input[value.start..value.end])
+                    .ok_or_else(|| cursor.error(C::RulePart, value.start, "a week day like MO"))?;
+            }
+            _ => return Err(cursor.error(C::RulePart, pos, "a known rule part")),
+        }
+        parts.push((name, pos));
+
+        if part_end == end {
+            break;
+        }
+        pos = part_end + 1;
+    }
+
+    rule.frequency =
+        frequency.ok_or_else(|| cursor.error(C::RulePart, start, "a FREQ rule part"))?;
+
+    // Combinations RFC 5545 does not allow
+    let part_pos = |name: &str| {
+        parts
+            .iter()
+            .find(|(seen, _)| seen == name)
+            .map(|(_, pos)| *pos)
+    };
+    let frequency = rule.frequency;
+    let ordinals_allowed = frequency == Frequency::Monthly
+        || (frequency == Frequency::Yearly && rule.by_week_no.is_empty());
+    let other_by_part = parts
+        .iter()
+        .any(|(name, _)| name.starts_with("BY") && name != "BYSETPOS");
+    let checks = [
+        (
+            rule.count.is_some(),
+            "UNTIL",
+            "either COUNT or UNTIL, not both",
+        ),
+        (
+            frequency != Frequency::Yearly,
+            "BYWEEKNO",
+            "BYWEEKNO only on a yearly rule",
+        ),
+        (
+            matches!(
+                frequency,
+                Frequency::Monthly | Frequency::Weekly | Frequency::Daily
+            ),
+            "BYYEARDAY",
+            "BYYEARDAY only on a yearly, hourly, minutely or secondly rule",
+        ),
+        (
+            frequency == Frequency::Weekly,
+            "BYMONTHDAY",
+            "BYMONTHDAY not on a weekly rule",
+        ),
+        (
+            !ordinals_allowed && rule.by_day.iter().any(|day| day.nth != 0),
+            "BYDAY",
+            "BYDAY ordinals only on a monthly rule, or a yearly rule without BYWEEKNO",
+        ),
+        (
+            !other_by_part,
+            "BYSETPOS",
+            "BYSETPOS together with another BYxxx rule part",
+        ),
+    ];
+    for (broken, name, expected) in checks {
+        if let (true, Some(pos)) = (broken, part_pos(name)) {
+            return Err(cursor.error(C::RulePart, pos, expected));
+        }
+    }
+
+    Ok(rule)
+}
+
+/// The `,` separated values of a rule part.
+struct List<'a> {
+    input: &'a str,
+    start: usize,
+    end: usize,
+}
+
+impl List<'_> {
+    /// Returns every value with its position.
+    fn values(&self) -> impl Iterator<Item = (usize, &str)> {
+        let mut pos = self.start;
+        self.input[self.start..self.end]
+            .split(',')
+            .map(move |value| {
+                let out = (pos, value);
+                pos += value.len() + 1;
+                out
+            })
+    }
+
+    fn error(&self, position: usize, expected: &'static str) -> ParseFailure {
+        Cursor::new(self.input).error(ParseComponent::RulePart, position, expected)
+    }
+
+    fn single(&self, min: i64, max: i64, expected: &'static str) -> Result<i64, ParseFailure> {
+        let value = &self.input[self.start..self.end];
+        number(value, false)
+            .filter(|n| (min..=max).contains(n))
+            .ok_or_else(|| self.error(self.start, expected))
+    }
+
+    /// Reads a list of numbers of 0 up to `max`.
+    fn unsigned(&self, max: u8, expected: &'static str) -> Result<Vec<u8>, ParseFailure> {
+        self.values()
+            .map(|(pos, value)| {
+                number(value, false)
+                    .filter(|n| (0..=i64::from(max)).contains(n))
+                    .map(|n| n as u8)
+                    .ok_or_else(|| self.error(pos, expected))
+            })
+            .collect()
+    }
+
+    /// Reads a list of numbers of 1 up to `max`, or -`max` up to -1.
+    fn signed(&self, max: i64, expected: &'static str) -> Result<Vec<i64>, ParseFailure> {
+        self.values()
+            .map(|(pos, value)| {
+                number(value, true)
+                    .filter(|n| *n != 0 && n.abs() <= max)
+                    .ok_or_else(|| self.error(pos, expected))
+            })
+            .collect()
+    }
+
+    fn weekdays(&self) -> Result<Vec<NthWeekday>, ParseFailure> {
+        self.values()
+            .map(|(pos, value)| {
+                let split = value.len().saturating_sub(2);
+                let (nth, code) = (value.get(..split), value.get(split..));
+                let weekday = code.and_then(weekday);
+                let nth = match nth {
+                    Some("") => Some(0),
+                    Some(nth) => number(nth, true).filter(|n| *n != 0 && n.abs() <= 53),
+                    None => None,
+                };
+                match (nth, weekday) {
+                    (Some(nth), Some(weekday)) => Ok(NthWeekday {
+                        nth: nth as i16,
+                        weekday,
+                    }),
+                    _ => Err(self.error(pos, "week days like MO, 1MO or -1FR")),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Reads an optionally signed decimal number.
+fn number(value: &str, allow_sign: bool) -> Option<i64> {
+    let (negative, digits) = match value.as_bytes().first() {
+        Some(b'+') if allow_sign => (false, &value[1..]),
+        Some(b'-') if allow_sign => (true, &value[1..]),
+        _ => (false, value),
+    };
+    if digits.is_empty() || digits.len() > 10 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let n: i64 = digits.parse().ok()?;
+    Some(if negative { -n } else { n })
+}
+
+/// Reads a two letter week day code.
+fn weekday(code: &str) -> Option<Weekday> {
+    WEEKDAY_CODES
+        .iter()
+        .position(|known| known.eq_ignore_ascii_case(code))
+        .map(|i| Weekday::ALL[i])
+}
+
+/// Parses an iCalendar `YYYYMMDD` date or `YYYYMMDDTHHMMSS` date-time with an optional
+/// trailing `Z`, from `start` up to `end`.
+///
+/// Returns the date, the time if there is one, and whether it is in UTC.
+fn parse_date_time(
+    input: &str,
+    start: usize,
+    end: usize,
+) -> Result<(Date, Option<Time>, bool), ParseFailure> {
+    use ParseComponent as C;
+
+    let mut cursor = Cursor::with_end(input, end);
+    cursor.pos = start;
+    let year = cursor.digits(4, C::Year, "a four digit year")? as u16;
+    let month_pos = cursor.pos;
+    let month = cursor.digits(2, C::Month, "a two digit month")? as u8;
+    let day_pos = cursor.pos;
+    let day = cursor.digits(2, C::Day, "a two digit day")? as u8;
+    if let Err(e) = validate_date(year, month, day) {
+        let (component, position, expected) = match e {
+            HoraeError::InvalidYear(_) => (C::Year, start, "a year of 0001-9999"),
+            HoraeError::InvalidMonth(_) => (C::Month, month_pos, "a month of 01-12"),
+            _ => (C::Day, day_pos, "a day that exists in the month"),
+        };
+        return Err(cursor.range_error(component, position, expected, e));
+    }
+    let date = Date::from_ymd_unchecked(year, month, day);
+
+    if cursor.is_done() {
+        return Ok((date, None, false));
+    }
+    cursor.expect(b"Tt", C::DateTimeSeparator, "'T' or the end of the value")?;
+    let hour_pos = cursor.pos;
+    let hour = cursor.digits(2, C::Hour, "a two digit hour")? as u8;
+    let minute_pos = cursor.pos;
+    let minute = cursor.digits(2, C::Minute, "a two digit minute")? as u8;
+    let second_pos = cursor.pos;
+    let second = cursor.digits(2, C::Second, "a two digit second")? as u8;
+    if let Err(e) = validate_time(hour, minute, second) {
+        let (component, position, expected) = match e {
+            HoraeError::InvalidHour(_) => (C::Hour, hour_pos, "an hour of 00-23"),
+            HoraeError::InvalidMinute(_) => (C::Minute, minute_pos, "a minute of 00-59"),
+            _ => (C::Second, second_pos, "a second of 00-59"),
+        };
+        return Err(cursor.range_error(component, position, expected, e));
+    }
+    let is_utc = cursor.eat(b"Zz").is_some();
+    if !cursor.is_done() {
+        return Err(cursor.error(C::Offset, cursor.pos, "'Z' or the end of the value"));
+    }
+    Ok((
+        date,
+        Some(Time::from_hms_nano_unchecked(hour, minute, second, 0)),
+        is_utc,
+    ))
+}
+
+/// Parses a date or date-time value of a recurrence set into a `Utc`.
+fn parse_value(input: &str, start: usize, end: usize) -> Result<Utc, ParseFailure> {
+    let (date, time, _) = parse_date_time(input, start, end)?;
+    Ok(date.at_time(time.unwrap_or(Time::MIDNIGHT)))
+}
+
+/// Parses the `DTSTART`, `RRULE`, `RDATE` and `EXDATE` content lines of `input`.
+pub fn parse_recurrence_set(input: &str) -> Result<RecurrenceSet, ParseFailure> {
+    use ParseComponent as C;
+
+    let cursor = Cursor::new(input);
+    let mut start = None;
+    let mut rules = Vec::new();
+    let mut dates = Vec::new();
+    let mut excluded_dates = Vec::new();
+
+    let mut line_start = 0;
+    for raw_line in input.split('\n') {
+        let line_end = line_start + raw_line.trim_end_matches('\r').len();
+        let line = &input[line_start..line_end];
+        let this_line_start = line_start;
+        line_start += raw_line.len() + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let colon = line.find(':').map(|i| this_line_start + i).ok_or_else(|| {
+            cursor.error(C::Property, this_line_start, "a NAME:VALUE content line")
+        })?;
+        let mut head = input[this_line_start..colon].split(';');
+        let name = head.next().unwrap_or_default().to_ascii_uppercase();
+        let mut param_pos = this_line_start + name.len() + 1;
+        for param in head {
+            let upper = param.to_ascii_uppercase();
+            if upper != "VALUE=DATE" && upper != "VALUE=DATE-TIME" {
+                return Err(cursor.error(
+                    C::Property,
+                    param_pos,
+                    "no parameter but VALUE=DATE or VALUE=DATE-TIME",
+                ));
+            }
+            param_pos += param.len() + 1;
+        }
+
+        let value_start = colon + 1;
+        let values = || {
+            let mut pos = value_start;
+            input[value_start..line_end].split(',').map(move |value| {
+                let out = (pos, pos + value.len());
+                pos += value.len() + 1;
+                out
+            })
+        };
+        match name.as_str() {
+            "DTSTART" => {
+                if start.is_some() {
+                    return Err(cursor.error(C::Property, this_line_start, "a single DTSTART"));
+                }
+                start = Some(parse_value(input, value_start, line_end)?);
+            }
+            "RRULE" => rules.push(parse_rule_parts(input, value_start, line_end)?),
+            "RDATE" => {
+                for (start, end) in values() {
+                    dates.push(parse_value(input, start, end)?);
+                }
+            }
+            "EXDATE" => {
+                for (start, end) in values() {
+                    excluded_dates.push(parse_value(input, start, end)?);
+                }
+            }
+            _ => {
+                return Err(cursor.error(
+                    C::Property,
+                    this_line_start,
+                    "DTSTART, RRULE, RDATE or EXDATE",
+                ));
+            }
+        }
+    }
+
+    let start =
+        start.ok_or_else(|| cursor.error(C::Property, input.len(), "a DTSTART content line"))?;
+    Ok(RecurrenceSet {
+        start,
+        rules,
+        dates,
+        excluded_dates,
+    })
+}
@@ -715,10 +715,10 @@ fn parse_tzif(file: &mut File) -> Option<f64> {
 
         // Read 64-bit transitions
         let mut transition_times = vec![0i64; tzh_timecnt];
-        for i in 0..tzh_timecnt {
+        for transition_time in &mut transition_times {
             let mut buf = [0u8; 8];
             file.read_exact(&mut buf).ok()?;
-            transition_times[i] = i64::from_be_bytes(buf);
+            *transition_time = i64::from_be_bytes(buf);
         }
 
         let mut type_indices = vec![0u8; tzh_timecnt];
@@ -747,10 +747,10 @@ fn parse_tzif(file: &mut File) -> Option<f64> {
             .as_secs() as i32;
 
         let mut transition_times = vec![0i32; tzh_timecnt];
-        for i in 0..tzh_timecnt {
+        for transition_time in &mut transition_times {
             let mut buf = [0u8; 4];
             file.read_exact(&mut buf).ok()?;
-            transition_times[i] = i32::from_be_bytes(buf);
+            *transition_time = i32::from_be_bytes(buf);
         }
 
         let mut type_indices = vec![0u8; tzh_timecnt];
@@ -0,0 +1,76 @@
+use crate::{HoraeError, ParseComponent, ParseError};
+
+use super::{Token, lex};
+
+/// A format string compiled once into its directives, to format many values without reading the
+/// format string again.
+///
+/// Directives are matched by their longest name, so `%ddd` is the day of the year and never
+/// `%dd` followed by a `d`. `%%` writes a literal `%`.
+///
+/// # Examples
+/// ```rust
+/// use horae::{Date, Format, ParseComponent, Utc};
+///
+/// let format = Format::new("%dd days, 100%% of %yyyy").unwrap();
+/// let utc = Utc::from_ymd_hms(2019, 1, 15, 9, 9, 9);
+/// assert_eq!(utc.format_with(&format), "15 days, 100% of 2019");
+/// assert_eq!(Date::parse_from_format("15 days, 100% of 2019", format.as_str()), Ok(utc.date()));
+///
+/// let error = Format::new("%yyyy-%k").unwrap_err();
+/// assert_eq!(error.component(), ParseComponent::Directive);
+/// assert_eq!(error.position(), 6);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Format {
+    format: String,
+    tokens: Vec<Token>,
+}
+
+impl Format {
+    /// Compiles a format string of the directives listed in the README.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` of the format string pointing at the first unknown directive.
+    pub fn new(format: &str) -> Result<Format, ParseError> {
+        let (tokens, unknown) = lex(format);
+        if let Some(position) = unknown {
+            return Err(ParseError::new(
+                format,
+                ParseComponent::Directive,
+                position,
+                "a known directive like %dd, or %% for a literal %",
+            ));
+        }
+        Ok(Format {
+            format: format.to_string(),
+            tokens,
+        })
+    }
+
+    /// Returns the format string the `Format` was compiled from.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.format
+    }
+
+    pub(crate) fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+}
+
+impl std::fmt::Display for Format {
+    /// Writes the format string the `Format` was compiled from.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.format)
+    }
+}
+
+impl std::str::FromStr for Format {
+    type Err = HoraeError;
+
+    /// Compiles a format string, see `Format::new`.
+    fn from_str(s: &str) -> Result<Format, HoraeError> {
+        Format::new(s).map_err(HoraeError::from)
+    }
+}
@@ -1,158 +1,115 @@
+mod format;
+mod parser;
 mod tokens;
 
+pub use format::Format;
+pub use parser::{DEFAULT_PIVOT_YEAR, parse_with_format};
+
 pub use tokens::Separator;
 pub use tokens::Token;
 pub use tokens::Unit;
 
-/// Tokensize the format argument from `.format()`
-pub fn tokenize<S: Into<String>>(format: S) -> Vec<Token> {
-    let tmp_into_bind: String = format.into();
-    let sepatated = tmp_into_bind.split('%');
-    let mut generated_tokens = Vec::new();
-    for token in sepatated {
-        if token.contains("MS") {
-            let rest = token.replace("MS", "");
-            generated_tokens.push(Token::Unit(Unit::Millisecond));
-            generated_tokens.push(Token::Separator(Separator {
-                separator_symbol: rest,
-            }));
-        } else if token.contains("tz") {
-            let rest = token.replace("tz", "");
-            generated_tokens.push(Token::Unit(Unit::Timezone));
-            generated_tokens.push(Token::Separator(Separator {
-                separator_symbol: rest,
-            }));
-        } else if token.contains('m') {
-            if token.contains("mmmm") {
-                let rest = token.replace('m', "");
-                generated_tokens.push(Token::Unit(Unit::WordMonth));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
-            } else if token.contains("mmm") {
-                let rest = token.replace('m', "");
-                generated_tokens.push(Token::Unit(Unit::ShortWordMonth));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
-            } else if token.contains("mm") {
-                let rest = token.replace('m', "");
-                generated_tokens.push(Token::Unit(Unit::NumMonth));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
-            } else {
-                let rest = token.replace('m', "");
-                generated_tokens.push(Token::Unit(Unit::ShortNumMonth));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
-            }
-        } else if token.contains('M') {
-            if token.contains("MM") {
-                let rest = token.replace('M', "");
-                generated_tokens.push(Token::Unit(Unit::Minute));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
-            } else {
-                let rest = token.replace('M', "");
-                generated_tokens.push(Token::Unit(Unit::ShortMinute));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
-            }
-        } else if token.contains("wd") {
-            if token.contains("wdd") {
-                let rest = token.replace("wdd", "");
-                generated_tokens.push(Token::Unit(Unit::WeekDay));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
-            } else {
-                let rest = token.replace("wd", "");
-                generated_tokens.push(Token::Unit(Unit::ShortWeekDay));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
-            }
-        } else if token.contains('d') {
-            if token.contains("dd") {
-                let rest = token.replace('d', "");
-                generated_tokens.push(Token::Unit(Unit::Day));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
-            } else {
-                let rest = token.replace('d', "");
-                generated_tokens.push(Token::Unit(Unit::ShortDay));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
-            }
-        } else if token.contains('H') {
-            if token.contains("HH") {
-                let rest = token.replace('H', "");
-                generated_tokens.push(Token::Unit(Unit::Hour));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
-            } else {
-                let rest = token.replace('H', "");
-                generated_tokens.push(Token::Unit(Unit::ShortHour));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
-            }
-        } else if token.contains('y') {
-            if token.contains("yyyy") {
-                let rest = token.replace('y', "");
-                generated_tokens.push(Token::Unit(Unit::FullYear));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
-            } else if token.contains("yy") {
-                let rest = token.replace('y', "");
-                generated_tokens.push(Token::Unit(Unit::Year));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
-            } else {
-                let rest = token.replace('y', "");
-                generated_tokens.push(Token::Unit(Unit::ShortYear));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
-            }
-        } else if token.contains("wn") {
-            if token.contains("wnn") {
-                let rest = token.replace("wnn", "");
-                generated_tokens.push(Token::Unit(Unit::WeekNumber));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
-            } else {
-                let rest = token.replace("wn", "");
-                generated_tokens.push(Token::Unit(Unit::ShortWeekNumber));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
+/// All directives, longest first so that the first match is the longest one.
+///
+/// `%yyy` is kept as an alias of `%yy`. A leading `_` pads with spaces instead of zeros, a
+/// leading `-` trims the trailing zeros of a fraction of a second.
+const DIRECTIVES: [(&str, Unit); 46] = [
+    ("mmmm", Unit::WordMonth),
+    ("yyyy", Unit::FullYear),
+    ("GGGG", Unit::IsoYear),
+    ("_ddd", Unit::SpacePaddedOrdinalDay),
+    ("mmm", Unit::ShortWordMonth),
+    ("yyy", Unit::Year),
+    ("ddd", Unit::OrdinalDay),
+    ("wdd", Unit::WeekDay),
+    ("wnn", Unit::WeekNumber),
+    ("_wn", Unit::SpacePaddedWeekNumber),
+    ("tms", Unit::TimestampMillis),
+    ("-MS", Unit::TrimmedMillisecond),
+    ("-US", Unit::TrimmedMicrosecond),
+    ("-NS", Unit::TrimmedNanosecond),
+    ("MS", Unit::Millisecond),
+    ("US", Unit::Microsecond),
+    ("NS", Unit::Nanosecond),
+    ("tz", Unit::Timezone),
+    ("mm", Unit::NumMonth),
+    ("MM", Unit::Minute),
+    ("wd", Unit::ShortWeekDay),
+    ("dd", Unit::Day),
+    ("HH", Unit::Hour),
+    ("yy", Unit::Year),
+    ("wn", Unit::ShortWeekNumber),
+    ("SS", Unit::Second),
+    ("GG", Unit::ShortIsoYear),
+    ("II", Unit::TwelveHour),
+    ("do", Unit::OrdinalSuffixDay),
+    ("ts", Unit::TimestampSeconds),
+    ("_d", Unit::SpacePaddedDay),
+    ("_m", Unit::SpacePaddedNumMonth),
+    ("_H", Unit::SpacePaddedHour),
+    ("_I", Unit::SpacePaddedTwelveHour),
+    ("_M", Unit::SpacePaddedMinute),
+    ("_S", Unit::SpacePaddedSecond),
+    ("m", Unit::ShortNumMonth),
+    ("M", Unit::ShortMinute),
+    ("d", Unit::ShortDay),
+    ("H", Unit::ShortHour),
+    ("y", Unit::ShortYear),
+    ("S", Unit::ShortSecond),
+    ("I", Unit::ShortTwelveHour),
+    ("P", Unit::UpperMeridiem),
+    ("p", Unit::LowerMeridiem),
+    ("q", Unit::Quarter),
+];
+
+/// Tokenizes the format argument from `.format()`, writing unknown directives as literal text.
+pub fn tokenize(format: &str) -> Vec<Token> {
+    lex(format).0
+}
+
+/// Splits a format string into directives and the literal text between them.
+///
+/// `%%` is a literal `%`, every other `%` starts the longest directive it is followed by.
+/// Unknown directives are kept as literal text, the byte position of the first one is returned
+/// alongside the tokens.
+pub fn lex(format: &str) -> (Vec<Token>, Option<usize>) {
+    let mut tokens = Vec::new();
+    let mut literal = String::new();
+    let mut unknown = None;
+    let mut rest = format;
+    while let Some(percent) = rest.find('%') {
+        literal.push_str(&rest[..percent]);
+        let directive = &rest[percent + 1..];
+        if let Some(escaped) = directive.strip_prefix('%') {
+            literal.push('%');
+            rest = escaped;
+            continue;
+        }
+        match DIRECTIVES
+            .iter()
+            .find(|(name, _)| directive.starts_with(name))
+        {
+            Some((name, unit)) => {
+                if !literal.is_empty() {
+                    tokens.push(Token::Separator(Separator {
+                        separator_symbol: std::mem::take(&mut literal),
+                    }));
+                }
+                tokens.push(Token::Unit(*unit));
+                rest = &directive[name.len()..];
             }
-        } else if token.contains('S') {
-            if token.contains("SS") {
-                let rest = token.replace('S', "");
-                generated_tokens.push(Token::Unit(Unit::Second));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
-            } else {
-                let rest = token.replace('S', "");
-                generated_tokens.push(Token::Unit(Unit::ShortSecond));
-                generated_tokens.push(Token::Separator(Separator {
-                    separator_symbol: rest,
-                }));
+            None => {
+                unknown.get_or_insert(format.len() - rest.len() + percent);
+                literal.push('%');
+                rest = directive;
             }
         }
     }
-    generated_tokens
+    literal.push_str(rest);
+    if !literal.is_empty() {
+        tokens.push(Token::Separator(Separator {
+            separator_symbol: literal,
+        }));
+    }
+    (tokens, unknown)
 }
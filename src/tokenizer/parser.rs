@@ -0,0 +1,478 @@
+use crate::{
+    HoraeError, ParseComponent, Weekday,
+    date_time::{
+        DateTime,
+        common::{
+            NANOS_IN_SECOND, SECONDS_IN_DAY, SECONDS_IN_HOUR, SECONDS_IN_MINUTE, ordinal_suffix,
+            validate_date,
+        },
+        date::Date,
+        iso_week::IsoWeek,
+        month::Month,
+        parser::{Cursor, ParseFailure, iso8601_offset},
+        time::Time,
+    },
+};
+
+use super::{Format, Token, Unit};
+
+/// The first year two-digit years are read in, `69` is 1969 and `68` is 2068 as in POSIX.
+pub const DEFAULT_PIVOT_YEAR: u16 = 1969;
+
+/// The Unix timestamps of 0001-01-01T00:00:00Z and 9999-12-31T23:59:59Z.
+const TIMESTAMP_RANGE: std::ops::RangeInclusive<i64> = -62_135_596_800..=253_402_300_799;
+
+/// The components read from an input by a format string, together with where they started.
+pub struct Parsed<'a> {
+    input: &'a str,
+    year: Option<(u16, usize)>,
+    month: Option<(u8, usize)>,
+    day: Option<(u8, usize)>,
+    ordinal: Option<(u16, usize)>,
+    iso_year: Option<(u16, usize)>,
+    week: Option<(u8, usize)>,
+    weekday: Option<(Weekday, usize)>,
+    quarter: Option<(u8, usize)>,
+    hour: u8,
+    twelve_hour: Option<u8>,
+    pm: bool,
+    minute: u8,
+    second: u8,
+    nanos: u32,
+    offset: Option<i32>,
+    timestamp: Option<(i64, u32, usize)>,
+}
+
+/// Reads `input` by the directives of the compiled `format`, the literal text between them has
+/// to match exactly.
+///
+/// Two-digit years are read as the year ending in them in the hundred years from `pivot_year`.
+pub fn parse_with_format<'a>(
+    input: &'a str,
+    format: &Format,
+    pivot_year: u16,
+) -> Result<Parsed<'a>, ParseFailure> {
+    use ParseComponent as C;
+
+    let mut cursor = Cursor::new(input);
+    let mut parsed = Parsed {
+        input,
+        year: None,
+        month: None,
+        day: None,
+        ordinal: None,
+        iso_year: None,
+        week: None,
+        weekday: None,
+        quarter: None,
+        hour: 0,
+        twelve_hour: None,
+        pm: false,
+        minute: 0,
+        second: 0,
+        nanos: 0,
+        offset: None,
+        timestamp: None,
+    };
+    let two_digit_year = |year: u32| {
+        let offset = (year as i32 - i32::from(pivot_year % 100)).rem_euclid(100);
+        (i32::from(pivot_year) + offset) as u16
+    };
+    for token in format.tokens() {
+        let unit = match token {
+            Token::Separator(separator) => {
+                if !cursor.eat_str(&separator.separator_symbol) {
+                    return Err(cursor.error(
+                        C::Literal,
+                        cursor.pos,
+                        "the literal text of the format",
+                    ));
+                }
+                continue;
+            }
+            Token::Unit(unit) => *unit,
+        };
+        let pos = cursor.pos;
+        match unit {
+            Unit::Timezone => parsed.offset = Some(offset(&mut cursor)?),
+            Unit::Millisecond
+            | Unit::Microsecond
+            | Unit::Nanosecond
+            | Unit::TrimmedMillisecond
+            | Unit::TrimmedMicrosecond
+            | Unit::TrimmedNanosecond => {
+                let (digits, trimmed, description) = match unit {
+                    Unit::Millisecond => (3, false, "three digit milliseconds"),
+                    Unit::Microsecond => (6, false, "six digit microseconds"),
+                    Unit::Nanosecond => (9, false, "nine digit nanoseconds"),
+                    Unit::TrimmedMillisecond => (3, true, "one to three digit milliseconds"),
+                    Unit::TrimmedMicrosecond => (6, true, "one to six digit microseconds"),
+                    _ => (9, true, "one to nine digit nanoseconds"),
+                };
+                let len = if trimmed {
+                    cursor.digit_run().clamp(1, digits)
+                } else {
+                    digits
+                };
+                parsed.nanos =
+                    cursor.digits(len, C::Fraction, description)? * 10u32.pow(9 - len as u32);
+            }
+            Unit::ShortSecond | Unit::Second | Unit::SpacePaddedSecond => {
+                let second = number(&mut cursor, unit, C::Second, "a second")?;
+                if second > 59 {
+                    return Err(cursor.range_error(
+                        C::Second,
+                        pos,
+                        "a second of 00-59",
+                        HoraeError::InvalidSecond(second as u8),
+                    ));
+                }
+                parsed.second = second as u8;
+            }
+            Unit::ShortMinute | Unit::Minute | Unit::SpacePaddedMinute => {
+                let minute = number(&mut cursor, unit, C::Minute, "a minute")?;
+                if minute > 59 {
+                    return Err(cursor.range_error(
+                        C::Minute,
+                        pos,
+                        "a minute of 00-59",
+                        HoraeError::InvalidMinute(minute as u8),
+                    ));
+                }
+                parsed.minute = minute as u8;
+            }
+            Unit::ShortHour | Unit::Hour | Unit::SpacePaddedHour => {
+                let hour = number(&mut cursor, unit, C::Hour, "an hour")?;
+                if hour > 23 {
+                    return Err(cursor.range_error(
+                        C::Hour,
+                        pos,
+                        "an hour of 00-23",
+                        HoraeError::InvalidHour(hour as u8),
+                    ));
+                }
+                parsed.hour = hour as u8;
+            }
+            Unit::ShortTwelveHour | Unit::TwelveHour | Unit::SpacePaddedTwelveHour => {
+                let hour = number(&mut cursor, unit, C::Hour, "an hour")?;
+                if !(1..=12).contains(&hour) {
+                    return Err(cursor.range_error(
+                        C::Hour,
+                        pos,
+                        "an hour of 01-12",
+                        HoraeError::InvalidHour(hour as u8),
+                    ));
+                }
+                parsed.twelve_hour = Some(hour as u8);
+            }
+            Unit::UpperMeridiem | Unit::LowerMeridiem => {
+                let meridiem = cursor.letters();
+                if meridiem.eq_ignore_ascii_case("am") {
+                    parsed.pm = false;
+                } else if meridiem.eq_ignore_ascii_case("pm") {
+                    parsed.pm = true;
+                } else {
+                    return Err(cursor.error(C::Hour, pos, "AM or PM"));
+                }
+            }
+            Unit::ShortDay | Unit::Day | Unit::SpacePaddedDay => {
+                parsed.day = Some((number(&mut cursor, unit, C::Day, "a day")? as u8, pos));
+            }
+            Unit::OrdinalSuffixDay => {
+                let day = number(&mut cursor, unit, C::Day, "a day")? as u8;
+                if !cursor.eat_str(ordinal_suffix(day)) {
+                    return Err(cursor.error(
+                        C::Day,
+                        cursor.pos,
+                        "the ordinal suffix of the day, like the st of 1st",
+                    ));
+                }
+                parsed.day = Some((day, pos));
+            }
+            Unit::OrdinalDay | Unit::SpacePaddedOrdinalDay => {
+                let ordinal = number(&mut cursor, unit, C::Day, "a three digit day of the year")?;
+                parsed.ordinal = Some((ordinal as u16, pos));
+            }
+            Unit::ShortNumMonth | Unit::NumMonth | Unit::SpacePaddedNumMonth => {
+                let month = number(&mut cursor, unit, C::Month, "a month")?;
+                parsed.month = Some((month as u8, pos));
+            }
+            Unit::Quarter => {
+                let quarter = cursor.digits(1, C::Month, "a quarter of 1-4")?;
+                if !(1..=4).contains(&quarter) {
+                    return Err(cursor.error(C::Month, pos, "a quarter of 1-4"));
+                }
+                parsed.quarter = Some((quarter as u8, pos));
+            }
+            Unit::ShortWordMonth | Unit::WordMonth => {
+                let month = Month::from_name(cursor.letters())
+                    .ok_or_else(|| cursor.error(C::Month, pos, "a month like Jan or January"))?;
+                parsed.month = Some((month.number(), pos));
+            }
+            Unit::ShortYear => {
+                return Err(cursor.error(
+                    C::Year,
+                    pos,
+                    "a year of more than one digit, %y cannot be read back",
+                ));
+            }
+            Unit::Year => {
+                let year = cursor.digits(2, C::Year, "a two digit year")?;
+                parsed.year = Some((two_digit_year(year), pos));
+            }
+            Unit::FullYear => {
+                let year = cursor.digits(4, C::Year, "a four digit year")?;
+                parsed.year = Some((year as u16, pos));
+            }
+            Unit::ShortIsoYear => {
+                let year = cursor.digits(2, C::Year, "a two digit ISO year")?;
+                parsed.iso_year = Some((two_digit_year(year), pos));
+            }
+            Unit::IsoYear => {
+                let year = cursor.digits(4, C::Year, "a four digit ISO year")?;
+                parsed.iso_year = Some((year as u16, pos));
+            }
+            Unit::ShortWeekDay | Unit::WeekDay => {
+                let weekday = Weekday::from_name(cursor.letters()).ok_or_else(|| {
+                    cursor.error(C::WeekDay, pos, "a week day like Mon or Monday")
+                })?;
+                parsed.weekday = Some((weekday, pos));
+            }
+            Unit::ShortWeekNumber | Unit::WeekNumber | Unit::SpacePaddedWeekNumber => {
+                let week = number(&mut cursor, unit, C::Week, "a week")?;
+                parsed.week = Some((week as u8, pos));
+            }
+            Unit::TimestampSeconds => {
+                parsed.timestamp = Some((timestamp(&mut cursor, 1)?, 0, pos));
+            }
+            Unit::TimestampMillis => {
+                let millis = timestamp(&mut cursor, 1000)?;
+                let nanos = millis.rem_euclid(1000) as u32 * (NANOS_IN_SECOND / 1000);
+                parsed.timestamp = Some((millis.div_euclid(1000), nanos, pos));
+            }
+        }
+    }
+    if !cursor.is_done() {
+        return Err(cursor.error(C::Literal, cursor.pos, "the end of the input"));
+    }
+    Ok(parsed)
+}
+
+impl Parsed<'_> {
+    /// Returns the read time of day, midnight if none was read.
+    ///
+    /// An hour on a 12-hour clock is read as AM without a marker, a Unix timestamp is read in
+    /// the read UTC offset.
+    pub fn time(&self) -> Time {
+        if let Some((seconds, nanos, _)) = self.timestamp {
+            let local_seconds =
+                (seconds + i64::from(self.offset.unwrap_or(0))).rem_euclid(SECONDS_IN_DAY) as u32;
+            return Time::from_hms_nano_unchecked(
+                (local_seconds / 3600) as u8,
+                (local_seconds / 60 % 60) as u8,
+                (local_seconds % 60) as u8,
+                nanos,
+            );
+        }
+        let hour = match self.twelve_hour {
+            Some(hour) => hour % 12 + if self.pm { 12 } else { 0 },
+            None => self.hour,
+        };
+        Time::from_hms_nano_unchecked(hour, self.minute, self.second, self.nanos)
+    }
+
+    /// Returns the read date, checked against every other date component that was read.
+    ///
+    /// The date is read from a year, month and day, a year and day of the year, or an ISO year
+    /// or year and a week. A missing month is read as the first of the quarter or year, a
+    /// missing day as the first, a missing week day as Monday. A Unix timestamp takes precedence
+    /// over all of them.
+    pub fn date(&self) -> Result<Date, ParseFailure> {
+        use ParseComponent as C;
+
+        let cursor = Cursor::new(self.input);
+        let end = self.input.len();
+        if let Some((seconds, _, pos)) = self.timestamp {
+            let local_seconds = seconds + i64::from(self.offset.unwrap_or(0));
+            return Date::from_days_since_epoch(local_seconds.div_euclid(SECONDS_IN_DAY))
+                .map_err(|e| cursor.range_error(C::Timestamp, pos, "a date in 0001-9999", e));
+        }
+        let date = match (self.year, self.ordinal, self.week) {
+            (Some((year, year_pos)), Some((ordinal, ordinal_pos)), _)
+                if self.month.is_none() && self.day.is_none() =>
+            {
+                Date::from_ordinal(year, ordinal).map_err(|e| match e {
+                    HoraeError::InvalidYear(_) => {
+                        cursor.range_error(C::Year, year_pos, "a year of 0001-9999", e)
+                    }
+                    _ => {
+                        cursor.range_error(C::Day, ordinal_pos, "a day that exists in the year", e)
+                    }
+                })?
+            }
+            (year, _, Some((week, week_pos))) if self.month.is_none() && self.day.is_none() => {
+                let (year, year_pos) = self
+                    .iso_year
+                    .or(year)
+                    .ok_or_else(|| cursor.error(C::Year, end, "a year for the week"))?;
+                let week = IsoWeek::new(year, week).map_err(|e| match e {
+                    HoraeError::InvalidYear(_) => {
+                        cursor.range_error(C::Year, year_pos, "a year of 0001-9999", e)
+                    }
+                    _ => cursor.range_error(C::Week, week_pos, "a week that exists in the year", e),
+                })?;
+                let weekday = self.weekday.map_or(0, |(weekday, _)| weekday as u8);
+                Date::from_days_since_epoch(week.first_day_since_epoch() + i64::from(weekday))
+                    .map_err(|e| cursor.range_error(C::Week, week_pos, "a date in 0001-9999", e))?
+            }
+            (Some((year, year_pos)), _, _) => {
+                let (month, month_pos) = self
+                    .month
+                    .or(self.quarter.map(|(quarter, pos)| (quarter * 3 - 2, pos)))
+                    .unwrap_or((1, end));
+                let (day, day_pos) = self.day.unwrap_or((1, end));
+                if let Err(e) = validate_date(year, month, day) {
+                    let (component, position, expected) = match e {
+                        HoraeError::InvalidYear(_) => (C::Year, year_pos, "a year of 0001-9999"),
+                        HoraeError::InvalidMonth(_) => (C::Month, month_pos, "a month of 01-12"),
+                        _ => (C::Day, day_pos, "a day that exists in the month"),
+                    };
+                    return Err(cursor.range_error(component, position, expected, e));
+                }
+                Date::from_ymd_unchecked(year, month, day)
+            }
+            (None, _, _) => return Err(cursor.error(C::Year, end, "a year in the input")),
+        };
+
+        if let Some((quarter, pos)) = self.quarter
+            && quarter != (date.month() - 1) / 3 + 1
+        {
+            return Err(cursor.error(C::Month, pos, "the quarter of the date"));
+        }
+        if let Some((weekday, pos)) = self.weekday
+            && weekday != date.weekday()
+        {
+            return Err(cursor.error(C::WeekDay, pos, "the week day of the date"));
+        }
+        if let Some((ordinal, pos)) = self.ordinal
+            && ordinal != date.ordinal_day()
+        {
+            return Err(cursor.error(C::Day, pos, "the day of the year of the date"));
+        }
+        let iso_week = date.iso_week();
+        if let Some((week, pos)) = self.week
+            && week != iso_week.week()
+        {
+            return Err(cursor.error(C::Week, pos, "the week of the date"));
+        }
+        if let Some((iso_year, pos)) = self.iso_year
+            && iso_year != iso_week.year()
+        {
+            return Err(cursor.error(C::Year, pos, "the ISO year of the date"));
+        }
+        Ok(date)
+    }
+
+    /// Returns the read date and time in the read UTC offset, UTC if none was read.
+    pub fn date_time(&self) -> Result<DateTime, ParseFailure> {
+        let offset = self.offset.unwrap_or(0);
+        if let Some((seconds, nanos, _)) = self.timestamp {
+            let mut dt = DateTime::from_timestamp_parts(seconds, nanos);
+            dt.with_utc_offset_seconds(offset);
+            return Ok(dt);
+        }
+        let date = self.date()?;
+        let time = self.time();
+        let local_seconds = date.days_since_epoch() * SECONDS_IN_DAY
+            + i64::from(time.hour()) * SECONDS_IN_HOUR
+            + i64::from(time.minute()) * SECONDS_IN_MINUTE
+            + i64::from(time.second());
+        let mut dt = DateTime::from_timestamp_parts(local_seconds - i64::from(offset), self.nanos);
+        dt.with_utc_offset_seconds(offset);
+        Ok(dt)
+    }
+}
+
+/// Reads the digits of a long directive, one or two digits of a short one, or the digits of a
+/// space-padded one after its padding.
+fn number(
+    cursor: &mut Cursor,
+    unit: Unit,
+    component: ParseComponent,
+    description: &'static str,
+) -> Result<u32, ParseFailure> {
+    let width = match unit {
+        Unit::ShortSecond
+        | Unit::ShortMinute
+        | Unit::ShortHour
+        | Unit::ShortTwelveHour
+        | Unit::ShortDay
+        | Unit::OrdinalSuffixDay
+        | Unit::ShortNumMonth
+        | Unit::ShortWeekNumber => {
+            let len = cursor.digit_run().clamp(1, 2);
+            return cursor.digits(len, component, description);
+        }
+        Unit::OrdinalDay | Unit::SpacePaddedOrdinalDay => 3,
+        _ => 2,
+    };
+    let padded = matches!(
+        unit,
+        Unit::SpacePaddedSecond
+            | Unit::SpacePaddedMinute
+            | Unit::SpacePaddedHour
+            | Unit::SpacePaddedTwelveHour
+            | Unit::SpacePaddedDay
+            | Unit::SpacePaddedOrdinalDay
+            | Unit::SpacePaddedNumMonth
+            | Unit::SpacePaddedWeekNumber
+    );
+    let start = cursor.pos;
+    let mut len = width;
+    while padded && len > 1 && cursor.eat(b" ").is_some() {
+        len -= 1;
+    }
+    cursor.digits(len, component, description).map_err(|_| {
+        cursor.pos = start;
+        cursor.error(component, start, description)
+    })
+}
+
+/// Reads a signed Unix timestamp in units of `1 / per_second` seconds.
+fn timestamp(cursor: &mut Cursor, per_second: i64) -> Result<i64, ParseFailure> {
+    use ParseComponent as C;
+
+    let start = cursor.pos;
+    let negative = cursor.eat(b"-").is_some();
+    let len = cursor.digit_run();
+    // Every supported timestamp fits into 12 digits of seconds
+    if len == 0 || len > 12 + per_second.ilog10() as usize {
+        return Err(cursor.error(C::Timestamp, start, "a Unix timestamp"));
+    }
+    let mut value: i64 = 0;
+    while let Some(digit) = cursor.eat(b"0123456789") {
+        value = value * 10 + i64::from(digit - b'0');
+    }
+    if negative {
+        value = -value;
+    }
+    if !TIMESTAMP_RANGE.contains(&value.div_euclid(per_second)) {
+        return Err(cursor.range_error(
+            C::Timestamp,
+            start,
+            "a Unix timestamp in 0001-9999",
+            HoraeError::OutOfRange,
+        ));
+    }
+    Ok(value)
+}
+
+/// Reads a UTC offset as written by `%tz`, `Coordinated Universal Time` or `GMT+hh:mm`, or as
+/// `UTC`, `Z`, `+hh:mm`, `+hhmm` or `+hh`, into seconds.
+fn offset(cursor: &mut Cursor) -> Result<i32, ParseFailure> {
+    if cursor.eat_str("Coordinated Universal Time") || cursor.eat_str("UTC") {
+        return Ok(0);
+    }
+    cursor.eat_str("GMT");
+    iso8601_offset(cursor)
+}
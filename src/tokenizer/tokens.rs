@@ -1,16 +1,16 @@
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 /// All Tokens needed to construct a formatted string
 pub enum Token {
     Separator(Separator),
     Unit(Unit),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Separator {
     pub separator_symbol: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Unit {
     Timezone,
     Millisecond,
@@ -22,6 +22,7 @@ pub enum Unit {
     Hour,
     ShortDay,
     Day,
+    OrdinalDay,
     ShortNumMonth,
     NumMonth,
     ShortWordMonth,
@@ -33,4 +34,27 @@ pub enum Unit {
     WeekDay,
     ShortWeekNumber,
     WeekNumber,
+    ShortIsoYear,
+    IsoYear,
+    SpacePaddedDay,
+    SpacePaddedOrdinalDay,
+    OrdinalSuffixDay,
+    SpacePaddedNumMonth,
+    Quarter,
+    SpacePaddedWeekNumber,
+    SpacePaddedHour,
+    ShortTwelveHour,
+    TwelveHour,
+    SpacePaddedTwelveHour,
+    SpacePaddedMinute,
+    SpacePaddedSecond,
+    UpperMeridiem,
+    LowerMeridiem,
+    TimestampSeconds,
+    TimestampMillis,
+    Microsecond,
+    Nanosecond,
+    TrimmedMillisecond,
+    TrimmedMicrosecond,
+    TrimmedNanosecond,
 }
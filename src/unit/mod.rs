@@ -0,0 +1,53 @@
+use crate::date_time::common::{
+    SECONDS_IN_DAY, SECONDS_IN_HOUR, SECONDS_IN_MINUTE, civil_from_days, days_from_civil, week_day,
+};
+
+/// A calendar or clock unit to truncate, round or ceil a `Utc` to.
+///
+/// Units are evaluated on the local date and time in the UTC offset of the `Utc`, so `Day`
+/// starts at local midnight. Weeks are ISO 8601 weeks starting on Monday, quarters start in
+/// January, April, July and October.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl TimeUnit {
+    /// Returns the local seconds since the unix epoch of the start of the unit containing
+    /// `local_seconds`, and of the start of the unit after it.
+    pub(crate) fn bounds(&self, local_seconds: i64) -> (i64, i64) {
+        let fixed = |size: i64| {
+            let start = local_seconds - local_seconds.rem_euclid(size);
+            (start, start + size)
+        };
+        let day = local_seconds.div_euclid(SECONDS_IN_DAY);
+        let (year, month, _) = civil_from_days(day);
+        let months = |months: u8| {
+            let first = (month - 1) / months * months + 1;
+            let next = i64::from(first + months);
+            let start = days_from_civil(year, first, 1);
+            let end = days_from_civil(year + (next - 1) / 12, ((next - 1) % 12 + 1) as u8, 1);
+            (start * SECONDS_IN_DAY, end * SECONDS_IN_DAY)
+        };
+        match self {
+            TimeUnit::Second => fixed(1),
+            TimeUnit::Minute => fixed(SECONDS_IN_MINUTE),
+            TimeUnit::Hour => fixed(SECONDS_IN_HOUR),
+            TimeUnit::Day => fixed(SECONDS_IN_DAY),
+            TimeUnit::Week => {
+                let monday = day - i64::from(week_day(day)) + 1;
+                (monday * SECONDS_IN_DAY, (monday + 7) * SECONDS_IN_DAY)
+            }
+            TimeUnit::Month => months(1),
+            TimeUnit::Quarter => months(3),
+            TimeUnit::Year => months(12),
+        }
+    }
+}
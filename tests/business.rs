@@ -0,0 +1,200 @@
+use horae::{BusinessCalendar, Date, HoraeError, RollConvention, Weekday};
+
+fn christmas_calendar() -> BusinessCalendar {
+    let mut calendar = BusinessCalendar::new();
+    calendar.add_holidays([
+        Date::from_ymd(2024, 12, 25).unwrap(),
+        Date::from_ymd(2024, 12, 26).unwrap(),
+        Date::from_ymd(2025, 1, 1).unwrap(),
+    ]);
+    calendar
+}
+
+#[test]
+fn business_days() {
+    let calendar = christmas_calendar();
+    assert!(calendar.is_business_day(Date::from_ymd(2024, 12, 24).unwrap()));
+    assert!(!calendar.is_business_day(Date::from_ymd(2024, 12, 25).unwrap()));
+    assert!(!calendar.is_business_day(Date::from_ymd(2024, 12, 28).unwrap()));
+    assert!(calendar.is_holiday(Date::from_ymd(2025, 1, 1).unwrap()));
+    assert!(!calendar.is_weekend(Date::from_ymd(2025, 1, 1).unwrap()));
+    assert!(calendar.is_weekend(Date::from_ymd(2024, 12, 29).unwrap()));
+    assert_eq!(calendar.weekend(), [Weekday::Saturday, Weekday::Sunday]);
+    assert_eq!(BusinessCalendar::default(), BusinessCalendar::new());
+}
+
+#[test]
+fn holidays() {
+    let mut calendar = christmas_calendar();
+    assert!(!calendar.add_holiday(Date::from_ymd(2024, 12, 25).unwrap()));
+    assert!(calendar.remove_holiday(Date::from_ymd(2024, 12, 26).unwrap()));
+    assert!(!calendar.remove_holiday(Date::from_ymd(2024, 12, 26).unwrap()));
+    assert_eq!(
+        calendar.holidays().collect::<Vec<Date>>(),
+        [
+            Date::from_ymd(2024, 12, 25).unwrap(),
+            Date::from_ymd(2025, 1, 1).unwrap()
+        ]
+    );
+    assert!(calendar.is_business_day(Date::from_ymd(2024, 12, 26).unwrap()));
+}
+
+#[test]
+fn custom_weekend() {
+    let mut calendar = BusinessCalendar::new();
+    calendar.with_weekend(&[Weekday::Saturday, Weekday::Friday, Weekday::Friday]);
+    assert_eq!(calendar.weekend(), [Weekday::Friday, Weekday::Saturday]);
+    // Thursday to Sunday
+    assert_eq!(
+        calendar
+            .add_business_days(Date::from_ymd(2024, 3, 7).unwrap(), 1)
+            .unwrap(),
+        Date::from_ymd(2024, 3, 10).unwrap()
+    );
+
+    calendar.with_weekend(&[]);
+    assert!(calendar.is_business_day(Date::from_ymd(2024, 3, 9).unwrap()));
+    assert_eq!(
+        calendar.business_days_between(
+            Date::from_ymd(2024, 1, 1).unwrap(),
+            Date::from_ymd(2025, 1, 1).unwrap()
+        ),
+        366
+    );
+}
+
+#[test]
+fn add_business_days() {
+    let calendar = christmas_calendar();
+    let friday = Date::from_ymd(2024, 12, 20).unwrap();
+    assert_eq!(calendar.add_business_days(friday, 0).unwrap(), friday);
+    assert_eq!(
+        calendar.add_business_days(friday, 1).unwrap(),
+        Date::from_ymd(2024, 12, 23).unwrap()
+    );
+    assert_eq!(
+        calendar.add_business_days(friday, 3).unwrap(),
+        Date::from_ymd(2024, 12, 27).unwrap()
+    );
+    assert_eq!(
+        calendar.add_business_days(friday, 6).unwrap(),
+        Date::from_ymd(2025, 1, 2).unwrap()
+    );
+    assert_eq!(
+        calendar
+            .add_business_days(Date::from_ymd(2025, 1, 2).unwrap(), -6)
+            .unwrap(),
+        friday
+    );
+    // From a day that is not a business day
+    assert_eq!(
+        calendar
+            .add_business_days(Date::from_ymd(2024, 12, 25).unwrap(), -1)
+            .unwrap(),
+        Date::from_ymd(2024, 12, 24).unwrap()
+    );
+    assert_eq!(
+        calendar.add_business_days(Date::from_ymd(9999, 12, 30).unwrap(), 2),
+        Err(HoraeError::OutOfRange)
+    );
+}
+
+#[test]
+fn business_days_between() {
+    let calendar = christmas_calendar();
+    let friday = Date::from_ymd(2024, 12, 20).unwrap();
+    assert_eq!(calendar.business_days_between(friday, friday), 0);
+    assert_eq!(
+        calendar.business_days_between(friday, Date::from_ymd(2025, 1, 2).unwrap()),
+        6
+    );
+    assert_eq!(
+        calendar.business_days_between(Date::from_ymd(2025, 1, 2).unwrap(), friday),
+        -6
+    );
+    // A full year of Saturday and Sunday weekends
+    assert_eq!(
+        BusinessCalendar::new().business_days_between(
+            Date::from_ymd(2023, 1, 1).unwrap(),
+            Date::from_ymd(2024, 1, 1).unwrap()
+        ),
+        260
+    );
+
+    // Agrees with adding business days
+    for days in [1, 2, 5, 13, 40] {
+        let end = calendar.add_business_days(friday, days).unwrap();
+        assert_eq!(calendar.business_days_between(friday, end), days);
+        assert_eq!(calendar.business_days_between(end, friday), -days);
+    }
+}
+
+#[test]
+fn next_and_previous() {
+    let calendar = christmas_calendar();
+    assert_eq!(
+        calendar
+            .next_business_day(Date::from_ymd(2024, 12, 24).unwrap())
+            .unwrap(),
+        Date::from_ymd(2024, 12, 27).unwrap()
+    );
+    assert_eq!(
+        calendar
+            .previous_business_day(Date::from_ymd(2024, 12, 27).unwrap())
+            .unwrap(),
+        Date::from_ymd(2024, 12, 24).unwrap()
+    );
+    assert_eq!(
+        calendar.previous_business_day(Date::from_ymd(1, 1, 1).unwrap()),
+        Err(HoraeError::OutOfRange)
+    );
+}
+
+#[test]
+fn roll_conventions() {
+    let calendar = christmas_calendar();
+    let business_day = Date::from_ymd(2024, 12, 24).unwrap();
+    for convention in [
+        RollConvention::Following,
+        RollConvention::ModifiedFollowing,
+        RollConvention::Preceding,
+        RollConvention::ModifiedPreceding,
+    ] {
+        assert_eq!(
+            calendar.roll(business_day, convention).unwrap(),
+            business_day
+        );
+    }
+
+    let saturday = Date::from_ymd(2024, 11, 30).unwrap();
+    let roll = |date, convention| calendar.roll(date, convention).unwrap();
+    assert_eq!(
+        roll(saturday, RollConvention::Following),
+        Date::from_ymd(2024, 12, 2).unwrap()
+    );
+    assert_eq!(
+        roll(saturday, RollConvention::ModifiedFollowing),
+        Date::from_ymd(2024, 11, 29).unwrap()
+    );
+    assert_eq!(
+        roll(saturday, RollConvention::Preceding),
+        Date::from_ymd(2024, 11, 29).unwrap()
+    );
+
+    let sunday = Date::from_ymd(2024, 12, 1).unwrap();
+    assert_eq!(
+        roll(sunday, RollConvention::Preceding),
+        Date::from_ymd(2024, 11, 29).unwrap()
+    );
+    assert_eq!(
+        roll(sunday, RollConvention::ModifiedPreceding),
+        Date::from_ymd(2024, 12, 2).unwrap()
+    );
+    assert_eq!(
+        roll(
+            Date::from_ymd(2024, 12, 25).unwrap(),
+            RollConvention::ModifiedFollowing
+        ),
+        Date::from_ymd(2024, 12, 27).unwrap()
+    );
+}
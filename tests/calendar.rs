@@ -0,0 +1,212 @@
+use horae::{CalendarEvent, HoraeError, ParseComponent, Utc};
+
+#[test]
+fn normalization() {
+    for (expression, expected) in [
+        (
+            "Sat,Thu,Mon..Wed,Sat..Sun",
+            "Mon..Thu,Sat,Sun *-*-* 00:00:00",
+        ),
+        ("Mon,Sun 12-*-* 2,1:23", "Mon,Sun 0012-*-* 01,02:23:00"),
+        ("Wed *-1", "Wed *-*-01 00:00:00"),
+        ("Wed..Wed,Wed *-1", "Wed *-*-01 00:00:00"),
+        ("friday 2024-03-01 12:00:30", "Fri 2024-03-01 12:00:30"),
+        ("*-*-7 0:0:0", "*-*-07 00:00:00"),
+        ("10-15", "*-10-15 00:00:00"),
+        ("*:2/3", "*-*-* *:02/3:00"),
+        ("*:0/15", "*-*-* *:00/15:00"),
+        ("*:*/15", "*-*-* *:00/15:00"),
+        ("9..17/2:00", "*-*-* 09..17/2:00:00"),
+        ("*-02~03", "*-02~03 00:00:00"),
+        ("2024..2026-01-01", "2024..2026-01-01 00:00:00"),
+        ("12:00 utc", "*-*-* 12:00:00 UTC"),
+        ("12:00 -0530", "*-*-* 12:00:00 -05:30"),
+        ("12:00 +01", "*-*-* 12:00:00 +01:00"),
+    ] {
+        assert_eq!(
+            CalendarEvent::parse(expression).unwrap().to_string(),
+            expected,
+            "{expression}"
+        );
+    }
+}
+
+#[test]
+fn shorthands() {
+    for (expression, expected) in [
+        ("minutely", "*-*-* *:*:00"),
+        ("hourly", "*-*-* *:00:00"),
+        ("DAILY", "*-*-* 00:00:00"),
+        ("weekly", "Mon *-*-* 00:00:00"),
+        ("monthly", "*-*-01 00:00:00"),
+        ("quarterly", "*-01,04,07,10-01 00:00:00"),
+        ("semiannually", "*-01,07-01 00:00:00"),
+        ("yearly", "*-01-01 00:00:00"),
+        ("annually", "*-01-01 00:00:00"),
+        ("daily UTC", "*-*-* 00:00:00 UTC"),
+    ] {
+        assert_eq!(
+            CalendarEvent::parse(expression).unwrap().to_string(),
+            expected,
+            "{expression}"
+        );
+    }
+}
+
+#[test]
+fn next_elapse() {
+    let event = CalendarEvent::parse("*:0/20").unwrap();
+    let mut after = Utc::from_ymd_hms(2024, 1, 1, 10, 7, 0);
+    for expected in [
+        "2024-01-01 10:20:00.000",
+        "2024-01-01 10:40:00.000",
+        "2024-01-01 11:00:00.000",
+        "2024-01-01 11:20:00.000",
+    ] {
+        after = event.next_elapse(after).unwrap();
+        assert_eq!(after.to_string(), expected);
+    }
+
+    let event = CalendarEvent::parse("Mon..Fri 9..10:30").unwrap();
+    let mut after = Utc::from_ymd_hms(2024, 3, 1, 9, 30, 0);
+    for expected in [
+        "2024-03-01 10:30:00.000",
+        "2024-03-04 09:30:00.000",
+        "2024-03-04 10:30:00.000",
+    ] {
+        after = event.next_elapse(after).unwrap();
+        assert_eq!(after.to_string(), expected);
+    }
+
+    // Strictly after
+    let new_year = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let event = CalendarEvent::parse("daily").unwrap();
+    assert_eq!(
+        event.next_elapse(new_year),
+        Some(Utc::from_ymd_hms(2024, 1, 2, 0, 0, 0))
+    );
+
+    // Week days and days both have to match
+    let event = CalendarEvent::parse("Fri *-*-13").unwrap();
+    let first = event.next_elapse(new_year).unwrap();
+    assert_eq!(first, Utc::from_ymd_hms(2024, 9, 13, 0, 0, 0));
+    assert_eq!(
+        event.next_elapse(first),
+        Some(Utc::from_ymd_hms(2024, 12, 13, 0, 0, 0))
+    );
+}
+
+#[test]
+fn days_from_end() {
+    let new_year = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+
+    let event = CalendarEvent::parse("*-*~01").unwrap();
+    let mut after = new_year;
+    for expected in ["2024-01-31", "2024-02-29", "2024-03-31"] {
+        after = event.next_elapse(after).unwrap();
+        assert_eq!(after.date().to_string(), expected);
+    }
+
+    // The last seven days, every other one
+    let event = CalendarEvent::parse("*-02~07/2").unwrap();
+    let mut after = new_year;
+    for expected in ["2024-02-23", "2024-02-25", "2024-02-27", "2024-02-29"] {
+        after = event.next_elapse(after).unwrap();
+        assert_eq!(after.date().to_string(), expected);
+    }
+
+    // The last Sunday of March
+    let event = CalendarEvent::parse("Sun *-03~01..07").unwrap();
+    let first = event.next_elapse(new_year).unwrap();
+    assert_eq!(first, Utc::from_ymd_hms(2024, 3, 31, 0, 0, 0));
+    assert_eq!(
+        event.next_elapse(first),
+        Some(Utc::from_ymd_hms(2025, 3, 30, 0, 0, 0))
+    );
+}
+
+#[test]
+fn years() {
+    let new_year = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+
+    let event = CalendarEvent::parse("2024/4-02-29").unwrap();
+    let first = event.next_elapse(new_year).unwrap();
+    assert_eq!(first, Utc::from_ymd_hms(2024, 2, 29, 0, 0, 0));
+    assert_eq!(
+        event.next_elapse(first),
+        Some(Utc::from_ymd_hms(2028, 2, 29, 0, 0, 0))
+    );
+
+    let event = CalendarEvent::parse("2020-01-01").unwrap();
+    assert_eq!(event.next_elapse(new_year), None);
+    let event = CalendarEvent::parse("yearly").unwrap();
+    assert_eq!(
+        event.next_elapse(Utc::from_ymd_hms(9999, 6, 1, 0, 0, 0)),
+        None
+    );
+    let event = CalendarEvent::parse("*-02-30").unwrap();
+    assert_eq!(event.next_elapse(new_year), None);
+}
+
+#[test]
+fn zones() {
+    let new_year = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let event = CalendarEvent::parse("*-*-* 06:00 +02:00").unwrap();
+    let next = event.next_elapse(new_year).unwrap();
+    assert_eq!(next, Utc::from_ymd_hms(2024, 1, 1, 4, 0, 0));
+    assert_eq!(next.get_utc_offset(), 2.0);
+    assert_eq!(next.time().hour(), 6);
+
+    // Without a zone the offset of `after` is used
+    let event = CalendarEvent::parse("*-*-* 06:00").unwrap();
+    let mut after = new_year;
+    after.with_utc_offset(-5.0);
+    let next = event.next_elapse(after).unwrap();
+    assert_eq!(next, Utc::from_ymd_hms(2024, 1, 1, 11, 0, 0));
+    assert_eq!(next.get_utc_offset(), -5.0);
+
+    let event = CalendarEvent::parse("*-*-* 06:00 UTC").unwrap();
+    let next = event.next_elapse(after).unwrap();
+    assert_eq!(next, Utc::from_ymd_hms(2024, 1, 1, 6, 0, 0));
+    assert_eq!(next.get_utc_offset(), 0.0);
+}
+
+#[test]
+fn parse_errors() {
+    let error = |expression: &str| CalendarEvent::parse(expression).unwrap_err();
+
+    for (expression, component, position) in [
+        ("", ParseComponent::Field, 0),
+        ("   ", ParseComponent::Field, 3),
+        ("Foo 12:00", ParseComponent::WeekDay, 0),
+        ("Mo 12:00", ParseComponent::WeekDay, 0),
+        ("Fri..Mon", ParseComponent::WeekDay, 5),
+        ("*-13-01", ParseComponent::Month, 2),
+        ("*-*-32", ParseComponent::Day, 4),
+        ("*-*-0", ParseComponent::Day, 4),
+        ("0-01-01", ParseComponent::Year, 0),
+        ("*-01-02-03", ParseComponent::DateSeparator, 4),
+        ("24:00", ParseComponent::Hour, 0),
+        ("12:60", ParseComponent::Minute, 3),
+        ("12:00:61", ParseComponent::Second, 6),
+        ("12:00:00:00", ParseComponent::TimeSeparator, 8),
+        ("*:0/0", ParseComponent::Minute, 4),
+        ("*-*-5..2", ParseComponent::Day, 7),
+        ("12:00 +24:00", ParseComponent::Offset, 6),
+        ("12:00 CET", ParseComponent::Offset, 6),
+        ("12:00 UTC UTC", ParseComponent::Field, 10),
+        ("daily 12:00", ParseComponent::Offset, 6),
+    ] {
+        let e = error(expression);
+        assert_eq!(
+            (e.component(), e.position()),
+            (component, position),
+            "{expression}"
+        );
+    }
+
+    assert!(matches!(
+        "Mon..".parse::<CalendarEvent>(),
+        Err(HoraeError::Parse(_))
+    ));
+}
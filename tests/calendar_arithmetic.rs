@@ -0,0 +1,161 @@
+use horae::{HoraeError, MonthOverflow, Utc};
+
+#[test]
+fn add_months_end_of_month() {
+    let utc = Utc::from_ymd_hms(2023, 1, 31, 12, 0, 0);
+    assert_eq!(
+        utc.add_months(1, MonthOverflow::Clamp).unwrap().to_string(),
+        "2023-02-28 12:00:00.000"
+    );
+    assert_eq!(
+        utc.add_months(1, MonthOverflow::Rollover)
+            .unwrap()
+            .to_string(),
+        "2023-03-03 12:00:00.000"
+    );
+    assert_eq!(
+        utc.add_months(1, MonthOverflow::Fail),
+        Err(HoraeError::InvalidDay {
+            year: 2023,
+            month: 2,
+            day: 31
+        })
+    );
+    assert_eq!(
+        utc.add_months(2, MonthOverflow::Fail).unwrap().to_string(),
+        "2023-03-31 12:00:00.000"
+    );
+}
+
+#[test]
+fn add_months_across_years() {
+    let utc = Utc::from_ymd_hms(2023, 11, 15, 0, 0, 0);
+    assert_eq!(
+        utc.add_months(3, MonthOverflow::Fail).unwrap().to_string(),
+        "2024-02-15 00:00:00.000"
+    );
+    assert_eq!(
+        utc.add_months(-11, MonthOverflow::Fail)
+            .unwrap()
+            .to_string(),
+        "2022-12-15 00:00:00.000"
+    );
+    assert_eq!(
+        utc.add_months(-24, MonthOverflow::Fail)
+            .unwrap()
+            .to_string(),
+        "2021-11-15 00:00:00.000"
+    );
+    assert_eq!(utc.add_months(0, MonthOverflow::Fail).unwrap(), utc);
+}
+
+#[test]
+fn add_months_billing_cycle() {
+    // Each renewal is computed from the start, so the 31st is kept where it exists
+    let start = Utc::from_ymd_hms(2024, 1, 31, 8, 30, 0);
+    let renewals: Vec<String> = (1..=4)
+        .map(|n| {
+            start
+                .add_months(n, MonthOverflow::Clamp)
+                .unwrap()
+                .date()
+                .to_string()
+        })
+        .collect();
+    assert_eq!(
+        renewals,
+        ["2024-02-29", "2024-03-31", "2024-04-30", "2024-05-31"]
+    );
+}
+
+#[test]
+fn add_years_leap_day() {
+    let utc = Utc::from_ymd_hms(2024, 2, 29, 0, 0, 0);
+    assert_eq!(
+        utc.add_years(1, MonthOverflow::Clamp).unwrap().to_string(),
+        "2025-02-28 00:00:00.000"
+    );
+    assert_eq!(
+        utc.add_years(1, MonthOverflow::Rollover)
+            .unwrap()
+            .to_string(),
+        "2025-03-01 00:00:00.000"
+    );
+    assert_eq!(
+        utc.add_years(-4, MonthOverflow::Fail).unwrap().to_string(),
+        "2020-02-29 00:00:00.000"
+    );
+    assert!(utc.add_years(76, MonthOverflow::Fail).is_err());
+}
+
+#[test]
+fn add_out_of_range() {
+    let utc = Utc::from_ymd_hms(9999, 12, 1, 0, 0, 0);
+    assert_eq!(
+        utc.add_months(1, MonthOverflow::Clamp),
+        Err(HoraeError::OutOfRange)
+    );
+    let utc = Utc::from_ymd_hms(1, 1, 1, 0, 0, 0);
+    assert_eq!(
+        utc.add_years(-1, MonthOverflow::Clamp),
+        Err(HoraeError::OutOfRange)
+    );
+    assert_eq!(
+        utc.add_years(i32::MAX, MonthOverflow::Clamp),
+        Err(HoraeError::OutOfRange)
+    );
+}
+
+#[test]
+fn arithmetic_uses_local_date() {
+    // 23:00 on January 31 in UTC is already February 1 in UTC+2
+    let mut utc = Utc::from_ymd_hms(2023, 1, 31, 23, 0, 0);
+    utc.with_utc_offset(2.0);
+    assert_eq!(utc.to_string(), "2023-02-01 01:00:00.000");
+
+    let moved = utc.add_months(1, MonthOverflow::Fail).unwrap();
+    assert_eq!(moved.to_string(), "2023-03-01 01:00:00.000");
+    assert_eq!(moved.get_utc_offset(), 2.0);
+    assert_eq!(moved.to_rfc3339(), "2023-03-01T01:00:00+02:00");
+
+    let mut utc = Utc::from_ymd_hms(2023, 3, 31, 1, 0, 0);
+    utc.with_utc_offset(-5.0);
+    assert_eq!(utc.to_string(), "2023-03-30 20:00:00.000");
+    assert_eq!(
+        utc.add_months(-1, MonthOverflow::Clamp)
+            .unwrap()
+            .to_string(),
+        "2023-02-28 20:00:00.000"
+    );
+}
+
+#[test]
+fn add_days_and_weeks() {
+    let utc = Utc::from_timestamp_parts(1_700_000_000, 123);
+    assert_eq!(utc.add_days(0), Ok(utc));
+    assert_eq!(
+        utc.add_days(1).unwrap().unix_timestamp_secs(),
+        1_700_086_400
+    );
+    assert_eq!(utc.add_days(1).unwrap().subsec_nanos(), 123);
+    assert_eq!(utc.add_weeks(-2), utc.add_days(-14));
+
+    let utc = Utc::from_ymd_hms(2024, 2, 28, 6, 0, 0);
+    assert_eq!(utc.add_days(1).unwrap().date().to_string(), "2024-02-29");
+    assert_eq!(utc.add_days(366).unwrap().date().to_string(), "2025-02-28");
+    assert_eq!(utc.add_weeks(52).unwrap().date().to_string(), "2025-02-26");
+
+    assert_eq!(utc.add_days(10_000_000), Err(HoraeError::OutOfRange));
+    assert_eq!(utc.add_days(-800_000), Err(HoraeError::OutOfRange));
+    assert_eq!(utc.add_days(i64::MAX), Err(HoraeError::OutOfRange));
+    assert_eq!(utc.add_weeks(i64::MIN), Err(HoraeError::OutOfRange));
+
+    let mut last = Utc::from_ymd_hms(9999, 12, 31, 4, 0, 0);
+    last.with_utc_offset(-5.0);
+    assert_eq!(last.date().to_string(), "9999-12-30");
+    assert_eq!(
+        last.add_days(1).unwrap().to_rfc3339(),
+        "9999-12-31T23:00:00-05:00"
+    );
+    assert_eq!(last.add_days(2), Err(HoraeError::OutOfRange));
+}
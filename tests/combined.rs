@@ -5,7 +5,7 @@ fn positive() {
     // CEST(+02:00)
     let cest = Utc::from_ymd_hms_timezone(
         2021,
-        02,
+        2,
         25,
         13,
         59,
@@ -37,7 +37,7 @@ fn positive() {
 #[test]
 fn negative() {
     // Marquesas Islands =-09:30
-    let mart = Utc::from_ymd_hms_timezone(2021, 02, 25, 13, 59, 59, TimeZone::MarquesasIslandsTime);
+    let mart = Utc::from_ymd_hms_timezone(2021, 2, 25, 13, 59, 59, TimeZone::MarquesasIslandsTime);
     // Fixed: output should match input local time
     assert_eq!("2021-02-25 13:59:59.000", mart.to_string());
     let second = std::time::Duration::from_secs(1);
@@ -0,0 +1,297 @@
+use horae::{CronSchedule, HoraeError, ParseComponent, TimeZone, Utc};
+
+#[test]
+fn steps_ranges_and_lists() {
+    let new_year = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    for (expression, after, expected) in [
+        (
+            "*/15 * * * *",
+            Utc::from_ymd_hms(2024, 1, 1, 10, 7, 0),
+            &[
+                "2024-01-01 10:15:00.000",
+                "2024-01-01 10:30:00.000",
+                "2024-01-01 10:45:00.000",
+                "2024-01-01 11:00:00.000",
+            ][..],
+        ),
+        (
+            "10-40/15 8,17 * * *",
+            new_year,
+            &[
+                "2024-01-01 08:10:00.000",
+                "2024-01-01 08:25:00.000",
+                "2024-01-01 08:40:00.000",
+                "2024-01-01 17:10:00.000",
+            ],
+        ),
+        (
+            "50/5 23 * * *",
+            new_year,
+            &[
+                "2024-01-01 23:50:00.000",
+                "2024-01-01 23:55:00.000",
+                "2024-01-02 23:50:00.000",
+            ],
+        ),
+    ] {
+        let schedule = CronSchedule::parse(expression).unwrap();
+        let mut after = after;
+        for expected in expected {
+            after = schedule.next_after(after).unwrap();
+            assert_eq!(after.to_string(), *expected, "{expression}");
+        }
+    }
+}
+
+#[test]
+fn seconds_field() {
+    let schedule = CronSchedule::parse("*/20 * * * * *").unwrap();
+    let mut after = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    for expected in [
+        "2024-01-01 00:00:20.000",
+        "2024-01-01 00:00:40.000",
+        "2024-01-01 00:01:00.000",
+    ] {
+        after = schedule.next_after(after).unwrap();
+        assert_eq!(after.to_string(), expected);
+    }
+
+    let schedule = CronSchedule::parse("30 59 23 31 12 *").unwrap();
+    assert_eq!(
+        schedule.next_after(Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0)),
+        Some(Utc::from_ymd_hms(2024, 12, 31, 23, 59, 30))
+    );
+}
+
+#[test]
+fn names() {
+    let new_year = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    for (expression, expected) in [
+        (
+            "0 12 * jan,JUL Mon",
+            &[
+                "2024-01-01",
+                "2024-01-08",
+                "2024-01-15",
+                "2024-01-22",
+                "2024-01-29",
+                "2024-07-01",
+            ][..],
+        ),
+        ("0 0 * * 7", &["2024-01-07", "2024-01-14"]),
+        ("0 0 * * SUN", &["2024-01-07", "2024-01-14"]),
+        ("0 0 * * 0", &["2024-01-07"]),
+    ] {
+        let schedule = CronSchedule::parse(expression).unwrap();
+        let mut after = new_year;
+        for expected in expected {
+            after = schedule.next_after(after).unwrap();
+            assert_eq!(after.date().to_string(), *expected, "{expression}");
+        }
+    }
+}
+
+#[test]
+fn day_and_week_day() {
+    let new_year = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    for (expression, expected) in [
+        // Both restricted, either matches
+        (
+            "0 0 13 * FRI",
+            &["2024-01-05", "2024-01-12", "2024-01-13", "2024-01-19"][..],
+        ),
+        // Only one restricted
+        ("0 0 * * FRI", &["2024-01-05", "2024-01-12"]),
+        ("0 0 ? * FRI", &["2024-01-05", "2024-01-12"]),
+        ("0 0 13 * ?", &["2024-01-13", "2024-02-13"]),
+    ] {
+        let schedule = CronSchedule::parse(expression).unwrap();
+        let mut after = new_year;
+        for expected in expected {
+            after = schedule.next_after(after).unwrap();
+            assert_eq!(after.date().to_string(), *expected, "{expression}");
+        }
+    }
+}
+
+#[test]
+fn last_and_nearest_weekday() {
+    for (expression, after, expected) in [
+        (
+            "0 0 L * *",
+            Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0),
+            &["2024-01-31", "2024-02-29", "2024-03-31"][..],
+        ),
+        (
+            "0 0 LW * *",
+            Utc::from_ymd_hms(2024, 3, 1, 0, 0, 0),
+            &[
+                "2024-03-29",
+                "2024-04-30",
+                "2024-05-31",
+                "2024-06-28",
+                "2024-07-31",
+                "2024-08-30",
+            ],
+        ),
+        (
+            "0 0 15W * *",
+            Utc::from_ymd_hms(2024, 6, 1, 0, 0, 0),
+            &["2024-06-14", "2024-07-15", "2024-08-15", "2024-09-16"],
+        ),
+        // Never moves into another month
+        (
+            "0 0 1W * *",
+            Utc::from_ymd_hms(2024, 5, 31, 0, 0, 0),
+            &["2024-06-03"],
+        ),
+        (
+            "0 0 31W * *",
+            Utc::from_ymd_hms(2024, 3, 1, 0, 0, 0),
+            &["2024-03-29"],
+        ),
+    ] {
+        let schedule = CronSchedule::parse(expression).unwrap();
+        let mut after = after;
+        for expected in expected {
+            after = schedule.next_after(after).unwrap();
+            assert_eq!(after.date().to_string(), *expected, "{expression}");
+        }
+    }
+}
+
+#[test]
+fn nth_and_last_week_day() {
+    let new_year = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    for (expression, expected) in [
+        ("0 0 * * FRI#3", ["2024-01-19", "2024-02-16", "2024-03-15"]),
+        ("0 0 * * 5L", ["2024-01-26", "2024-02-23", "2024-03-29"]),
+        ("0 0 * * 1#5", ["2024-01-29", "2024-04-29", "2024-07-29"]),
+    ] {
+        let schedule = CronSchedule::parse(expression).unwrap();
+        let mut after = new_year;
+        for expected in expected {
+            after = schedule.next_after(after).unwrap();
+            assert_eq!(after.date().to_string(), expected, "{expression}");
+        }
+    }
+}
+
+#[test]
+fn next_and_previous() {
+    let schedule = CronSchedule::parse("0 0 1 1 *").unwrap();
+    let firing = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+
+    assert_eq!(
+        schedule.next_after(firing),
+        Some(Utc::from_ymd_hms(2025, 1, 1, 0, 0, 0))
+    );
+    assert_eq!(
+        schedule.prev_before(firing),
+        Some(Utc::from_ymd_hms(2023, 1, 1, 0, 0, 0))
+    );
+    let just_after = Utc::from_timestamp_parts(firing.unix_timestamp_secs(), 500_000_000);
+    assert_eq!(schedule.prev_before(just_after), Some(firing));
+    assert_eq!(
+        schedule.prev_before(Utc::from_ymd_hms(2024, 6, 1, 0, 0, 0)),
+        Some(firing)
+    );
+
+    let schedule = CronSchedule::parse("15 30 10 * * *").unwrap();
+    assert_eq!(
+        schedule.prev_before(Utc::from_ymd_hms(2024, 1, 2, 10, 30, 15)),
+        Some(Utc::from_ymd_hms(2024, 1, 1, 10, 30, 15))
+    );
+    assert_eq!(
+        schedule.prev_before(Utc::from_ymd_hms(2024, 1, 2, 10, 30, 16)),
+        Some(Utc::from_ymd_hms(2024, 1, 2, 10, 30, 15))
+    );
+}
+
+#[test]
+fn never_firing() {
+    let new_year = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let schedule = CronSchedule::parse("0 0 30 2 *").unwrap();
+    assert_eq!(schedule.next_after(new_year), None);
+    assert_eq!(schedule.prev_before(new_year), None);
+    assert_eq!(schedule.upcoming(new_year).next(), None);
+
+    let schedule = CronSchedule::parse("0 0 1 1 *").unwrap();
+    assert_eq!(
+        schedule.next_after(Utc::from_ymd_hms(9999, 6, 1, 0, 0, 0)),
+        None
+    );
+}
+
+#[test]
+fn evaluated_in_offset() {
+    let new_year = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let mut schedule = CronSchedule::parse("0 9 * * *").unwrap();
+    assert_eq!(schedule.with_utc_offset(-5.0), Ok(()));
+    assert_eq!(schedule.get_utc_offset(), -5.0);
+    for offset in [24.0, -24.0, 1e12, f64::NAN] {
+        assert!(matches!(
+            schedule.with_utc_offset(offset),
+            Err(HoraeError::InvalidUtcOffset(_))
+        ));
+    }
+    assert_eq!(schedule.get_utc_offset(), -5.0);
+
+    let next = schedule.next_after(new_year).unwrap();
+    assert_eq!(next, Utc::from_ymd_hms(2024, 1, 1, 14, 0, 0));
+    assert_eq!(next.get_utc_offset(), -5.0);
+    assert_eq!(next.time().hour(), 9);
+
+    let previous = schedule.prev_before(new_year).unwrap();
+    assert_eq!(previous, Utc::from_ymd_hms(2023, 12, 31, 14, 0, 0));
+
+    schedule.with_timezone(TimeZone::JapanStandardTime);
+    assert_eq!(
+        schedule.next_after(new_year),
+        Some(Utc::from_ymd_hms(2024, 1, 2, 0, 0, 0))
+    );
+}
+
+#[test]
+fn display() {
+    let schedule = CronSchedule::parse("  0  9 * *\tmon ").unwrap();
+    assert_eq!(schedule.to_string(), "0 9 * * mon");
+}
+
+#[test]
+fn parse_errors() {
+    let error = |expression: &str| CronSchedule::parse(expression).unwrap_err();
+
+    let e = error("* * * *");
+    assert_eq!(e.component(), ParseComponent::Field);
+    assert_eq!(e.position(), 7);
+    assert_eq!(error("* * * * * * *").position(), 12);
+
+    for (expression, component, position) in [
+        ("60 * * * *", ParseComponent::Minute, 0),
+        ("* 1,24 * * *", ParseComponent::Hour, 4),
+        ("* * 32 * *", ParseComponent::Day, 4),
+        ("* * 0 * *", ParseComponent::Day, 4),
+        ("* * * 13 *", ParseComponent::Month, 6),
+        ("* * * FOO *", ParseComponent::Month, 6),
+        ("* * * * 8", ParseComponent::WeekDay, 8),
+        ("*/0 * * * *", ParseComponent::Minute, 2),
+        ("5-1 * * * *", ParseComponent::Minute, 2),
+        ("* * * * FRI#6", ParseComponent::WeekDay, 12),
+        ("* * * * L", ParseComponent::WeekDay, 8),
+        ("* ? * * *", ParseComponent::Hour, 2),
+        ("60 * * * * *", ParseComponent::Second, 0),
+    ] {
+        let e = error(expression);
+        assert_eq!(
+            (e.component(), e.position()),
+            (component, position),
+            "{expression}"
+        );
+    }
+
+    assert!(matches!(
+        "* * * * MON-".parse::<CronSchedule>(),
+        Err(HoraeError::Parse(_))
+    ));
+}
@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+
+use horae::{Date, HoraeError, MonthOverflow, Time, Utc, Weekday};
+
+#[test]
+fn from_ymd_validates() {
+    let date = Date::from_ymd(2024, 2, 29).unwrap();
+    assert_eq!((date.year(), date.month(), date.day()), (2024, 2, 29));
+    assert_eq!(Date::from_ymd(0, 1, 1), Err(HoraeError::InvalidYear(0)));
+    assert_eq!(
+        Date::from_ymd(2024, 13, 1),
+        Err(HoraeError::InvalidMonth(13))
+    );
+    assert_eq!(
+        Date::from_ymd(2024, 4, 31),
+        Err(HoraeError::InvalidDay {
+            year: 2024,
+            month: 4,
+            day: 31
+        })
+    );
+    assert!(Date::from_ymd(9999, 12, 31).is_ok());
+}
+
+#[test]
+fn date_ordering_and_hash() {
+    let a = Date::from_ymd(2023, 12, 31).unwrap();
+    let b = Date::from_ymd(2024, 1, 1).unwrap();
+    let c = Date::from_ymd(2024, 1, 2).unwrap();
+    assert!(a < b && b < c);
+    assert_eq!(a.max(c), c);
+
+    let mut dates = vec![c, a, b];
+    dates.sort();
+    assert_eq!(dates, [a, b, c]);
+
+    let set: HashSet<Date> = [a, b, b, c].into_iter().collect();
+    assert_eq!(set.len(), 3);
+    assert_eq!(Utc::from_ymd_hms(2024, 1, 1, 23, 59, 59).date(), b);
+}
+
+#[test]
+fn date_add_days_and_weeks() {
+    let date = Date::from_ymd(2023, 12, 31).unwrap();
+    assert_eq!(date.add_days(1).unwrap().to_string(), "2024-01-01");
+    assert_eq!(date.add_days(-365).unwrap().to_string(), "2022-12-31");
+    assert_eq!(date.add_weeks(9).unwrap().to_string(), "2024-03-03");
+    assert_eq!(date.add_days(0).unwrap(), date);
+
+    let last = Date::from_ymd(9999, 12, 31).unwrap();
+    assert_eq!(last.add_days(1), Err(HoraeError::OutOfRange));
+    let first = Date::from_ymd(1, 1, 1).unwrap();
+    assert_eq!(first.add_days(-1), Err(HoraeError::OutOfRange));
+    assert_eq!(first.add_days(i64::MAX), Err(HoraeError::OutOfRange));
+    assert_eq!(first.add_weeks(i64::MIN), Err(HoraeError::OutOfRange));
+}
+
+#[test]
+fn date_add_months_and_years() {
+    let date = Date::from_ymd(2023, 3, 31).unwrap();
+    assert_eq!(
+        date.add_months(-1, MonthOverflow::Clamp)
+            .unwrap()
+            .to_string(),
+        "2023-02-28"
+    );
+    assert_eq!(
+        date.add_months(-1, MonthOverflow::Rollover)
+            .unwrap()
+            .to_string(),
+        "2023-03-03"
+    );
+    assert_eq!(
+        date.add_months(1, MonthOverflow::Fail),
+        Err(HoraeError::InvalidDay {
+            year: 2023,
+            month: 4,
+            day: 31
+        })
+    );
+    assert_eq!(
+        date.add_months(10, MonthOverflow::Fail)
+            .unwrap()
+            .to_string(),
+        "2024-01-31"
+    );
+
+    let leap = Date::from_ymd(2000, 2, 29).unwrap();
+    assert_eq!(
+        leap.add_years(100, MonthOverflow::Clamp)
+            .unwrap()
+            .to_string(),
+        "2100-02-28"
+    );
+    assert_eq!(
+        leap.add_years(400, MonthOverflow::Fail)
+            .unwrap()
+            .to_string(),
+        "2400-02-29"
+    );
+    assert_eq!(
+        leap.add_years(8000, MonthOverflow::Clamp),
+        Err(HoraeError::OutOfRange)
+    );
+}
+
+#[test]
+fn date_difference() {
+    let a = Date::from_ymd(2024, 1, 1).unwrap();
+    let b = Date::from_ymd(2025, 1, 1).unwrap();
+    assert_eq!(b - a, 366);
+    assert_eq!(a - b, -366);
+    assert_eq!(a - a, 0);
+    assert_eq!(
+        Date::from_ymd(9999, 12, 31).unwrap() - Date::from_ymd(1, 1, 1).unwrap(),
+        3_652_058
+    );
+}
+
+#[test]
+fn date_accessors() {
+    let date = Date::from_ymd(2021, 1, 3).unwrap();
+    assert_eq!(date.weekday(), Weekday::Sunday);
+    assert_eq!(date.ordinal_day(), 3);
+    assert_eq!(date.get_weeknumber(), 53);
+
+    let date = Date::from_ymd(2023, 12, 31).unwrap();
+    assert_eq!(date.ordinal_day(), 365);
+    assert_eq!(date.get_weeknumber(), 52);
+    assert_eq!(date.format("%wdd %dd.%mm.%yyyy"), "Sunday 31.12.2023");
+}
+
+#[test]
+fn date_at_time() {
+    let date = Date::from_ymd(1969, 12, 31).unwrap();
+    let utc = date.at_time(Time::from_hms_nano(23, 59, 59, 500_000_000).unwrap());
+    assert_eq!(utc.unix_timestamp_nanos(), -500_000_000);
+    assert_eq!(utc.date(), date);
+    assert_eq!(utc.get_utc_offset(), 0.0);
+}
+
+#[test]
+fn date_in_offset() {
+    let date = Date::from_ymd(2024, 1, 1).unwrap();
+    let local = date
+        .in_offset(Time::from_hms(0, 30, 0).unwrap(), 1.0)
+        .unwrap();
+    assert_eq!(local.date(), date);
+    assert_eq!(local.to_rfc3339(), "2024-01-01T00:30:00+01:00");
+    assert_eq!(local, Utc::from_ymd_hms(2023, 12, 31, 23, 30, 0));
+    assert_eq!(local, Utc::from_ymd_hms_offset(2024, 1, 1, 0, 30, 0, 1.0));
+    assert_eq!(
+        date.in_offset(Time::from_hms(0, 0, 0).unwrap(), 24.0),
+        Err(HoraeError::InvalidUtcOffset(24.0))
+    );
+    assert!(
+        date.in_offset(Time::from_hms(0, 0, 0).unwrap(), f64::NAN)
+            .is_err()
+    );
+}
@@ -8,7 +8,7 @@ const SECONDS_IN_YEAR: f64 = 31_536_000.0;
 #[test]
 fn add_to_datetime_no_rollover() {
     let utc_now =
-        Utc::from_ymd_hms_timezone(2020, 03, 02, 12, 0, 0, TimeZone::CoordinatedUniversalTime);
+        Utc::from_ymd_hms_timezone(2020, 3, 2, 12, 0, 0, TimeZone::CoordinatedUniversalTime);
 
     let duration_second = std::time::Duration::from_secs(1);
     let now_plus_second = utc_now + duration_second;
@@ -35,7 +35,7 @@ fn add_to_datetime_no_rollover() {
     assert_eq!("2021-03-02 12:00:00.000", now_plus_year.to_string());
 
     let utc_add =
-        Utc::from_ymd_hms_timezone(2019, 02, 12, 12, 1, 1, TimeZone::CoordinatedUniversalTime);
+        Utc::from_ymd_hms_timezone(2019, 2, 12, 12, 1, 1, TimeZone::CoordinatedUniversalTime);
     let duration_year = std::time::Duration::from_secs(SECONDS_IN_YEAR.trunc() as u64);
     let now_plus_year = utc_add + duration_year;
     assert_eq!("2020-02-12 12:01:01.000", now_plus_year.to_string());
@@ -44,7 +44,7 @@ fn add_to_datetime_no_rollover() {
 #[test]
 fn sub_from_datetime_no_rollover() {
     let utc_now =
-        Utc::from_ymd_hms_timezone(2020, 02, 02, 12, 1, 1, TimeZone::CoordinatedUniversalTime);
+        Utc::from_ymd_hms_timezone(2020, 2, 2, 12, 1, 1, TimeZone::CoordinatedUniversalTime);
 
     let duration_second = std::time::Duration::from_secs(1);
     let now_minus_second = utc_now - duration_second;
@@ -71,13 +71,13 @@ fn sub_from_datetime_no_rollover() {
     assert_eq!("2019-02-02 12:01:01.000", now_minus_year.to_string());
 
     let utc_sub =
-        Utc::from_ymd_hms_timezone(2020, 02, 12, 12, 1, 1, TimeZone::CoordinatedUniversalTime);
+        Utc::from_ymd_hms_timezone(2020, 2, 12, 12, 1, 1, TimeZone::CoordinatedUniversalTime);
     let duration_year = std::time::Duration::from_secs(SECONDS_IN_YEAR.trunc() as u64);
     let now_minus_year = utc_sub - duration_year;
     assert_eq!("2019-02-12 12:01:01.000", now_minus_year.to_string());
 
     let utc_now =
-        Utc::from_ymd_hms_timezone(2020, 02, 02, 12, 1, 1, TimeZone::CoordinatedUniversalTime);
+        Utc::from_ymd_hms_timezone(2020, 2, 2, 12, 1, 1, TimeZone::CoordinatedUniversalTime);
     let duration_second = std::time::Duration::from_secs(1);
     let now_minus_second = utc_now - duration_second;
     assert_eq!("2020-02-02 12:01:00.000", now_minus_second.to_string());
@@ -86,7 +86,7 @@ fn sub_from_datetime_no_rollover() {
 #[test]
 fn sub_from_datetime_rollover_hms() {
     let utc_now =
-        Utc::from_ymd_hms_timezone(2020, 02, 02, 12, 1, 1, TimeZone::CoordinatedUniversalTime);
+        Utc::from_ymd_hms_timezone(2020, 2, 2, 12, 1, 1, TimeZone::CoordinatedUniversalTime);
 
     let duration_second = std::time::Duration::from_secs(2);
     let now_minus_second = utc_now - duration_second;
@@ -117,7 +117,7 @@ fn sub_from_datetime_rollover_hms() {
 #[test]
 fn add_to_datetime_rollover_hms() {
     let utc_now =
-        Utc::from_ymd_hms_timezone(2020, 02, 02, 22, 59, 59, TimeZone::CoordinatedUniversalTime);
+        Utc::from_ymd_hms_timezone(2020, 2, 2, 22, 59, 59, TimeZone::CoordinatedUniversalTime);
 
     let duration_second = std::time::Duration::from_secs(2);
     let now_plus_second = utc_now + duration_second;
@@ -147,7 +147,7 @@ fn add_to_datetime_rollover_hms() {
 #[test]
 fn on_leap_day_plus() {
     let leap_day =
-        Utc::from_ymd_hms_timezone(2020, 02, 29, 12, 1, 1, TimeZone::CoordinatedUniversalTime);
+        Utc::from_ymd_hms_timezone(2020, 2, 29, 12, 1, 1, TimeZone::CoordinatedUniversalTime);
 
     let leap_day_plus_second = leap_day + std::time::Duration::from_secs(1);
     assert_eq!("2020-02-29 12:01:02.000", leap_day_plus_second.to_string());
@@ -162,7 +162,7 @@ fn on_leap_day_plus() {
 #[test]
 fn on_leap_day_minus() {
     let leap_day =
-        Utc::from_ymd_hms_timezone(2020, 02, 29, 12, 1, 1, TimeZone::CoordinatedUniversalTime);
+        Utc::from_ymd_hms_timezone(2020, 2, 29, 12, 1, 1, TimeZone::CoordinatedUniversalTime);
 
     let leap_day_minus_second = leap_day - std::time::Duration::from_secs(1);
     assert_eq!("2020-02-29 12:01:00.000", leap_day_minus_second.to_string());
@@ -177,7 +177,7 @@ fn on_leap_day_minus() {
 #[test]
 fn rolling_into_leap_day() {
     let before_leap_day =
-        Utc::from_ymd_hms_timezone(2020, 02, 28, 23, 59, 59, TimeZone::CoordinatedUniversalTime);
+        Utc::from_ymd_hms_timezone(2020, 2, 28, 23, 59, 59, TimeZone::CoordinatedUniversalTime);
 
     let leap_day_sec = before_leap_day + std::time::Duration::from_secs(1);
     assert_eq!("2020-02-29 00:00:00.000", leap_day_sec.to_string());
@@ -192,12 +192,12 @@ fn rolling_into_leap_day() {
     assert_eq!("2020-02-29 23:59:59.000", leap_day_day.to_string());
 
     let month_before_leap_day =
-        Utc::from_ymd_hms_timezone(2020, 01, 29, 23, 59, 59, TimeZone::CoordinatedUniversalTime);
+        Utc::from_ymd_hms_timezone(2020, 1, 29, 23, 59, 59, TimeZone::CoordinatedUniversalTime);
     let leap_day_month = month_before_leap_day + std::time::Duration::from_secs(60 * 60 * 24 * 31);
     assert_eq!("2020-02-29 23:59:59.000", leap_day_month.to_string());
 
     let last_leap_day =
-        Utc::from_ymd_hms_timezone(2016, 02, 29, 23, 59, 59, TimeZone::CoordinatedUniversalTime);
+        Utc::from_ymd_hms_timezone(2016, 2, 29, 23, 59, 59, TimeZone::CoordinatedUniversalTime);
     let leap_day_year =
         last_leap_day + std::time::Duration::from_secs(60 * 60 * 24 * 365 * 4 + 60 * 60 * 24);
     assert_eq!("2020-02-29 23:59:59.000", leap_day_year.to_string());
@@ -206,7 +206,7 @@ fn rolling_into_leap_day() {
 #[test]
 fn rolling_from_leap_day() {
     let leap_day =
-        Utc::from_ymd_hms_timezone(2020, 02, 29, 23, 59, 59, TimeZone::CoordinatedUniversalTime);
+        Utc::from_ymd_hms_timezone(2020, 2, 29, 23, 59, 59, TimeZone::CoordinatedUniversalTime);
 
     // first rolling positive
     let leap_day_plus_second = leap_day + std::time::Duration::from_secs(1);
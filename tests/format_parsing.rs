@@ -0,0 +1,262 @@
+use horae::{Date, ParseComponent, Time, Utc};
+
+#[test]
+fn numeric() {
+    let utc = Utc::parse_from_format("31/12/2019 23:59:58", "%dd/%mm/%yyyy %HH:%MM:%SS").unwrap();
+    assert_eq!(utc, Utc::from_ymd_hms(2019, 12, 31, 23, 59, 58));
+
+    // Short directives read one or two digits
+    let utc = Utc::parse_from_format("1.2.2019 9:5:7", "%d.%m.%yyyy %H:%M:%S").unwrap();
+    assert_eq!(utc, Utc::from_ymd_hms(2019, 2, 1, 9, 5, 7));
+    let utc = Utc::parse_from_format("11.12.2019 19:15:17", "%d.%m.%yyyy %H:%M:%S").unwrap();
+    assert_eq!(utc, Utc::from_ymd_hms(2019, 12, 11, 19, 15, 17));
+
+    let utc =
+        Utc::parse_from_format("2019-01-01 09:09:09.250", "%yyyy-%mm-%dd %HH:%MM:%SS.%MS").unwrap();
+    assert_eq!(utc.subsec_nanos(), 250_000_000);
+
+    // Missing components
+    let utc = Utc::parse_from_format("2019", "%yyyy").unwrap();
+    assert_eq!(utc, Utc::from_ymd_hms(2019, 1, 1, 0, 0, 0));
+    let utc = Utc::parse_from_format("2019-032 12", "%yyyy-%ddd %HH").unwrap();
+    assert_eq!(utc, Utc::from_ymd_hms(2019, 2, 1, 12, 0, 0));
+    let utc = Utc::parse_from_format("2020-W53 Sun", "%GGGG-W%wnn %wd").unwrap();
+    assert_eq!(utc, Utc::from_ymd_hms(2021, 1, 3, 0, 0, 0));
+}
+
+#[test]
+fn names() {
+    for input in [
+        "Tuesday, 01 January 2019",
+        "tuesday, 01 JANUARY 2019",
+        "Tue, 01 Jan 2019",
+    ] {
+        assert_eq!(
+            Date::parse_from_format(input, "%wdd, %dd %mmmm %yyyy"),
+            Ok(Date::from_ymd(2019, 1, 1).unwrap()),
+            "{input}"
+        );
+    }
+    assert_eq!(
+        Date::parse_from_format("2019-Sep-30", "%yyyy-%mmm-%dd"),
+        Ok(Date::from_ymd(2019, 9, 30).unwrap())
+    );
+}
+
+#[test]
+fn two_digit_years() {
+    let year = |input: &str| Date::parse_from_format(input, "%yy").unwrap().year();
+    assert_eq!(year("69"), 1969);
+    assert_eq!(year("99"), 1999);
+    assert_eq!(year("00"), 2000);
+    assert_eq!(year("68"), 2068);
+
+    let year = |input: &str, pivot| {
+        Date::parse_from_format_with_pivot(input, "%yy", pivot)
+            .unwrap()
+            .year()
+    };
+    assert_eq!(year("49", 1950), 2049);
+    assert_eq!(year("50", 1950), 1950);
+    assert_eq!(year("00", 2000), 2000);
+    assert_eq!(year("99", 2000), 2099);
+    assert_eq!(year("00", 1), 100);
+
+    // The window cannot leave the supported years
+    let error = Date::parse_from_format_with_pivot("49", "%yy", 9950).unwrap_err();
+    assert_eq!(error.component(), ParseComponent::Year);
+}
+
+#[test]
+fn offsets() {
+    for (input, offset) in [
+        ("09:00 Coordinated Universal Time", 0.0),
+        ("09:00 UTC", 0.0),
+        ("09:00 Z", 0.0),
+        ("09:00 GMT+05:30", 5.5),
+        ("09:00 GMT-08:00", -8.0),
+        ("09:00 +0530", 5.5),
+        ("09:00 -02", -2.0),
+    ] {
+        let utc =
+            Utc::parse_from_format(&format!("2019-01-01 {input}"), "%yyyy-%mm-%dd %HH:%MM %tz")
+                .unwrap();
+        assert_eq!(utc.get_utc_offset(), offset, "{input}");
+        assert_eq!(utc.time().hour(), 9, "{input}");
+    }
+}
+
+#[test]
+fn round_trips() {
+    let mut utc = Utc::from_timestamp_parts(
+        Utc::from_ymd_hms(2024, 2, 29, 18, 7, 5).unix_timestamp_secs(),
+        0,
+    );
+    for offset in [0.0, 5.75, -3.5] {
+        utc.with_utc_offset(offset);
+        for format in [
+            "%yyyy-%mm-%dd %HH:%MM:%SS %tz",
+            "%wdd %d %mmmm %yyyy, %H:%M:%S %tz",
+            "%wd %dd %mmm %yy %HH%MM%SS.%MS %tz",
+            "%GGGG-W%wnn-%wd %ddd %HH:%MM:%SS %tz",
+        ] {
+            let text = utc.format(format);
+            assert_eq!(Utc::parse_from_format(&text, format), Ok(utc), "{text}");
+        }
+    }
+
+    let date = Date::from_ymd(2021, 1, 3).unwrap();
+    let format = "%dd.%mm.%yyyy (%wdd, week %wn of %GGGG)";
+    assert_eq!(
+        Date::parse_from_format(&date.format(format), format),
+        Ok(date)
+    );
+
+    let time = Time::from_hms(7, 8, 9).unwrap();
+    assert_eq!(
+        Time::parse_from_format(&time.format("%H:%MM:%SS"), "%H:%MM:%SS"),
+        Ok(time)
+    );
+}
+
+#[test]
+fn errors() {
+    use ParseComponent as C;
+
+    let format = "%wd, %dd %mmm %yyyy %HH:%MM %tz";
+    for (input, component, position) in [
+        ("Tue, 01 Jan 2019 09:09 UTC!", C::Literal, 26),
+        ("Tue 01 Jan 2019 09:09 UTC", C::Literal, 3),
+        ("Tux, 01 Jan 2019 09:09 UTC", C::WeekDay, 0),
+        ("Wed, 01 Jan 2019 09:09 UTC", C::WeekDay, 0),
+        ("Tue, 1 Jan 2019 09:09 UTC", C::Day, 5),
+        ("Tue, 32 Jan 2019 09:09 UTC", C::Day, 5),
+        ("Tue, 01 Jab 2019 09:09 UTC", C::Month, 8),
+        ("Tue, 01 Jan 19 09:09 UTC", C::Year, 12),
+        ("Tue, 01 Jan 2019 24:09 UTC", C::Hour, 17),
+        ("Tue, 01 Jan 2019 09:60 UTC", C::Minute, 20),
+        ("Tue, 01 Jan 2019 09:09 +25:00", C::Offset, 24),
+        ("Tue, 01 Jan 2019 09:09 CET", C::Offset, 23),
+    ] {
+        let error = Utc::parse_from_format(input, format).unwrap_err();
+        assert_eq!(
+            (error.component(), error.position()),
+            (component, position),
+            "{input}"
+        );
+    }
+
+    let error = Date::parse_from_format("12-31", "%mm-%dd").unwrap_err();
+    assert_eq!((error.component(), error.position()), (C::Year, 5));
+    // A year without an ISO year is read as the ISO year of the week
+    assert_eq!(
+        Date::parse_from_format("2019-W01", "%yyyy-W%wnn"),
+        Ok(Date::from_ymd(2018, 12, 31).unwrap())
+    );
+    let error = Date::parse_from_format("2019-13-01", "%yyyy-%mm-%dd").unwrap_err();
+    assert_eq!(error.component(), C::Month);
+    let error =
+        Date::parse_from_format("2019-01-01 (week 2)", "%yyyy-%mm-%dd (week %wn)").unwrap_err();
+    assert_eq!((error.component(), error.position()), (C::Week, 17));
+    let error = Date::parse_from_format("9", "%y").unwrap_err();
+    assert_eq!(error.component(), C::Year);
+    let error = Time::parse_from_format("09:61", "%HH:%MM").unwrap_err();
+    assert_eq!((error.component(), error.position()), (C::Minute, 3));
+}
+
+#[test]
+fn extended_directives() {
+    use ParseComponent as C;
+
+    let utc = Utc::from_ymd_hms(2019, 1, 2, 21, 5, 9);
+    for format in [
+        "%wdd, %mmmm %do %yyyy, %I:%MM:%SS %p",
+        "%_d.%_m.%yyyy %_I:%_M:%_S %P",
+        "%yyyy-%_ddd %_H:%MM:%SS",
+        "Q%q %yyyy-%mm-%dd %II:%MM:%SS %P",
+        "%ts",
+        "%tms",
+    ] {
+        let text = utc.format(format);
+        assert_eq!(Utc::parse_from_format(&text, format), Ok(utc), "{text}");
+    }
+
+    // A quarter without a month is read as its first month
+    assert_eq!(
+        Date::parse_from_format("Q3 2019", "Q%q %yyyy"),
+        Ok(Date::from_ymd(2019, 7, 1).unwrap())
+    );
+    assert_eq!(
+        Time::parse_from_format("12:30 am", "%I:%MM %p"),
+        Ok(Time::from_hms(0, 30, 0).unwrap())
+    );
+    // A timestamp is read in the UTC offset
+    let utc = Utc::parse_from_format("-1 +05:30", "%ts %tz").unwrap();
+    assert_eq!(utc.to_rfc3339(), "1970-01-01T05:29:59+05:30");
+    assert_eq!(
+        Date::parse_from_format("-1 +05:30", "%ts %tz"),
+        Ok(Date::from_ymd(1970, 1, 1).unwrap())
+    );
+    assert_eq!(
+        Time::parse_from_format("1546333749123", "%tms"),
+        Ok(Time::from_hms_nano(9, 9, 9, 123_000_000).unwrap())
+    );
+
+    for (input, format, component, position) in [
+        ("13:00 PM", "%II:%MM %P", C::Hour, 0),
+        ("00:00 AM", "%II:%MM %P", C::Hour, 0),
+        ("01:00 XM", "%II:%MM %P", C::Hour, 6),
+        ("32nd January 2019", "%do %mmmm %yyyy", C::Day, 0),
+        ("1nd January 2019", "%do %mmmm %yyyy", C::Day, 1),
+        ("Q5 2019", "Q%q %yyyy", C::Month, 1),
+        ("Q2 2019-01-01", "Q%q %yyyy-%mm-%dd", C::Month, 1),
+        ("2019-1-01", "%yyyy-%_m-%dd", C::Month, 5),
+        ("-", "%ts", C::Timestamp, 0),
+        ("253402300800", "%ts", C::Timestamp, 0),
+        ("1234567890123456", "%tms", C::Timestamp, 0),
+    ] {
+        let error = Utc::parse_from_format(input, format).unwrap_err();
+        assert_eq!(
+            (error.component(), error.position()),
+            (component, position),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn fractions() {
+    use ParseComponent as C;
+
+    let utc = Utc::from_timestamp_nanos(1_546_333_749_120_456_000);
+    for (format, nanos) in [
+        ("%HH:%MM:%SS.%MS", 120_000_000),
+        ("%HH:%MM:%SS.%US", 120_456_000),
+        ("%HH:%MM:%SS.%NS", 120_456_000),
+        ("%HH:%MM:%SS.%-MS", 120_000_000),
+        ("%HH:%MM:%SS.%-US", 120_456_000),
+        ("%HH:%MM:%SS.%-NS", 120_456_000),
+    ] {
+        let text = utc.format(format);
+        let time = Time::parse_from_format(&text, format).unwrap();
+        assert_eq!(time, Time::from_hms_nano(9, 9, 9, nanos).unwrap(), "{text}");
+    }
+    assert_eq!(
+        Time::parse_from_format("09:09:09.5", "%HH:%MM:%SS.%-NS"),
+        Ok(Time::from_hms_nano(9, 9, 9, 500_000_000).unwrap())
+    );
+
+    for (input, format, component, position) in [
+        ("09.12", "%SS.%MS", C::Fraction, 3),
+        ("09.1234", "%SS.%-MS", C::Literal, 6),
+        ("09.", "%SS.%-US", C::Fraction, 3),
+        ("09.12345678", "%SS.%NS", C::Fraction, 3),
+    ] {
+        let error = Time::parse_from_format(input, format).unwrap_err();
+        assert_eq!(
+            (error.component(), error.position()),
+            (component, position),
+            "{input}"
+        );
+    }
+}
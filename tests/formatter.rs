@@ -110,3 +110,177 @@ fn formatter_timezone() {
         }
     }
 }
+
+#[test]
+fn formatter_literals() {
+    let datetime = Utc::from_ymd_hms(1997, 1, 15, 7, 30, 59);
+    // Literal text may hold directive letters
+    assert_eq!("15 days, 7 hours", datetime.format("%dd days, %H hours"));
+    assert_eq!("Month: 01", datetime.format("Month: %mm"));
+    assert_eq!("mm/dd/yyyy", datetime.format("mm/dd/yyyy"));
+    // `%%` is a literal `%`, also right before a directive
+    assert_eq!("100% 15%", datetime.format("100%% %dd%%"));
+    assert_eq!("%15", datetime.format("%%%dd"));
+    assert_eq!("%dd", datetime.format("%%dd"));
+    // The longest directive wins, directives may be followed by letters
+    assert_eq!("015", datetime.format("%ddd"));
+    assert_eq!("07h30", datetime.format("%HHh%MM"));
+    assert_eq!("97", datetime.format("%yyy"));
+    assert_eq!("1997y", datetime.format("%yyyyy"));
+    // Unknown directives are written as is
+    assert_eq!("%k 1997 %", datetime.format("%k %yyyy %"));
+    assert_eq!("15.01.1997", datetime.date().format("%dd.%mm.%yyyy"));
+    assert_eq!("at 07:30", datetime.time().format("at %HH:%MM"));
+}
+
+#[test]
+fn compiled_format() {
+    use horae::{Date, Format, HoraeError, ParseComponent, Time};
+
+    let format = Format::new("%wdd, %dd %mmmm %yyyy %HH:%MM:%SS (100%%)").unwrap();
+    assert_eq!(format.as_str(), "%wdd, %dd %mmmm %yyyy %HH:%MM:%SS (100%%)");
+    assert_eq!(format.to_string(), format.as_str());
+    for (y, m, d) in [(1997, 1, 15), (2024, 2, 29), (9999, 12, 31)] {
+        let utc = Utc::from_ymd_hms(y, m, d, 7, 30, 59);
+        assert_eq!(
+            utc.format_with(&format),
+            utc.format(format.as_str()),
+            "{y}-{m}-{d}"
+        );
+    }
+    let mut utc = Utc::from_ymd_hms(1997, 1, 15, 7, 30, 59);
+    utc.with_timezone(TimeZone::CentralEuropeanTime);
+    assert_eq!(
+        utc.format_with(&Format::new("%HH:%MM %tz").unwrap()),
+        "08:30 GMT+01:00"
+    );
+
+    let date = Date::from_ymd(1997, 1, 15).unwrap();
+    assert_eq!(date.format_with(&"%ddd/%yy".parse().unwrap()), "015/97");
+    let time = Time::from_hms(7, 30, 59).unwrap();
+    assert_eq!(time.format_with(&"%H%%%MM".parse().unwrap()), "7%30");
+
+    for (format, position) in [("%k", 0), ("%yyyy-%x-%k", 6), ("%dd%", 3), ("%%%", 2)] {
+        let error = Format::new(format).unwrap_err();
+        assert_eq!(
+            (error.component(), error.position(), error.input()),
+            (ParseComponent::Directive, position, format),
+            "{format}"
+        );
+        assert!(matches!(
+            format.parse::<Format>(),
+            Err(HoraeError::Parse(_))
+        ));
+    }
+    // An unknown directive fails parsing with the position in the format
+    let error = Date::parse_from_format("1997-01-15", "%yyyy-%mm-%e").unwrap_err();
+    assert_eq!(
+        (error.component(), error.position()),
+        (ParseComponent::Directive, 10)
+    );
+    assert_eq!(
+        Date::parse_from_format("15% of 1997-01", "%dd%% of %yyyy-%mm"),
+        Ok(date)
+    );
+}
+
+#[test]
+fn formatter_extended() {
+    use horae::{Date, Time};
+
+    let datetime = Utc::from_ymd_hms(1997, 1, 2, 7, 3, 9);
+    assert_eq!(
+        "07:03:09 AM, 7:03 am,  7",
+        datetime.format("%II:%MM:%SS %P, %I:%MM %p, %_I")
+    );
+    assert_eq!(" 2. 1. 7: 3: 9", datetime.format("%_d.%_m.%_H:%_M:%_S"));
+    assert_eq!("  2,  1, 002", datetime.format("%_ddd, %_wn, %ddd"));
+    assert_eq!(
+        "Thursday, January 2nd 1997 (Q1)",
+        datetime.format("%wdd, %mmmm %do %yyyy (Q%q)")
+    );
+    assert_eq!("852188589 852188589000", datetime.format("%ts %tms"));
+
+    for (hour, expected) in [
+        (0, "12 AM"),
+        (1, "01 AM"),
+        (11, "11 AM"),
+        (12, "12 PM"),
+        (13, "01 PM"),
+        (23, "11 PM"),
+    ] {
+        let time = Time::from_hms(hour, 0, 0).unwrap();
+        assert_eq!(time.format("%II %P"), expected);
+        assert_eq!(
+            Utc::from_ymd_hms(2019, 1, 1, hour, 0, 0).format("%II %P"),
+            expected
+        );
+    }
+
+    for (day, suffix) in [
+        (1, "st"),
+        (2, "nd"),
+        (3, "rd"),
+        (4, "th"),
+        (11, "th"),
+        (12, "th"),
+        (13, "th"),
+        (21, "st"),
+        (22, "nd"),
+        (23, "rd"),
+        (30, "th"),
+        (31, "st"),
+    ] {
+        let date = Date::from_ymd(2019, 1, day).unwrap();
+        assert_eq!(date.format("%do"), format!("{day}{suffix}"));
+    }
+
+    for (month, quarter) in [
+        (1, "1"),
+        (3, "1"),
+        (4, "2"),
+        (6, "2"),
+        (7, "3"),
+        (10, "4"),
+        (12, "4"),
+    ] {
+        let date = Date::from_ymd(2019, month, 1).unwrap();
+        assert_eq!(date.format("%q"), quarter);
+    }
+
+    // Epoch directives do not depend on the UTC offset, dates are read at midnight UTC
+    let mut datetime = Utc::from_ymd_hms(1969, 12, 31, 23, 59, 59);
+    datetime.with_utc_offset(5.5);
+    assert_eq!("-1 -1000 05:29", datetime.format("%ts %tms %HH:%MM"));
+    let date = Date::from_ymd(1969, 12, 31).unwrap();
+    assert_eq!("-86400 -86400000", date.format("%ts %tms"));
+    assert_eq!(
+        "12:00 PM",
+        Time::from_hms(12, 0, 0).unwrap().format("%_I:%MM %P")
+    );
+}
+
+#[test]
+fn formatter_fractions() {
+    use horae::Time;
+
+    let utc = Utc::from_timestamp_nanos(1_546_333_749_123_456_789);
+    assert_eq!("09.123", utc.format("%SS.%MS"));
+    assert_eq!("123 123456 123456789", utc.format("%MS %US %NS"));
+    assert_eq!("123 123456 123456789", utc.format("%-MS %-US %-NS"));
+
+    for (nanos, fixed, trimmed) in [
+        (0, "000 000000 000000000", "0 0 0"),
+        (500_000_000, "500 500000 500000000", "5 5 5"),
+        (120_000, "000 000120 000120000", "0 00012 00012"),
+        (7_000_000, "007 007000 007000000", "007 007 007"),
+        (999_999_999, "999 999999 999999999", "999 999999 999999999"),
+    ] {
+        let time = Time::from_hms_nano(9, 9, 9, nanos).unwrap();
+        assert_eq!(time.format("%MS %US %NS"), fixed, "{nanos}");
+        assert_eq!(time.format("%-MS %-US %-NS"), trimmed, "{nanos}");
+        let utc = Utc::from_timestamp_nanos(1_546_333_749_000_000_000 + i128::from(nanos));
+        assert_eq!(utc.format("%MS %US %NS"), fixed, "{nanos}");
+        assert_eq!(utc.format("%-MS %-US %-NS"), trimmed, "{nanos}");
+    }
+}
@@ -0,0 +1,165 @@
+use horae::{HoraeError, TimeZone, Utc};
+
+#[test]
+fn constructors_before_epoch() {
+    let cases: Vec<(u16, u8, u8, u8, u8, u8, i64)> = vec![
+        (1969, 12, 31, 23, 59, 59, -1),
+        (1969, 7, 20, 20, 17, 40, -14_182_940),
+        (1900, 1, 1, 0, 0, 0, -2_208_988_800),
+        (1600, 2, 29, 12, 0, 0, -11_670_955_200),
+        (1, 1, 1, 0, 0, 0, -62_135_596_800),
+    ];
+    for (y, m, d, h, min, s, ts) in cases {
+        let utc = Utc::from_ymd_hms(y, m, d, h, min, s);
+        assert_eq!(utc.unix_timestamp_secs(), ts, "Failed for {y}-{m}-{d}");
+        assert_eq!(
+            utc.to_string(),
+            format!("{y:04}-{m:02}-{d:02} {h:02}:{min:02}:{s:02}.000")
+        );
+        let back = Utc::from_timestamp_parts(ts, 0);
+        assert_eq!(back, utc);
+        assert_eq!(back.to_string(), utc.to_string());
+    }
+}
+
+#[test]
+fn negative_timestamps() {
+    let utc = Utc::from_timestamp(-0.5);
+    assert_eq!(utc.to_string(), "1969-12-31 23:59:59.500");
+    assert_eq!(utc.unix_timestamp_secs(), -1);
+    assert_eq!(utc.subsec_nanos(), 500_000_000);
+    assert_eq!(utc.unix_timestamp_millis(), -500);
+    assert_eq!(utc.unix_timestamp_u64(), 0);
+
+    let earlier = Utc::from_ymd_hms(1970, 1, 1, 0, 0, 0) - std::time::Duration::from_secs(86_400);
+    assert_eq!(earlier.to_string(), "1969-12-31 00:00:00.000");
+}
+
+#[test]
+fn timestamps_outside_of_supported_years() {
+    let first = Utc::from_ymd_hms(1, 1, 1, 0, 0, 0);
+    let last = Utc::from_ymd_hms(9999, 12, 31, 23, 59, 59);
+    let first_secs = first.unix_timestamp_secs();
+    let last_secs = last.unix_timestamp_secs();
+
+    assert_eq!(Utc::try_from_timestamp_parts(first_secs, 0), Ok(first));
+    assert_eq!(
+        Utc::try_from_timestamp_parts(last_secs, 999_999_999),
+        Ok(Utc::from_timestamp_parts(last_secs, 999_999_999))
+    );
+    assert_eq!(
+        Utc::try_from_timestamp_parts(first_secs - 1, 0),
+        Err(HoraeError::OutOfRange)
+    );
+    assert_eq!(
+        Utc::try_from_timestamp_parts(last_secs, 1_000_000_000),
+        Err(HoraeError::OutOfRange)
+    );
+    assert_eq!(
+        Utc::try_from_timestamp_parts(i64::MAX, 1_000_000_000),
+        Err(HoraeError::OutOfRange)
+    );
+
+    assert_eq!(
+        Utc::try_from_timestamp_nanos(first.unix_timestamp_nanos()),
+        Ok(first)
+    );
+    assert_eq!(
+        Utc::try_from_timestamp_nanos(first.unix_timestamp_nanos() - 1),
+        Err(HoraeError::OutOfRange)
+    );
+    assert_eq!(
+        Utc::try_from_timestamp_nanos(i128::MIN),
+        Err(HoraeError::OutOfRange)
+    );
+
+    assert_eq!(Utc::try_from_timestamp(-0.5), Ok(Utc::from_timestamp(-0.5)));
+    for timestamp in [
+        -1e15,
+        1e15,
+        1e12,
+        f64::NAN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ] {
+        assert_eq!(
+            Utc::try_from_timestamp(timestamp),
+            Err(HoraeError::OutOfRange)
+        );
+    }
+
+    let far = Utc::from_timestamp(1e300);
+    assert_eq!(far.unix_timestamp_millis(), i64::MAX);
+    assert_eq!(far.format("%tms"), i64::MAX.to_string());
+    assert_eq!(
+        Utc::from_timestamp_parts(i64::MAX, 1_000_000_000).unix_timestamp_secs(),
+        i64::MAX
+    );
+}
+
+#[test]
+fn formatting_before_epoch() {
+    let utc = Utc::from_ymd_hms(1066, 10, 14, 9, 0, 0);
+    // Proleptic Gregorian, the Julian calendar of the time called this a Saturday
+    assert_eq!(utc.format("%yyyy-%mmm-%dd %wdd"), "1066-Oct-14 Sunday");
+    assert_eq!(utc.format("%yy %y"), "66 6");
+
+    let early = Utc::from_ymd_hms(5, 3, 1, 0, 0, 0);
+    assert_eq!(early.format("%yyyy/%yy/%y"), "0005/05/5");
+    assert_eq!(early.to_rfc3339(), "0005-03-01T00:00:00Z");
+
+    let mut local = Utc::from_ymd_hms(1969, 12, 31, 23, 0, 0);
+    local.with_timezone(TimeZone::CentralEuropeanTime);
+    assert_eq!(local.to_string(), "1970-01-01 00:00:00.000");
+    local.with_utc_offset(-5.0);
+    assert_eq!(local.to_string(), "1969-12-31 18:00:00.000");
+}
+
+#[test]
+fn weekdays_and_weeknumbers_before_epoch() {
+    let cases = vec![
+        ("Mon", 1, 1, 1, 1),
+        ("Fri", 1582, 10, 15, 41),
+        ("Mon", 1900, 1, 1, 1),
+        ("Sun", 1950, 1, 1, 52),
+        ("Sun", 1969, 7, 20, 29),
+        ("Wed", 1969, 12, 31, 1),
+    ];
+    for (wd, y, m, d, wn) in cases {
+        let utc = Utc::from_ymd_hms(y, m, d, 12, 0, 0);
+        assert_eq!(utc.format("%wd"), wd, "Failed for {y}-{m}-{d}");
+        assert_eq!(utc.date().format("%wd"), wd, "Failed for {y}-{m}-{d}");
+        assert_eq!(utc.get_weeknumber(), wn, "Failed for {y}-{m}-{d}");
+    }
+}
+
+#[test]
+fn rfc_before_epoch() {
+    let utc = Utc::from_rfc3339("1969-07-20T20:17:40Z").unwrap();
+    assert_eq!(utc.unix_timestamp_secs(), -14_182_940);
+
+    let utc = Utc::from_rfc3339("0001-01-01T00:00:00.000000001+01:00").unwrap();
+    assert_eq!(utc.to_rfc3339(), "0001-01-01T00:00:00.000000001+01:00");
+    assert_eq!(
+        utc.unix_timestamp_nanos(),
+        -62_135_600_400 * 1_000_000_000 + 1
+    );
+
+    let utc = Utc::from_rfc9557("1955-11-05T06:00:00-08:00[!-08:00]").unwrap();
+    assert_eq!(utc.to_rfc9557(), "1955-11-05T06:00:00-08:00[-08:00]");
+
+    assert_eq!(
+        Utc::from_rfc3339("0000-01-01T00:00:00Z"),
+        Err(HoraeError::InvalidYear(0))
+    );
+}
+
+#[test]
+fn system_time_before_epoch() {
+    use std::time::{Duration, UNIX_EPOCH};
+    let st = UNIX_EPOCH - Duration::new(14_182_940, 250);
+    let utc = Utc::from(st);
+    assert_eq!(utc.unix_timestamp_secs(), -14_182_941);
+    assert_eq!(utc.subsec_nanos(), 999_999_750);
+    assert_eq!(utc.to_string(), "1969-07-20 20:17:39.999");
+}
@@ -0,0 +1,295 @@
+use horae::{
+    BusinessCalendar, Date, HolidayRule, HolidaySet, HoraeError, Month, Observance, ParseComponent,
+    Weekday,
+};
+
+#[test]
+fn easter() {
+    let easter = HolidayRule::easter("Easter Sunday", 0);
+    for (year, month, day) in [
+        (1961, 4, 2),
+        (2000, 4, 23),
+        (2019, 4, 21),
+        (2024, 3, 31),
+        (2025, 4, 20),
+        (2038, 4, 25),
+        (2285, 3, 22),
+    ] {
+        assert_eq!(
+            easter.date_in(year),
+            Some(Date::from_ymd(year, month, day).unwrap()),
+            "{year}"
+        );
+    }
+    assert_eq!(
+        HolidayRule::easter("Good Friday", -2).date_in(2024),
+        Some(Date::from_ymd(2024, 3, 29).unwrap())
+    );
+    assert_eq!(
+        HolidayRule::easter("Whit Monday", 50).date_in(2024),
+        Some(Date::from_ymd(2024, 5, 20).unwrap())
+    );
+}
+
+#[test]
+fn orthodox_easter() {
+    let easter = HolidayRule::orthodox_easter("Pascha", 0);
+    for (year, month, day) in [
+        (2010, 4, 4),
+        (2021, 5, 2),
+        (2023, 4, 16),
+        (2024, 5, 5),
+        (2025, 4, 20),
+    ] {
+        assert_eq!(
+            easter.date_in(year),
+            Some(Date::from_ymd(year, month, day).unwrap()),
+            "{year}"
+        );
+    }
+    assert_eq!(
+        HolidayRule::orthodox_easter("Orthodox Good Friday", -2).date_in(2024),
+        Some(Date::from_ymd(2024, 5, 3).unwrap())
+    );
+}
+
+#[test]
+fn fixed_and_nth_week_days() {
+    assert_eq!(
+        HolidayRule::nth_week_day(
+            "Martin Luther King Jr. Day",
+            3,
+            Weekday::Monday,
+            Month::January
+        )
+        .date_in(2024),
+        Some(Date::from_ymd(2024, 1, 15).unwrap())
+    );
+    assert_eq!(
+        HolidayRule::nth_week_day("Memorial Day", -1, Weekday::Monday, Month::May).date_in(2024),
+        Some(Date::from_ymd(2024, 5, 27).unwrap())
+    );
+    assert_eq!(
+        HolidayRule::nth_week_day("Labor Day", 1, Weekday::Monday, Month::September).date_in(2024),
+        Some(Date::from_ymd(2024, 9, 2).unwrap())
+    );
+    assert_eq!(
+        HolidayRule::nth_week_day("", -2, Weekday::Sunday, Month::October).date_in(2024),
+        Some(Date::from_ymd(2024, 10, 20).unwrap())
+    );
+    // February 2024 has four Mondays but five Thursdays
+    assert_eq!(
+        HolidayRule::nth_week_day("", 5, Weekday::Monday, Month::February).date_in(2024),
+        None
+    );
+    assert_eq!(
+        HolidayRule::nth_week_day("", 5, Weekday::Thursday, Month::February).date_in(2024),
+        Some(Date::from_ymd(2024, 2, 29).unwrap())
+    );
+
+    let leap_day = HolidayRule::fixed("Leap Day", 2, 29);
+    assert_eq!(leap_day.date_in(2023), None);
+    assert_eq!(
+        leap_day.date_in(2024),
+        Some(Date::from_ymd(2024, 2, 29).unwrap())
+    );
+
+    // Rules that never fall, and years outside of the supported years
+    assert_eq!(HolidayRule::fixed("", 13, 1).date_in(2024), None);
+    assert_eq!(
+        HolidayRule::nth_week_day("", 0, Weekday::Monday, Month::January).date_in(2024),
+        None
+    );
+    assert_eq!(HolidayRule::fixed("", 1, 1).date_in(0), None);
+    assert_eq!(HolidayRule::easter("", 0).date_in(10000), None);
+}
+
+#[test]
+fn observance() {
+    let mut independence_day = HolidayRule::fixed("Independence Day", 7, 4);
+    assert_eq!(independence_day.observance(), Observance::Actual);
+    assert_eq!(
+        independence_day.observed_in(2026),
+        Some(Date::from_ymd(2026, 7, 4).unwrap())
+    );
+    independence_day.with_observance(Observance::NearestWeekday);
+    assert_eq!(
+        independence_day.observed_in(2026),
+        Some(Date::from_ymd(2026, 7, 3).unwrap())
+    );
+    independence_day.with_observance(Observance::FollowingMonday);
+    assert_eq!(
+        independence_day.observed_in(2026),
+        Some(Date::from_ymd(2026, 7, 6).unwrap())
+    );
+    // A weekday is not moved
+    assert_eq!(
+        independence_day.observed_in(2024),
+        Some(Date::from_ymd(2024, 7, 4).unwrap())
+    );
+
+    let mut christmas = HolidayRule::fixed("Christmas Day", 12, 25);
+    christmas.with_observance(Observance::NearestWeekday);
+    assert_eq!(
+        christmas.observed_in(2022),
+        Some(Date::from_ymd(2022, 12, 26).unwrap())
+    );
+
+    // Into the year before
+    let mut new_year = HolidayRule::fixed("New Year's Day", 1, 1);
+    new_year.with_observance(Observance::NearestWeekday);
+    assert_eq!(
+        new_year.observed_in(2022),
+        Some(Date::from_ymd(2021, 12, 31).unwrap())
+    );
+}
+
+#[test]
+fn rule_text() {
+    for (text, expected) in [
+        ("Christmas Day: 12-25", "Christmas Day: 12-25"),
+        (
+            "  Boxing Day :12-26 OBSERVED Monday",
+            "Boxing Day: 12-26 observed monday",
+        ),
+        (
+            "Thanksgiving: 4th thursday of november",
+            "Thanksgiving: 4th Thu of Nov",
+        ),
+        (
+            "Memorial Day: last Mon of May",
+            "Memorial Day: last Mon of May",
+        ),
+        (
+            "Summer time ends: 1st last sun of oct",
+            "Summer time ends: last Sun of Oct",
+        ),
+        (
+            "Fathers' Day: 2nd last Sun of Jun",
+            "Fathers' Day: 2nd last Sun of Jun",
+        ),
+        ("Easter Sunday: Easter", "Easter Sunday: easter"),
+        ("Ascension Day: easter +39", "Ascension Day: easter +39"),
+        (
+            "Good Friday: orthodox easter -2",
+            "Good Friday: orthodox easter -2",
+        ),
+        ("Leap Day: 2-29", "Leap Day: 02-29"),
+    ] {
+        let rule = HolidayRule::parse(text).unwrap();
+        assert_eq!(rule.to_string(), expected, "{text}");
+        assert_eq!(HolidayRule::parse(expected), Ok(rule));
+    }
+
+    let rule: HolidayRule = "Independence Day: 07-04 observed nearest".parse().unwrap();
+    assert_eq!(rule.name(), "Independence Day");
+    assert_eq!(rule.observance(), Observance::NearestWeekday);
+}
+
+#[test]
+fn sets() {
+    let text = "
+        # United States federal holidays
+        [US federal]
+        New Year's Day: 01-01 observed nearest
+        Martin Luther King Jr. Day: 3rd Mon of Jan
+
+        Memorial Day: last Mon of May
+        Juneteenth: 06-19 observed nearest
+        Independence Day: 07-04 observed nearest
+        Labor Day: 1st Mon of Sep
+        Thanksgiving: 4th Thu of Nov
+        Christmas Day: 12-25 observed nearest
+    ";
+    let set = HolidaySet::parse(text).unwrap();
+    assert_eq!(set.name(), "US federal");
+    assert_eq!(set.rules().len(), 8);
+
+    let holidays: Vec<String> = set
+        .holidays_in(2027)
+        .iter()
+        .map(|(date, name)| format!("{date} {name}"))
+        .collect();
+    assert_eq!(
+        holidays,
+        [
+            "2027-01-01 New Year's Day",
+            "2027-01-18 Martin Luther King Jr. Day",
+            "2027-05-31 Memorial Day",
+            "2027-06-18 Juneteenth",
+            "2027-07-05 Independence Day",
+            "2027-09-06 Labor Day",
+            "2027-11-25 Thanksgiving",
+            "2027-12-24 Christmas Day",
+        ]
+    );
+
+    // Round trips through its text
+    assert_eq!(HolidaySet::parse(&set.to_string()), Ok(set.clone()));
+
+    let mut calendar = BusinessCalendar::new();
+    calendar.add_holidays(set.dates_in(2027));
+    assert_eq!(
+        calendar.next_business_day(Date::from_ymd(2027, 7, 2).unwrap()),
+        Ok(Date::from_ymd(2027, 7, 6).unwrap())
+    );
+
+    let mut built = HolidaySet::new("Bank holidays");
+    built.add_rule(HolidayRule::fixed("Christmas Day", 12, 25));
+    built.add_rule(HolidayRule::fixed("Also Christmas Day", 12, 25));
+    assert_eq!(
+        built.dates_in(2024),
+        [Date::from_ymd(2024, 12, 25).unwrap()]
+    );
+    assert_eq!(
+        built.to_string(),
+        "[Bank holidays]\nChristmas Day: 12-25\nAlso Christmas Day: 12-25"
+    );
+}
+
+#[test]
+fn parse_errors() {
+    for (text, component, position) in [
+        ("Christmas Day 12-25", ParseComponent::Holiday, 0),
+        (": 12-25", ParseComponent::Holiday, 0),
+        ("Christmas Day: 13-25", ParseComponent::Month, 15),
+        ("Christmas Day: 02-30", ParseComponent::Day, 18),
+        ("Christmas Day:", ParseComponent::Holiday, 14),
+        ("Thanksgiving: 6th Thu of Nov", ParseComponent::Holiday, 14),
+        ("Thanksgiving: 4th Th of Nov", ParseComponent::WeekDay, 18),
+        ("Thanksgiving: 4th Thu in Nov", ParseComponent::Holiday, 22),
+        ("Thanksgiving: 4th Thu of Foo", ParseComponent::Month, 25),
+        ("Good Friday: easter -2000", ParseComponent::Day, 20),
+        ("Good Friday: orthodox -2", ParseComponent::Holiday, 22),
+        (
+            "Christmas: 12-25 observed sunday",
+            ParseComponent::Holiday,
+            26,
+        ),
+        ("Christmas: 12-25 nearest", ParseComponent::Holiday, 17),
+        (
+            "Christmas: 12-25 observed nearest monday",
+            ParseComponent::Holiday,
+            34,
+        ),
+    ] {
+        let error = HolidayRule::parse(text).unwrap_err();
+        assert_eq!(
+            (error.component(), error.position()),
+            (component, position),
+            "{text}"
+        );
+    }
+
+    // Positions are counted in the whole set
+    let error = HolidaySet::parse("[Set]\nA: 01-01\nB: 01-32").unwrap_err();
+    assert_eq!(error.component(), ParseComponent::Day);
+    assert_eq!(error.position(), 21);
+    let error = HolidaySet::parse("A: 01-01\n[Set]").unwrap_err();
+    assert_eq!(error.position(), 9);
+
+    assert!(matches!(
+        "Christmas".parse::<HolidaySet>(),
+        Err(HoraeError::Parse(_))
+    ));
+}
@@ -0,0 +1,157 @@
+use horae::{Iso8601Format, Iso8601Precision, ParseComponent, Utc};
+
+fn rfc3339(input: &str) -> (String, Iso8601Precision) {
+    let (utc, precision) = Utc::parse_iso8601(input).unwrap();
+    (utc.to_rfc3339(), precision)
+}
+
+#[test]
+fn dates() {
+    use Iso8601Precision as P;
+
+    for (input, expected, precision) in [
+        ("2019-03-15", "2019-03-15T00:00:00Z", P::Day),
+        ("20190315", "2019-03-15T00:00:00Z", P::Day),
+        ("2019-03", "2019-03-01T00:00:00Z", P::Month),
+        ("2019", "2019-01-01T00:00:00Z", P::Year),
+        ("2019-074", "2019-03-15T00:00:00Z", P::Day),
+        ("2019074", "2019-03-15T00:00:00Z", P::Day),
+        ("2019-W11-5", "2019-03-15T00:00:00Z", P::Day),
+        ("2019W115", "2019-03-15T00:00:00Z", P::Day),
+        ("2019-W11", "2019-03-11T00:00:00Z", P::Week),
+        ("2019W11", "2019-03-11T00:00:00Z", P::Week),
+        ("2020-W01", "2019-12-30T00:00:00Z", P::Week),
+    ] {
+        assert_eq!(rfc3339(input), (expected.to_string(), precision), "{input}");
+    }
+}
+
+#[test]
+fn times() {
+    use Iso8601Precision as P;
+
+    for (input, expected, precision) in [
+        ("2019-01-01T09:09:09Z", "2019-01-01T09:09:09Z", P::Second),
+        ("20190101T090909Z", "2019-01-01T09:09:09Z", P::Second),
+        ("2019-01-01T09:09Z", "2019-01-01T09:09:00Z", P::Minute),
+        ("20190101T0909", "2019-01-01T09:09:00Z", P::Minute),
+        ("2019-01-01T09", "2019-01-01T09:00:00Z", P::Hour),
+        ("2019-01-01t09z", "2019-01-01T09:00:00Z", P::Hour),
+        // Decimal fractions of the smallest component, with a dot or a comma
+        (
+            "2019-01-01T09:09:09,25",
+            "2019-01-01T09:09:09.25Z",
+            P::Second,
+        ),
+        (
+            "2019-01-01T09:09:09.123456789",
+            "2019-01-01T09:09:09.123456789Z",
+            P::Second,
+        ),
+        ("2019-01-01T09:30,5", "2019-01-01T09:30:30Z", P::Minute),
+        ("2019-01-01T0930.25", "2019-01-01T09:30:15Z", P::Minute),
+        ("2019-01-01T09,75", "2019-01-01T09:45:00Z", P::Hour),
+        ("2019-01-01T09.1", "2019-01-01T09:06:00Z", P::Hour),
+        // End of the day and leap seconds
+        ("2019-12-31T24:00", "2020-01-01T00:00:00Z", P::Minute),
+        ("2019-12-31T24", "2020-01-01T00:00:00Z", P::Hour),
+        ("20161231T235960Z", "2017-01-01T00:00:00Z", P::Second),
+        // Week and ordinal dates with times
+        ("2019-W01-2T12:00", "2019-01-01T12:00:00Z", P::Minute),
+        ("2019001T1200", "2019-01-01T12:00:00Z", P::Minute),
+    ] {
+        assert_eq!(rfc3339(input), (expected.to_string(), precision), "{input}");
+    }
+}
+
+#[test]
+fn offsets() {
+    for (input, expected, offset) in [
+        ("2019-01-01T09:09:09+02", "2019-01-01T09:09:09+02:00", 2.0),
+        (
+            "2019-01-01T09:09:09+05:30",
+            "2019-01-01T09:09:09+05:30",
+            5.5,
+        ),
+        ("20190101T090909+0530", "2019-01-01T09:09:09+05:30", 5.5),
+        (
+            "2019-01-01T09:09:09-0330",
+            "2019-01-01T09:09:09-03:30",
+            -3.5,
+        ),
+        ("2019-01-01T09-08", "2019-01-01T09:00:00-08:00", -8.0),
+        ("2019-01-01T09:09:09.5-00", "2019-01-01T09:09:09.5Z", 0.0),
+    ] {
+        let (utc, _) = Utc::parse_iso8601(input).unwrap();
+        assert_eq!(utc.to_rfc3339(), expected, "{input}");
+        assert_eq!(utc.get_utc_offset(), offset, "{input}");
+    }
+    let (utc, _) = Utc::parse_iso8601("2019-01-01T00:30+01").unwrap();
+    assert_eq!(utc, Utc::from_ymd_hms(2018, 12, 31, 23, 30, 0));
+}
+
+#[test]
+fn format() {
+    let mut utc = Utc::from_ymd_hms(2019, 1, 1, 9, 9, 9);
+    assert_eq!(utc.to_iso8601(Iso8601Format::Basic), "20190101T090909Z");
+    assert_eq!(
+        utc.to_iso8601(Iso8601Format::Extended),
+        "2019-01-01T09:09:09Z"
+    );
+    utc.with_utc_offset(-3.5);
+    assert_eq!(utc.to_iso8601(Iso8601Format::Basic), "20190101T053909-0330");
+    assert_eq!(utc.to_iso8601(Iso8601Format::Extended), utc.to_rfc3339());
+
+    let utc = Utc::from_timestamp_parts(utc.unix_timestamp_secs(), 120_000_000);
+    assert_eq!(utc.to_iso8601(Iso8601Format::Basic), "20190101T090909.12Z");
+
+    // Both formats round trip
+    for format in [Iso8601Format::Basic, Iso8601Format::Extended] {
+        let text = utc.to_iso8601(format);
+        let (parsed, precision) = Utc::parse_iso8601(&text).unwrap();
+        assert_eq!(parsed, utc, "{text}");
+        assert_eq!(precision, Iso8601Precision::Second);
+    }
+}
+
+#[test]
+fn parse_errors() {
+    use ParseComponent as C;
+
+    for (input, component, position) in [
+        ("19", C::Year, 0),
+        ("0000-01-01", C::Year, 0),
+        ("201901", C::Month, 4),
+        ("2019-1", C::Month, 5),
+        ("2019-13", C::Month, 5),
+        ("2019-02-30", C::Day, 8),
+        ("20190230", C::Day, 6),
+        ("2019-366", C::Day, 5),
+        ("2019-W53", C::Week, 6),
+        ("2019-W01-8", C::WeekDay, 9),
+        ("2019-01T09", C::DateSeparator, 7),
+        ("2019-W01T09", C::DateSeparator, 8),
+        ("2019-01-01 09:00", C::DateTimeSeparator, 10),
+        ("2019-01-01T9", C::Hour, 11),
+        ("2019-01-01T25", C::Hour, 11),
+        ("2019-01-01T24:01", C::Hour, 11),
+        ("2019-01-01T24:00:00,5", C::Hour, 11),
+        ("2019-01-01T09:60", C::Minute, 14),
+        ("2019-01-01T096", C::Minute, 13),
+        ("2019-01-01T09:09:61", C::Second, 17),
+        ("2019-01-01T09:09:09.", C::Fraction, 20),
+        ("2019-01-01T09:09:09+1", C::Offset, 20),
+        ("2019-01-01T09:09:09+24", C::Offset, 20),
+        ("2019-01-01T09:09:09+05:60", C::Offset, 23),
+        ("2019-01-01T09:09:09+05:30:00", C::Offset, 25),
+        ("2019-01-01T09:09:09x", C::Offset, 19),
+        ("9999-12-31T24:00", C::Hour, 11),
+    ] {
+        let error = Utc::parse_iso8601(input).unwrap_err();
+        assert_eq!(
+            (error.component(), error.position()),
+            (component, position),
+            "{input}"
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use horae::{HoraeError, IsoDuration, MonthOverflow, ParseComponent, SignedDuration, Utc};
+
+#[test]
+fn parse_iso_duration() {
+    let duration = IsoDuration::parse("P1Y2M3W4DT5H6M7.008S").unwrap();
+    assert_eq!(
+        duration,
+        IsoDuration {
+            negative: false,
+            years: 1,
+            months: 2,
+            weeks: 3,
+            days: 4,
+            hours: 5,
+            minutes: 6,
+            seconds: 7,
+            nanos: 8_000_000,
+        }
+    );
+    assert_eq!(
+        IsoDuration::parse("P2W").unwrap(),
+        IsoDuration {
+            weeks: 2,
+            ..IsoDuration::default()
+        }
+    );
+    assert_eq!(
+        IsoDuration::parse("PT36H").unwrap(),
+        IsoDuration {
+            hours: 36,
+            ..IsoDuration::default()
+        }
+    );
+    assert_eq!(
+        IsoDuration::parse("-P1D").unwrap(),
+        IsoDuration {
+            negative: true,
+            days: 1,
+            ..IsoDuration::default()
+        }
+    );
+    assert_eq!(
+        IsoDuration::parse("+P1D").unwrap(),
+        IsoDuration::parse("P1D").unwrap()
+    );
+    assert_eq!(
+        IsoDuration::parse("p1dt2h").unwrap(),
+        IsoDuration::parse("P1DT2H").unwrap()
+    );
+    assert_eq!(IsoDuration::parse("PT0,5S").unwrap().nanos, 500_000_000);
+    assert_eq!(
+        IsoDuration::parse("PT0.1234567891S").unwrap().nanos,
+        123_456_789
+    );
+    assert_eq!(IsoDuration::parse("P1M").unwrap().months, 1);
+    assert_eq!(IsoDuration::parse("PT1M").unwrap().minutes, 1);
+    assert!(IsoDuration::parse("P0D").unwrap().is_zero());
+    assert_eq!(
+        "PT4294967295S".parse::<IsoDuration>().unwrap().seconds,
+        u32::MAX
+    );
+}
+
+#[test]
+fn parse_iso_duration_errors() {
+    let cases = [
+        ("", ParseComponent::Designator, 0),
+        ("1D", ParseComponent::Designator, 0),
+        ("P", ParseComponent::Designator, 1),
+        ("PT", ParseComponent::Designator, 2),
+        ("P1DT", ParseComponent::Designator, 4),
+        ("P1", ParseComponent::Designator, 2),
+        ("P1H", ParseComponent::Designator, 2),
+        ("PT1D", ParseComponent::Designator, 3),
+        ("P1D1Y", ParseComponent::Designator, 4),
+        ("P1M1M", ParseComponent::Designator, 4),
+        ("PT1S1M", ParseComponent::Designator, 5),
+        ("PD", ParseComponent::Designator, 1),
+        ("P1.5D", ParseComponent::Fraction, 2),
+        ("PT1.5M", ParseComponent::Fraction, 3),
+        ("PT1.S", ParseComponent::Fraction, 4),
+        ("PT4294967296S", ParseComponent::Second, 2),
+        ("P99999999999999999999999Y", ParseComponent::Year, 1),
+        ("P1DT1H ", ParseComponent::Designator, 6),
+        ("P1Dä", ParseComponent::Designator, 3),
+    ];
+    for (input, component, position) in cases {
+        let error = IsoDuration::parse(input).unwrap_err();
+        assert_eq!(error.component(), component, "{input}");
+        assert_eq!(error.position(), position, "{input}");
+    }
+    assert!(matches!(
+        "P1H".parse::<IsoDuration>(),
+        Err(HoraeError::Parse(_))
+    ));
+}
+
+#[test]
+fn format_iso_duration() {
+    for input in [
+        "P1Y2M3W4DT5H6M7.008S",
+        "P2W",
+        "-P1D",
+        "PT36H",
+        "PT0.000000001S",
+        "P1YT1S",
+        "PT1M",
+        "P1M",
+    ] {
+        assert_eq!(IsoDuration::parse(input).unwrap().to_string(), input);
+    }
+    assert_eq!(IsoDuration::default().to_string(), "PT0S");
+    assert_eq!(IsoDuration::parse("P0Y0D").unwrap().to_string(), "PT0S");
+    assert_eq!(IsoDuration::parse("PT0.5S").unwrap().to_string(), "PT0.5S");
+    assert_eq!(
+        IsoDuration::parse("PT1.500S").unwrap().to_string(),
+        "PT1.5S"
+    );
+    assert_eq!((-IsoDuration::parse("PT1H").unwrap()).to_string(), "-PT1H");
+
+    let overflowing = IsoDuration {
+        seconds: 1,
+        nanos: 2_500_000_000,
+        ..IsoDuration::default()
+    };
+    assert_eq!(overflowing.to_string(), "PT3.5S");
+    let whole = IsoDuration {
+        nanos: 2_000_000_000,
+        ..IsoDuration::default()
+    };
+    assert_eq!(whole.to_string(), "PT2S");
+}
+
+#[test]
+fn iso_duration_to_signed_duration() {
+    assert_eq!(
+        IsoDuration::parse("P1W1DT1H1M1.5S")
+            .unwrap()
+            .to_signed_duration(),
+        Some(SignedDuration::from_millis(8 * 86_400_000 + 3_661_500))
+    );
+    assert_eq!(
+        IsoDuration::parse("-PT1.5S").unwrap().to_signed_duration(),
+        Some(SignedDuration::from_millis(-1_500))
+    );
+    assert_eq!(
+        IsoDuration::parse("P1Y").unwrap().to_signed_duration(),
+        None
+    );
+}
+
+#[test]
+fn add_iso_duration() {
+    let utc = Utc::from_ymd_hms(2024, 1, 31, 12, 0, 0);
+    let add = |s: &str, overflow| {
+        utc.add_iso_duration(&IsoDuration::parse(s).unwrap(), overflow)
+            .map(|utc| utc.to_string())
+    };
+    assert_eq!(
+        add("P1M", MonthOverflow::Clamp).unwrap(),
+        "2024-02-29 12:00:00.000"
+    );
+    assert_eq!(
+        add("P1M", MonthOverflow::Rollover).unwrap(),
+        "2024-03-02 12:00:00.000"
+    );
+    assert_eq!(
+        add("P1M", MonthOverflow::Fail),
+        Err(HoraeError::InvalidDay {
+            year: 2024,
+            month: 2,
+            day: 31
+        })
+    );
+    // Months are applied before days
+    assert_eq!(
+        add("P1M1D", MonthOverflow::Clamp).unwrap(),
+        "2024-03-01 12:00:00.000"
+    );
+    assert_eq!(
+        add("P1Y2W", MonthOverflow::Fail).unwrap(),
+        "2025-02-14 12:00:00.000"
+    );
+    assert_eq!(
+        add("PT12H0.25S", MonthOverflow::Fail).unwrap(),
+        "2024-02-01 00:00:00.250"
+    );
+    assert_eq!(
+        add("-P1M1DT12H", MonthOverflow::Fail).unwrap(),
+        "2023-12-30 00:00:00.000"
+    );
+    assert_eq!(
+        add("P8000Y", MonthOverflow::Fail),
+        Err(HoraeError::OutOfRange)
+    );
+    assert_eq!(
+        add("P3000000D", MonthOverflow::Fail),
+        Err(HoraeError::OutOfRange)
+    );
+}
+
+#[test]
+fn add_iso_duration_out_of_range() {
+    let last = Utc::from_ymd_hms(9999, 12, 31, 12, 0, 0);
+    let add = |s: &str| last.add_iso_duration(&IsoDuration::parse(s).unwrap(), MonthOverflow::Fail);
+    assert_eq!(add("PT11H").unwrap().to_string(), "9999-12-31 23:00:00.000");
+    assert_eq!(add("PT13H"), Err(HoraeError::OutOfRange));
+    assert_eq!(add("PT4294967295H"), Err(HoraeError::OutOfRange));
+
+    let first = Utc::from_ymd_hms(1, 1, 1, 12, 0, 0);
+    let sub =
+        |s: &str| first.add_iso_duration(&IsoDuration::parse(s).unwrap(), MonthOverflow::Fail);
+    assert_eq!(
+        sub("-PT12H").unwrap().to_string(),
+        "0001-01-01 00:00:00.000"
+    );
+    assert_eq!(sub("-PT12H0.5S"), Err(HoraeError::OutOfRange));
+}
+
+#[test]
+fn add_iso_duration_in_offset() {
+    // 23:00 UTC on March 31 is already April 1 in UTC+2
+    let mut utc = Utc::from_ymd_hms(2024, 3, 31, 23, 0, 0);
+    utc.with_utc_offset(2.0);
+    let later = utc
+        .add_iso_duration(&IsoDuration::parse("P1M").unwrap(), MonthOverflow::Fail)
+        .unwrap();
+    assert_eq!(later.to_rfc3339(), "2024-05-01T01:00:00+02:00");
+}
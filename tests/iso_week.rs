@@ -0,0 +1,174 @@
+use horae::{Date, HoraeError, IsoWeek, ParseComponent, Utc, Weekday};
+
+#[test]
+fn week_numbering_years() {
+    for ((year, month, day), iso_year, week) in [
+        ((2024, 12, 29), 2024, 52),
+        ((2024, 12, 30), 2025, 1),
+        ((2025, 12, 28), 2025, 52),
+        ((2025, 12, 29), 2026, 1),
+        ((2021, 1, 1), 2020, 53),
+        ((2021, 1, 3), 2020, 53),
+        ((2021, 1, 4), 2021, 1),
+        ((2027, 1, 1), 2026, 53),
+        ((2026, 3, 12), 2026, 11),
+        ((1, 1, 1), 1, 1),
+        ((9999, 12, 31), 9999, 52),
+    ] {
+        let date = Date::from_ymd(year, month, day).unwrap();
+        assert_eq!(
+            date.iso_week(),
+            IsoWeek::new(iso_year, week).unwrap(),
+            "{date}"
+        );
+        assert_eq!(date.get_weeknumber(), week, "{date}");
+    }
+
+    // The local date in the UTC offset
+    let mut utc = Utc::from_ymd_hms(2024, 12, 29, 23, 0, 0);
+    assert_eq!(utc.iso_week(), IsoWeek::new(2024, 52).unwrap());
+    utc.with_utc_offset(2.0);
+    assert_eq!(utc.iso_week(), IsoWeek::new(2025, 1).unwrap());
+}
+
+#[test]
+fn weeks_in_year() {
+    let long_years: Vec<u16> = (2000..=2040)
+        .filter(|year| IsoWeek::weeks_in_year(*year) == 53)
+        .collect();
+    assert_eq!(long_years, [2004, 2009, 2015, 2020, 2026, 2032, 2037]);
+
+    // Every year has exactly as many weeks as the week of December 28
+    for year in 1..=2400 {
+        assert_eq!(
+            IsoWeek::weeks_in_year(year),
+            Date::from_ymd(year, 12, 28).unwrap().get_weeknumber(),
+            "{year}"
+        );
+    }
+}
+
+#[test]
+fn first_and_last_days() {
+    let week = IsoWeek::new(2025, 1).unwrap();
+    assert_eq!(week.first_day(), Date::from_ymd(2024, 12, 30).unwrap());
+    assert_eq!(week.last_day(), Ok(Date::from_ymd(2025, 1, 5).unwrap()));
+    let week = IsoWeek::new(2020, 53).unwrap();
+    assert_eq!(week.first_day(), Date::from_ymd(2020, 12, 28).unwrap());
+    assert_eq!(week.last_day(), Ok(Date::from_ymd(2021, 1, 3).unwrap()));
+
+    assert_eq!(
+        IsoWeek::new(1, 1).unwrap().first_day(),
+        Date::from_ymd(1, 1, 1).unwrap()
+    );
+    // The last week of 9999 ends in 10000
+    let last = IsoWeek::new(9999, 52).unwrap();
+    assert_eq!(last.first_day(), Date::from_ymd(9999, 12, 27).unwrap());
+    assert_eq!(last.last_day(), Err(HoraeError::OutOfRange));
+
+    assert_eq!(
+        IsoWeek::new(2025, 53),
+        Err(HoraeError::InvalidWeek {
+            year: 2025,
+            week: 53
+        })
+    );
+    assert!(IsoWeek::new(2026, 0).is_err());
+    assert_eq!(IsoWeek::new(0, 1), Err(HoraeError::InvalidYear(0)));
+}
+
+#[test]
+fn from_iso_week() {
+    for weekday in Weekday::ALL {
+        let date = Date::from_iso_week(2025, 1, weekday).unwrap();
+        assert_eq!(date.weekday(), weekday);
+        assert_eq!(date.iso_week(), IsoWeek::new(2025, 1).unwrap());
+    }
+    assert_eq!(
+        Date::from_iso_week(2020, 53, Weekday::Sunday),
+        Ok(Date::from_ymd(2021, 1, 3).unwrap())
+    );
+    assert_eq!(
+        Date::from_iso_week(9999, 52, Weekday::Friday),
+        Ok(Date::from_ymd(9999, 12, 31).unwrap())
+    );
+    assert_eq!(
+        Date::from_iso_week(9999, 52, Weekday::Saturday),
+        Err(HoraeError::OutOfRange)
+    );
+
+    // Every date round trips through its week date
+    let mut day = Date::from_ymd(2019, 12, 1).unwrap();
+    while day < Date::from_ymd(2022, 2, 1).unwrap() {
+        let week = day.iso_week();
+        assert_eq!(
+            Date::from_iso_week(week.year(), week.week(), day.weekday()),
+            Ok(day)
+        );
+        assert_eq!(Date::parse_iso_week_date(&day.to_iso_week_date()), Ok(day));
+        day = day.add_days(1).unwrap();
+    }
+}
+
+#[test]
+fn parse_and_format() {
+    assert_eq!(
+        Date::parse_iso_week_date("2026-W11-4"),
+        Ok(Date::from_ymd(2026, 3, 12).unwrap())
+    );
+    assert_eq!(
+        Date::parse_iso_week_date("2026W114"),
+        Ok(Date::from_ymd(2026, 3, 12).unwrap())
+    );
+    assert_eq!(
+        Date::from_ymd(2021, 1, 3).unwrap().to_iso_week_date(),
+        "2020-W53-7"
+    );
+
+    let week: IsoWeek = "2020-W53".parse().unwrap();
+    assert_eq!(week, IsoWeek::new(2020, 53).unwrap());
+    assert_eq!(week.to_string(), "2020-W53");
+    assert_eq!(IsoWeek::parse("2020W53"), Ok(week));
+
+    let utc = Utc::from_ymd_hms(2021, 1, 1, 12, 0, 0);
+    assert_eq!(utc.format("%GGGG-W%wnn"), "2020-W53");
+    assert_eq!(utc.format("%GG/%yy"), "20/21");
+    assert_eq!(utc.date().format("%GGGG"), "2020");
+}
+
+#[test]
+fn parse_errors() {
+    for (text, component, position) in [
+        ("2026-W11", ParseComponent::DateSeparator, 8),
+        ("2026-W114", ParseComponent::DateSeparator, 8),
+        ("2026W11-4", ParseComponent::WeekDay, 7),
+        ("2026-11-4", ParseComponent::DateSeparator, 5),
+        ("2026-W1-4", ParseComponent::Week, 6),
+        ("2025-W53-1", ParseComponent::Week, 6),
+        ("2026-W00-1", ParseComponent::Week, 6),
+        ("2026-W11-0", ParseComponent::WeekDay, 9),
+        ("2026-W11-8", ParseComponent::WeekDay, 9),
+        ("2026-W11-45", ParseComponent::WeekDay, 10),
+        ("0000-W01-1", ParseComponent::Year, 0),
+        ("26-W11-4", ParseComponent::Year, 0),
+        ("9999-W52-6", ParseComponent::WeekDay, 9),
+    ] {
+        let error = Date::parse_iso_week_date(text).unwrap_err();
+        assert_eq!(
+            (error.component(), error.position()),
+            (component, position),
+            "{text}"
+        );
+    }
+    assert_eq!(
+        IsoWeek::parse("2026-W11-4").unwrap_err().component(),
+        ParseComponent::Week
+    );
+    assert_eq!(
+        "2025W53".parse::<IsoWeek>(),
+        Err(HoraeError::InvalidWeek {
+            year: 2025,
+            week: 53
+        })
+    );
+}
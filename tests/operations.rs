@@ -22,3 +22,85 @@ fn add_duration_utc() {
     let utc2 = utc + duration;
     assert!(utc2.unix_timestamp() > utc.unix_timestamp());
 }
+
+#[test]
+fn sub_utc_utc_negative() {
+    let earlier = horae::Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let later = earlier + std::time::Duration::from_millis(1_500);
+    let duration = earlier - later;
+    assert!(duration.is_negative());
+    assert_eq!(duration.as_secs(), -1);
+    assert_eq!(duration.subsec_nanos(), -500_000_000);
+    assert_eq!(duration.as_millis(), -1_500);
+    assert_eq!(-duration, later - earlier);
+    assert_eq!(duration.abs(), later - earlier);
+}
+
+#[test]
+fn add_sub_signed_duration() {
+    use horae::SignedDuration;
+
+    let utc = horae::Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let back = utc + SignedDuration::from_secs(-86_400);
+    assert_eq!(back.to_string(), "2023-12-31 00:00:00.000");
+    assert_eq!(
+        utc - SignedDuration::from_secs(-86_400),
+        utc + SignedDuration::from_secs(86_400)
+    );
+    assert_eq!(
+        (utc + SignedDuration::new(-1, -1)).unix_timestamp_nanos(),
+        utc.unix_timestamp_nanos() - 1_000_000_001
+    );
+
+    let mut local = utc;
+    local.with_utc_offset(2.0);
+    let moved = local + SignedDuration::from_secs(3_600);
+    assert_eq!(moved.get_utc_offset(), 2.0);
+    assert_eq!(moved.to_string(), "2024-01-01 03:00:00.000");
+}
+
+#[test]
+fn duration_helpers() {
+    use horae::SignedDuration;
+    use std::time::Duration;
+
+    let earlier = horae::Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let later = earlier + Duration::from_nanos(5);
+    assert_eq!(
+        later.checked_duration_since(earlier),
+        Some(Duration::from_nanos(5))
+    );
+    assert_eq!(earlier.checked_duration_since(later), None);
+    assert_eq!(
+        earlier.checked_duration_since(earlier),
+        Some(Duration::ZERO)
+    );
+    assert_eq!(earlier.abs_diff(later), Duration::from_nanos(5));
+    assert_eq!(later.abs_diff(earlier), Duration::from_nanos(5));
+
+    assert_eq!(
+        Duration::try_from(SignedDuration::from_secs(-1)),
+        Err(horae::HoraeError::NegativeDuration)
+    );
+    assert_eq!(
+        Duration::try_from(SignedDuration::from_millis(1_500)),
+        Ok(Duration::from_millis(1_500))
+    );
+    assert_eq!(
+        SignedDuration::from(Duration::new(3, 7)),
+        SignedDuration::new(3, 7)
+    );
+    assert_eq!(
+        SignedDuration::from_secs(3) * -2,
+        SignedDuration::from_secs(-6)
+    );
+    assert_eq!(
+        SignedDuration::from_secs(-7) / 2,
+        SignedDuration::from_millis(-3_500)
+    );
+    assert_eq!(
+        SignedDuration::from_secs_f64(-0.25),
+        SignedDuration::from_millis(-250)
+    );
+    assert!(SignedDuration::from_secs(-1) < SignedDuration::ZERO);
+}
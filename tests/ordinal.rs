@@ -0,0 +1,102 @@
+use horae::{Date, HoraeError, ParseComponent, Utc};
+
+#[test]
+fn day_of_year() {
+    for ((year, month, day), ordinal, remaining) in [
+        ((2019, 1, 1), 1, 364),
+        ((2019, 2, 1), 32, 333),
+        ((2019, 3, 1), 60, 305),
+        ((2024, 3, 1), 61, 305),
+        ((2019, 12, 31), 365, 0),
+        ((2024, 12, 31), 366, 0),
+        ((1900, 12, 31), 365, 0),
+        ((2000, 12, 31), 366, 0),
+    ] {
+        let date = Date::from_ymd(year, month, day).unwrap();
+        assert_eq!(date.ordinal_day(), ordinal, "{date}");
+        assert_eq!(date.days_remaining_in_year(), remaining, "{date}");
+    }
+    assert_eq!(Date::from_ymd(2023, 6, 1).unwrap().days_in_year(), 365);
+    assert_eq!(Date::from_ymd(2024, 6, 1).unwrap().days_in_year(), 366);
+}
+
+#[test]
+fn from_ordinal() {
+    assert_eq!(
+        Date::from_ordinal(2024, 1),
+        Ok(Date::from_ymd(2024, 1, 1).unwrap())
+    );
+    assert_eq!(
+        Date::from_ordinal(2024, 60),
+        Ok(Date::from_ymd(2024, 2, 29).unwrap())
+    );
+    assert_eq!(
+        Date::from_ordinal(2023, 60),
+        Ok(Date::from_ymd(2023, 3, 1).unwrap())
+    );
+    assert_eq!(
+        Date::from_ordinal(2024, 366),
+        Ok(Date::from_ymd(2024, 12, 31).unwrap())
+    );
+    assert_eq!(
+        Date::from_ordinal(9999, 365),
+        Ok(Date::from_ymd(9999, 12, 31).unwrap())
+    );
+    for (year, ordinal) in [(2023, 366), (2024, 367), (2024, 0)] {
+        assert_eq!(
+            Date::from_ordinal(year, ordinal),
+            Err(HoraeError::InvalidOrdinal { year, ordinal })
+        );
+    }
+    assert_eq!(Date::from_ordinal(0, 1), Err(HoraeError::InvalidYear(0)));
+
+    // Every day of a leap year round trips
+    for ordinal in 1..=366 {
+        let date = Date::from_ordinal(2024, ordinal).unwrap();
+        assert_eq!(date.ordinal_day(), ordinal);
+        assert_eq!(Date::parse_ordinal_date(&date.to_ordinal_date()), Ok(date));
+    }
+}
+
+#[test]
+fn parse_and_format() {
+    assert_eq!(
+        Date::parse_ordinal_date("2019-032"),
+        Ok(Date::from_ymd(2019, 2, 1).unwrap())
+    );
+    assert_eq!(
+        Date::parse_ordinal_date("2019032"),
+        Ok(Date::from_ymd(2019, 2, 1).unwrap())
+    );
+    assert_eq!(
+        Date::from_ymd(2019, 2, 1).unwrap().to_ordinal_date(),
+        "2019-032"
+    );
+    assert_eq!(
+        Date::from_ymd(1, 1, 1).unwrap().to_ordinal_date(),
+        "0001-001"
+    );
+
+    let mut utc = Utc::from_ymd_hms(2024, 12, 31, 23, 0, 0);
+    assert_eq!(utc.format("%yyyy-%ddd"), "2024-366");
+    utc.with_utc_offset(1.0);
+    assert_eq!(utc.format("%yyyy-%ddd"), "2025-001");
+    assert_eq!(utc.date().format("%ddd/%dd"), "001/01");
+
+    for (text, component, position) in [
+        ("2019-32", ParseComponent::Day, 5),
+        ("2019-0320", ParseComponent::Day, 8),
+        ("2019/032", ParseComponent::Day, 4),
+        ("2019-000", ParseComponent::Day, 5),
+        ("2019-366", ParseComponent::Day, 5),
+        ("0000-001", ParseComponent::Year, 0),
+        ("19-032", ParseComponent::Year, 0),
+    ] {
+        let error = Date::parse_ordinal_date(text).unwrap_err();
+        assert_eq!(
+            (error.component(), error.position()),
+            (component, position),
+            "{text}"
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use horae::Utc;
+
+#[test]
+fn nanoseconds_survive_arithmetic() {
+    let base = Utc::from_timestamp_parts(1_700_000_000, 0);
+    let later = base + std::time::Duration::from_nanos(1);
+    assert_eq!(later.unix_timestamp_secs(), 1_700_000_000);
+    assert_eq!(later.subsec_nanos(), 1);
+    assert_eq!((later - base).as_nanos(), 1);
+
+    let earlier = base - std::time::Duration::from_nanos(1);
+    assert_eq!(earlier.unix_timestamp_secs(), 1_699_999_999);
+    assert_eq!(earlier.subsec_nanos(), 999_999_999);
+
+    let mut sum = base;
+    for _ in 0..1_000 {
+        sum = sum + std::time::Duration::from_nanos(7);
+    }
+    assert_eq!(
+        sum.unix_timestamp_nanos() - base.unix_timestamp_nanos(),
+        7_000
+    );
+}
+
+#[test]
+fn nanosecond_round_trips() {
+    let utc = Utc::from_timestamp_nanos(1_700_000_000_123_456_789);
+    assert_eq!(utc.unix_timestamp_secs(), 1_700_000_000);
+    assert_eq!(utc.subsec_nanos(), 123_456_789);
+    assert_eq!(utc.time().nanosecond(), 123_456_789);
+    assert_eq!(utc.to_rfc3339(), "2023-11-14T22:13:20.123456789Z");
+
+    let parsed = Utc::from_rfc3339(&utc.to_rfc3339()).unwrap();
+    assert_eq!(parsed, utc);
+    assert_eq!(parsed.unix_timestamp_nanos(), utc.unix_timestamp_nanos());
+
+    let parts = Utc::from_timestamp_parts(1, 1_500_000_000);
+    assert_eq!(parts.unix_timestamp_secs(), 2);
+    assert_eq!(parts.subsec_nanos(), 500_000_000);
+}
+
+#[test]
+fn offsets_keep_the_instant() {
+    let utc = Utc::from_timestamp_nanos(1_700_000_000_000_000_001);
+    let mut local = utc;
+    local.with_utc_offset(13.75);
+    assert_eq!(local, utc);
+    assert_eq!(local.unix_timestamp_nanos(), utc.unix_timestamp_nanos());
+    assert_eq!(local.to_rfc3339(), "2023-11-15T11:58:20.000000001+13:45");
+
+    let parsed = Utc::from_rfc3339("2023-11-15T11:58:20.000000001+13:45").unwrap();
+    assert_eq!(parsed, utc);
+}
+
+#[test]
+fn ordering_and_size() {
+    let a = Utc::from_timestamp_parts(10, 1);
+    let b = Utc::from_timestamp_parts(10, 2);
+    assert!(a < b);
+    assert_eq!(a.max(b), b);
+    assert!(std::mem::size_of::<Utc>() <= 32);
+}
@@ -0,0 +1,203 @@
+use horae::{Date, HoraeError, SignedDuration, Step, Utc};
+
+fn dates(range: impl Iterator<Item = Date>) -> Vec<String> {
+    range.map(|date| date.to_string()).collect()
+}
+
+#[test]
+fn utc_range_exact_step() {
+    let start = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let end = Utc::from_ymd_hms(2024, 1, 1, 0, 1, 0);
+
+    let range = Utc::range(start, end, Step::seconds(20)).unwrap();
+    assert_eq!(range.len(), 3);
+    let secs: Vec<i64> = range.map(|utc| utc.unix_timestamp_secs()).collect();
+    assert_eq!(secs, [1_704_067_200, 1_704_067_220, 1_704_067_240]);
+
+    assert_eq!(
+        Utc::range_inclusive(start, end, Step::seconds(20))
+            .unwrap()
+            .len(),
+        4
+    );
+    assert_eq!(Utc::range(start, end, Step::seconds(25)).unwrap().len(), 3);
+    assert_eq!(
+        Utc::range_inclusive(start, end, Step::seconds(25))
+            .unwrap()
+            .len(),
+        3
+    );
+    assert_eq!(
+        Utc::range(start, end, Step::Exact(SignedDuration::from_nanos(1)))
+            .unwrap()
+            .len(),
+        60_000_000_000
+    );
+}
+
+#[test]
+fn utc_range_empty() {
+    let start = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let end = Utc::from_ymd_hms(2023, 1, 1, 0, 0, 0);
+    assert_eq!(Utc::range(start, start, Step::Days(1)).unwrap().count(), 0);
+    assert_eq!(Utc::range(start, end, Step::Days(1)).unwrap().count(), 0);
+    assert_eq!(Utc::range(start, end, Step::Months(1)).unwrap().count(), 0);
+    assert_eq!(
+        Utc::range_inclusive(start, start, Step::Years(1))
+            .unwrap()
+            .collect::<Vec<_>>(),
+        [start]
+    );
+}
+
+#[test]
+fn utc_range_invalid_step() {
+    let start = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    for step in [
+        Step::Days(0),
+        Step::Months(0),
+        Step::seconds(0),
+        Step::Exact(SignedDuration::from_secs(-1)),
+    ] {
+        assert_eq!(
+            Utc::range(start, start, step).unwrap_err(),
+            HoraeError::InvalidStep
+        );
+    }
+}
+
+#[test]
+fn utc_range_calendar_steps_keep_offset() {
+    let mut start = Utc::from_ymd_hms(2024, 1, 30, 23, 0, 0);
+    start.with_utc_offset(2.0);
+    assert_eq!(start.to_string(), "2024-01-31 01:00:00.000");
+    let end = Utc::from_ymd_hms(2024, 6, 1, 0, 0, 0);
+
+    let months: Vec<String> = Utc::range(start, end, Step::Months(1))
+        .unwrap()
+        .map(|utc| utc.to_rfc3339())
+        .collect();
+    assert_eq!(
+        months,
+        [
+            "2024-01-31T01:00:00+02:00",
+            "2024-02-29T01:00:00+02:00",
+            "2024-03-31T01:00:00+02:00",
+            "2024-04-30T01:00:00+02:00",
+            "2024-05-31T01:00:00+02:00",
+        ]
+    );
+
+    let days = Utc::range(start, start.add_days(3).unwrap(), Step::Days(1)).unwrap();
+    let days: Vec<String> = days.map(|utc| utc.to_string()).collect();
+    assert_eq!(
+        days,
+        [
+            "2024-01-31 01:00:00.000",
+            "2024-02-01 01:00:00.000",
+            "2024-02-02 01:00:00.000"
+        ]
+    );
+}
+
+#[test]
+fn utc_range_reverse_and_nth() {
+    let start = Utc::from_ymd_hms(2020, 2, 29, 12, 0, 0);
+    let end = Utc::from_ymd_hms(2025, 1, 1, 0, 0, 0);
+    let mut range = Utc::range(start, end, Step::Years(1)).unwrap();
+    assert_eq!(range.len(), 5);
+    assert_eq!(range.next_back().unwrap().date().to_string(), "2024-02-29");
+    assert_eq!(range.next_back().unwrap().date().to_string(), "2023-02-28");
+    assert_eq!(range.next().unwrap(), start);
+    assert_eq!(range.len(), 2);
+    assert_eq!(range.nth(1).unwrap().date().to_string(), "2022-02-28");
+    assert_eq!(range.next(), None);
+    assert_eq!(range.next_back(), None);
+
+    let mut range = Utc::range(start, end, Step::Years(1)).unwrap();
+    assert_eq!(range.nth(10), None);
+    assert_eq!(range.len(), 0);
+}
+
+#[test]
+fn utc_range_end_of_supported_years() {
+    let start = Utc::from_ymd_hms(9999, 10, 31, 0, 0, 0);
+    let end = Utc::from_ymd_hms(9999, 12, 31, 23, 59, 59);
+    let mut range = Utc::range_inclusive(start, end, Step::Months(1)).unwrap();
+    assert_eq!(range.len(), 3);
+    assert_eq!(range.next_back().unwrap().date().to_string(), "9999-12-31");
+
+    // More nanoseconds than fit into the length of the range
+    let first = Utc::from_ymd_hms(1, 1, 1, 0, 0, 0);
+    let step = Step::Exact(SignedDuration::from_nanos(1));
+    assert_eq!(
+        Utc::range(first, end, step).unwrap_err(),
+        HoraeError::OutOfRange
+    );
+    assert_eq!(
+        Utc::range_inclusive(first, end, step).unwrap_err(),
+        HoraeError::OutOfRange
+    );
+    let step = Step::Exact(SignedDuration::from_secs(1));
+    assert_eq!(Utc::range(first, end, step).unwrap().len(), 315_537_897_599);
+}
+
+#[test]
+fn date_range_days_and_weeks() {
+    let start = Date::from_ymd(2023, 12, 30).unwrap();
+    let end = Date::from_ymd(2024, 1, 2).unwrap();
+    assert_eq!(
+        dates(Date::range(start, end, Step::Days(1)).unwrap()),
+        ["2023-12-30", "2023-12-31", "2024-01-01"]
+    );
+    assert_eq!(
+        dates(
+            Date::range_inclusive(start, end, Step::Days(1))
+                .unwrap()
+                .rev()
+        ),
+        ["2024-01-02", "2024-01-01", "2023-12-31", "2023-12-30"]
+    );
+    assert_eq!(
+        dates(Date::range(start, end, Step::Days(2)).unwrap()),
+        ["2023-12-30", "2024-01-01"]
+    );
+
+    let end = Date::from_ymd(2024, 12, 31).unwrap();
+    let weeks = Date::range_inclusive(Date::from_ymd(2024, 1, 1).unwrap(), end, Step::Weeks(1));
+    assert_eq!(weeks.unwrap().len(), 53);
+}
+
+#[test]
+fn date_range_months_and_years() {
+    let start = Date::from_ymd(2023, 8, 31).unwrap();
+    let end = Date::from_ymd(2024, 2, 29).unwrap();
+    assert_eq!(
+        dates(Date::range(start, end, Step::Months(2)).unwrap()),
+        ["2023-08-31", "2023-10-31", "2023-12-31"]
+    );
+    assert_eq!(
+        dates(Date::range_inclusive(start, end, Step::Months(6)).unwrap()),
+        ["2023-08-31", "2024-02-29"]
+    );
+    assert_eq!(Date::range(start, end, Step::Months(6)).unwrap().len(), 1);
+
+    let start = Date::from_ymd(1, 1, 1).unwrap();
+    let end = Date::from_ymd(9999, 12, 31).unwrap();
+    let mut centuries = Date::range_inclusive(start, end, Step::Years(100)).unwrap();
+    assert_eq!(centuries.len(), 100);
+    assert_eq!(centuries.next_back().unwrap().to_string(), "9901-01-01");
+}
+
+#[test]
+fn date_range_invalid_step() {
+    let date = Date::from_ymd(2024, 1, 1).unwrap();
+    assert_eq!(
+        Date::range(date, date, Step::seconds(86_400)).unwrap_err(),
+        HoraeError::InvalidStep
+    );
+    assert_eq!(
+        Date::range(date, date, Step::Weeks(0)).unwrap_err(),
+        HoraeError::InvalidStep
+    );
+}
@@ -0,0 +1,384 @@
+use horae::{
+    Date, Frequency, HoraeError, NthWeekday, ParseComponent, RRule, RecurrenceSet, Time, Until,
+    Utc, Weekday,
+};
+
+#[test]
+fn daily_count() {
+    let start = Utc::from_ymd_hms(1997, 9, 2, 9, 0, 0);
+    let days: Vec<Utc> = RRule::parse("FREQ=DAILY;COUNT=10")
+        .unwrap()
+        .occurrences(start)
+        .collect();
+    assert_eq!(days.len(), 10);
+    assert_eq!(days[0].date().to_string(), "1997-09-02");
+    assert_eq!(days[9].date().to_string(), "1997-09-11");
+}
+
+#[test]
+fn daily_until() {
+    let start = Utc::from_ymd_hms(1997, 9, 2, 9, 0, 0);
+    let rule = RRule::parse("FREQ=DAILY;UNTIL=19971224T000000Z").unwrap();
+    let all: Vec<Utc> = rule.occurrences(start).collect();
+    assert_eq!(all.len(), 113);
+    assert_eq!(all[112], Utc::from_ymd_hms(1997, 12, 23, 9, 0, 0));
+
+    // A date is inclusive of the whole day
+    let days: Vec<String> = RRule::parse("FREQ=DAILY;UNTIL=19970905")
+        .unwrap()
+        .occurrences(start)
+        .map(|utc| utc.date().to_string())
+        .collect();
+    assert_eq!(
+        days,
+        ["1997-09-02", "1997-09-03", "1997-09-04", "1997-09-05"]
+    );
+}
+
+#[test]
+fn weekly_interval_and_week_start() {
+    for (rule, start, expected) in [
+        (
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=8;WKST=SU;BYDAY=TU,TH",
+            Utc::from_ymd_hms(1997, 9, 2, 9, 0, 0),
+            &[
+                "1997-09-02",
+                "1997-09-04",
+                "1997-09-16",
+                "1997-09-18",
+                "1997-09-30",
+                "1997-10-02",
+                "1997-10-14",
+                "1997-10-16",
+            ][..],
+        ),
+        (
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO",
+            Utc::from_ymd_hms(1997, 8, 5, 9, 0, 0),
+            &["1997-08-05", "1997-08-10", "1997-08-19", "1997-08-24"],
+        ),
+        (
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU",
+            Utc::from_ymd_hms(1997, 8, 5, 9, 0, 0),
+            &["1997-08-05", "1997-08-17", "1997-08-19", "1997-08-31"],
+        ),
+    ] {
+        let days: Vec<String> = RRule::parse(rule)
+            .unwrap()
+            .occurrences(start)
+            .map(|utc| utc.date().to_string())
+            .collect();
+        assert_eq!(days, expected, "{rule}");
+    }
+
+    let mut rule = RRule::new(Frequency::Weekly);
+    assert_eq!(rule.week_start, Weekday::Monday);
+    rule.week_start = Weekday::Sunday;
+    rule.by_day = vec![NthWeekday::every(Weekday::Sunday)];
+    assert_eq!(rule.to_string(), "FREQ=WEEKLY;BYDAY=SU;WKST=SU");
+    assert_eq!(RRule::parse(&rule.to_string()), Ok(rule));
+}
+
+#[test]
+fn monthly_nth_weekday() {
+    let start = Utc::from_ymd_hms(1997, 9, 5, 9, 0, 0);
+    let days: Vec<String> = RRule::parse("FREQ=MONTHLY;COUNT=10;BYDAY=1FR")
+        .unwrap()
+        .occurrences(start)
+        .map(|utc| utc.date().to_string())
+        .collect();
+    assert_eq!(
+        days,
+        [
+            "1997-09-05",
+            "1997-10-03",
+            "1997-11-07",
+            "1997-12-05",
+            "1998-01-02",
+            "1998-02-06",
+            "1998-03-06",
+            "1998-04-03",
+            "1998-05-01",
+            "1998-06-05"
+        ]
+    );
+
+    let rule = RRule::parse("FREQ=MONTHLY;BYDAY=-2MO").unwrap();
+    let mut occurrences = rule.occurrences(start);
+    for expected in ["1997-09-22", "1997-10-20", "1997-11-17"] {
+        assert_eq!(occurrences.next().unwrap().date().to_string(), expected);
+    }
+}
+
+#[test]
+fn last_weekday_of_month() {
+    let rule = RRule::parse("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1").unwrap();
+    let mut occurrences = rule.occurrences(Utc::from_ymd_hms(1997, 9, 29, 9, 0, 0));
+    for expected in [
+        "1997-09-30",
+        "1997-10-31",
+        "1997-11-28",
+        "1997-12-31",
+        "1998-01-30",
+        "1998-02-27",
+        "1998-03-31",
+    ] {
+        assert_eq!(occurrences.next().unwrap().date().to_string(), expected);
+    }
+}
+
+#[test]
+fn monthly_skips_missing_days() {
+    let start = Utc::from_ymd_hms(2024, 1, 31, 9, 0, 0);
+    let days: Vec<String> = RRule::parse("FREQ=MONTHLY;COUNT=4")
+        .unwrap()
+        .occurrences(start)
+        .map(|utc| utc.date().to_string())
+        .collect();
+    assert_eq!(
+        days,
+        ["2024-01-31", "2024-03-31", "2024-05-31", "2024-07-31"]
+    );
+
+    let rule = RRule::parse("FREQ=MONTHLY;BYMONTHDAY=-1").unwrap();
+    let mut occurrences = rule.occurrences(start);
+    for expected in ["2024-01-31", "2024-02-29", "2024-03-31"] {
+        assert_eq!(occurrences.next().unwrap().date().to_string(), expected);
+    }
+}
+
+#[test]
+fn friday_13th() {
+    let rule = RRule::parse("FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13").unwrap();
+    let mut occurrences = rule.occurrences(Utc::from_ymd_hms(1997, 9, 2, 9, 0, 0));
+    for expected in [
+        "1998-02-13",
+        "1998-03-13",
+        "1998-11-13",
+        "1999-08-13",
+        "2000-10-13",
+    ] {
+        assert_eq!(occurrences.next().unwrap().date().to_string(), expected);
+    }
+}
+
+#[test]
+fn yearly() {
+    for (rule, start, expected) in [
+        (
+            "FREQ=YEARLY",
+            Utc::from_ymd_hms(2024, 2, 29, 9, 0, 0),
+            &["2024-02-29", "2028-02-29", "2032-02-29"][..],
+        ),
+        (
+            "FREQ=YEARLY;INTERVAL=4;BYMONTH=11;BYDAY=TU;BYMONTHDAY=2,3,4,5,6,7,8",
+            Utc::from_ymd_hms(1996, 11, 5, 9, 0, 0),
+            &["1996-11-05", "2000-11-07", "2004-11-02"],
+        ),
+        (
+            "FREQ=YEARLY;BYDAY=20MO",
+            Utc::from_ymd_hms(1997, 5, 19, 9, 0, 0),
+            &["1997-05-19", "1998-05-18", "1999-05-17"],
+        ),
+        (
+            "FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO",
+            Utc::from_ymd_hms(1997, 5, 12, 9, 0, 0),
+            &["1997-05-12", "1998-05-11", "1999-05-17"],
+        ),
+        (
+            "FREQ=YEARLY;BYYEARDAY=1,100,200",
+            Utc::from_ymd_hms(1997, 1, 1, 9, 0, 0),
+            &["1997-01-01", "1997-04-10", "1997-07-19", "1998-01-01"],
+        ),
+    ] {
+        let mut occurrences = RRule::parse(rule).unwrap().occurrences(start);
+        for expected in expected {
+            assert_eq!(
+                occurrences.next().unwrap().date().to_string(),
+                *expected,
+                "{rule}"
+            );
+        }
+    }
+}
+
+#[test]
+fn ends_with_supported_years() {
+    let start = Utc::from_ymd_hms(9998, 6, 1, 9, 0, 0);
+    let days: Vec<String> = RRule::parse("FREQ=YEARLY")
+        .unwrap()
+        .occurrences(start)
+        .map(|utc| utc.date().to_string())
+        .collect();
+    assert_eq!(days, ["9998-06-01", "9999-06-01"]);
+    let rule = RRule::parse("FREQ=DAILY;COUNT=0").unwrap();
+    assert_eq!(rule.occurrences(start).next(), None);
+}
+
+#[test]
+fn sub_daily() {
+    let start = Utc::from_ymd_hms(1997, 9, 2, 9, 0, 0);
+    let times: Vec<String> = RRule::parse("FREQ=HOURLY;INTERVAL=3;UNTIL=19970902T170000Z")
+        .unwrap()
+        .occurrences(start)
+        .map(|utc| utc.time().to_string())
+        .collect();
+    assert_eq!(times, ["09:00:00.000", "12:00:00.000", "15:00:00.000"]);
+
+    let rule = RRule::parse("FREQ=MINUTELY;INTERVAL=20;BYHOUR=9,10,11,12,13,14,15,16").unwrap();
+    let all: Vec<Utc> = rule.occurrences(start).take(25).collect();
+    assert_eq!(all[1], Utc::from_ymd_hms(1997, 9, 2, 9, 20, 0));
+    assert_eq!(all[23], Utc::from_ymd_hms(1997, 9, 2, 16, 40, 0));
+    assert_eq!(all[24], Utc::from_ymd_hms(1997, 9, 3, 9, 0, 0));
+
+    let rule = RRule::parse("FREQ=SECONDLY;BYMONTH=10;COUNT=2").unwrap();
+    let all: Vec<Utc> = rule.occurrences(start).collect();
+    assert_eq!(
+        all,
+        [
+            Utc::from_ymd_hms(1997, 10, 1, 0, 0, 0),
+            Utc::from_ymd_hms(1997, 10, 1, 0, 0, 1)
+        ]
+    );
+}
+
+#[test]
+fn evaluated_in_start_offset() {
+    let start = Utc::from_ymd_hms_offset(2024, 1, 1, 7, 0, 0, 2.0);
+    let all: Vec<Utc> = RRule::parse("FREQ=DAILY;BYHOUR=8;COUNT=2")
+        .unwrap()
+        .occurrences(start)
+        .collect();
+    assert_eq!(all[0], Utc::from_ymd_hms(2024, 1, 1, 6, 0, 0));
+    assert_eq!(all[1], Utc::from_ymd_hms(2024, 1, 2, 6, 0, 0));
+    assert_eq!(all[0].get_utc_offset(), 2.0);
+    assert_eq!(all[0].time().hour(), 8);
+
+    // A local UNTIL is read in the offset of the start
+    let mut rule = RRule::new(Frequency::Daily);
+    rule.until = Some(Until::Local(
+        Date::from_ymd(2024, 1, 3).unwrap(),
+        Time::from_hms(9, 0, 0).unwrap(),
+    ));
+    assert_eq!(rule.occurrences(start).count(), 3);
+
+    let nanos = Utc::from_timestamp_parts(start.unix_timestamp_secs(), 250);
+    let mut rule = RRule::new(Frequency::Hourly);
+    rule.count = Some(2);
+    assert!(rule.occurrences(nanos).all(|utc| utc.subsec_nanos() == 250));
+}
+
+#[test]
+fn parse_errors() {
+    let error = |rule: &str| RRule::parse(rule).unwrap_err();
+
+    let e = error("FREQ=DAILY;COUNT=2;UNTIL=20240101");
+    assert_eq!(e.component(), ParseComponent::RulePart);
+    assert_eq!(e.position(), 19);
+    assert_eq!(error("FREQ=MONTHLY;BYWEEKNO=1").position(), 13);
+    assert_eq!(error("COUNT=2").position(), 0);
+    assert_eq!(error("FREQ=DAILY;BYSECOND=60").position(), 20);
+    assert_eq!(error("FREQ=DAILY;BYSETPOS=1").position(), 11);
+    assert_eq!(error("FREQ=WEEKLY;BYDAY=1MO").position(), 12);
+    assert_eq!(error("FREQ=WEEKLY;BYMONTHDAY=1").position(), 12);
+    assert_eq!(error("FREQ=DAILY;BYYEARDAY=1").position(), 11);
+    assert_eq!(error("FREQ=DAILY;FREQ=DAILY").position(), 11);
+    assert_eq!(error("FREQ=DAILY;FOO=1").position(), 11);
+    assert_eq!(error("FREQ=FORTNIGHTLY").position(), 5);
+    assert_eq!(error("FREQ=DAILY;INTERVAL=0").position(), 20);
+    assert_eq!(error("FREQ=DAILY;BYDAY=MO,XX").position(), 20);
+    assert_eq!(error("FREQ=DAILY;BYMONTH=0").position(), 19);
+    assert_eq!(
+        error("FREQ=DAILY;UNTIL=20240230").component(),
+        ParseComponent::Day
+    );
+
+    assert!(matches!(
+        "FREQ=DAILY;BYHOUR=24".parse::<RRule>(),
+        Err(HoraeError::Parse(_))
+    ));
+}
+
+#[test]
+fn parse_and_display() {
+    let rule = RRule::parse("rrule:freq=monthly;byday=-1fr,2mo;until=20241231T235959z").unwrap();
+    assert_eq!(rule.frequency, Frequency::Monthly);
+    assert_eq!(
+        rule.by_day,
+        [
+            NthWeekday::nth(-1, Weekday::Friday),
+            NthWeekday::nth(2, Weekday::Monday)
+        ]
+    );
+    assert_eq!(
+        rule.until,
+        Some(Until::Instant(Utc::from_ymd_hms(2024, 12, 31, 23, 59, 59)))
+    );
+    assert_eq!(
+        rule.to_string(),
+        "FREQ=MONTHLY;UNTIL=20241231T235959Z;BYDAY=-1FR,2MO"
+    );
+
+    for canonical in [
+        "FREQ=YEARLY;COUNT=3;INTERVAL=2;BYMONTH=1,7",
+        "FREQ=WEEKLY;UNTIL=20240101T120000;BYDAY=TU,TH;WKST=SU",
+        "FREQ=DAILY;UNTIL=20240101;BYSECOND=0,30;BYMINUTE=15;BYHOUR=8,20",
+        "FREQ=YEARLY;BYYEARDAY=-1;BYWEEKNO=1,-1;BYSETPOS=1",
+        "FREQ=MONTHLY;BYMONTHDAY=1,-1",
+    ] {
+        assert_eq!(RRule::parse(canonical).unwrap().to_string(), canonical);
+    }
+}
+
+#[test]
+fn recurrence_set() {
+    let set = RecurrenceSet::parse(
+        "DTSTART:20240101T090000Z\r\n\
+         RRULE:FREQ=WEEKLY;COUNT=3\r\n\
+         RRULE:FREQ=MONTHLY;COUNT=2;BYMONTHDAY=8\r\n\
+         RDATE;VALUE=DATE:20240102,20240115\r\n\
+         EXDATE:20240101T090000Z\r\n",
+    )
+    .unwrap();
+    assert_eq!(set.rules.len(), 2);
+    let all: Vec<Utc> = set.occurrences().collect();
+    assert_eq!(
+        all,
+        [
+            Utc::from_ymd_hms(2024, 1, 2, 0, 0, 0),
+            Utc::from_ymd_hms(2024, 1, 8, 9, 0, 0),
+            Utc::from_ymd_hms(2024, 1, 15, 0, 0, 0),
+            Utc::from_ymd_hms(2024, 1, 15, 9, 0, 0),
+            Utc::from_ymd_hms(2024, 2, 8, 9, 0, 0),
+        ]
+    );
+
+    let mut set = RecurrenceSet::new(Utc::from_ymd_hms(2024, 1, 1, 9, 0, 0));
+    set.dates.push(Utc::from_ymd_hms(2024, 1, 1, 9, 0, 0));
+    assert_eq!(set.occurrences().count(), 1);
+}
+
+#[test]
+fn recurrence_set_errors() {
+    let error = |set: &str| RecurrenceSet::parse(set).unwrap_err();
+
+    let e = error("RRULE:FREQ=DAILY");
+    assert_eq!(e.component(), ParseComponent::Property);
+    assert_eq!(e.position(), 16);
+    let e = error("DTSTART;TZID=Europe/Berlin:20240101T090000");
+    assert_eq!(e.component(), ParseComponent::Property);
+    assert_eq!(e.position(), 8);
+    assert_eq!(
+        error("DTSTART:20240101T090000Z\nDTSTART:20240101T090000Z").position(),
+        25
+    );
+    assert_eq!(error("DTSTART:20240101T090000Z\nSUMMARY:x").position(), 25);
+    assert_eq!(
+        error("DTSTART:20240101T090000Z\nRRULE:FREQ=DAILY;BYHOUR=24").component(),
+        ParseComponent::RulePart
+    );
+    assert!(matches!(
+        "DTSTART:2024".parse::<RecurrenceSet>(),
+        Err(HoraeError::Parse(_))
+    ));
+}
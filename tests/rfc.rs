@@ -79,7 +79,7 @@ fn test_rfc9557_parsing() {
 
     let s2 = "1996-12-19T16:39:57-08:00[!u-ca=hebrew]";
     let dt2 = Utc::from_rfc9557(s2);
-    assert!(dt2.is_none()); // Critical unknown tag
+    assert!(dt2.is_err()); // Critical unknown tag
 
     let s3 = "1996-12-19T16:39:57-08:00[u-ca=hebrew]";
     let dt3 = Utc::from_rfc9557(s3).unwrap(); // Elective unknown tag
@@ -91,5 +91,148 @@ fn test_rfc9557_parsing() {
 
     let s5 = "2022-07-08T00:14:07+01:00[!-08:00]";
     let dt5 = Utc::from_rfc9557(s5);
-    assert!(dt5.is_none()); // Critical inconsistent offset
+    assert!(dt5.is_err()); // Critical inconsistent offset
+}
+
+#[test]
+fn test_rfc3339_parse_errors() {
+    use horae::ParseComponent;
+
+    let cases = vec![
+        ("198-04-12T23:20:50Z", ParseComponent::Year, 0),
+        ("1985/04-12T23:20:50Z", ParseComponent::DateSeparator, 4),
+        ("1985-4-12T23:20:50Z", ParseComponent::Month, 5),
+        ("1985-13-12T23:20:50Z", ParseComponent::Month, 5),
+        ("1985-04-31T23:20:50Z", ParseComponent::Day, 8),
+        (
+            "1985-04-12X23:20:50Z",
+            ParseComponent::DateTimeSeparator,
+            10,
+        ),
+        ("1985-04-12T24:20:50Z", ParseComponent::Hour, 11),
+        ("1985-04-12T23-20:50Z", ParseComponent::TimeSeparator, 13),
+        ("1985-04-12T23:60:50Z", ParseComponent::Minute, 14),
+        ("1985-04-12T23:20:61Z", ParseComponent::Second, 17),
+        ("1985-04-12T23:20:5Z", ParseComponent::Second, 17),
+        ("1985-04-12T23:20:50.Z", ParseComponent::Fraction, 20),
+        ("1985-04-12T23:20:50+0100", ParseComponent::Offset, 22),
+        ("1985-04-12T23:20:50+25:00", ParseComponent::Offset, 20),
+        ("1985-04-12T23:20:50Zz", ParseComponent::Offset, 20),
+        ("1985-04-1ä2T23:20:50Z", ParseComponent::Day, 8),
+    ];
+    for (input, component, position) in cases {
+        let error = Utc::parse_rfc3339(input).unwrap_err();
+        assert_eq!(error.component(), component, "Failed for {input}");
+        assert_eq!(error.position(), position, "Failed for {input}");
+        assert_eq!(error.input(), input);
+    }
+}
+
+#[test]
+fn test_rfc9557_parse_errors() {
+    use horae::ParseComponent;
+
+    let cases = vec![
+        ("1996-12-19T16:39:57-08:00[!u-ca=hebrew]", 25),
+        ("1996-12-19T16:39:57-08:00[!-07:00]", 27),
+        ("1996-12-19T16:39:57-08:00[!America/Los_Angeles]", 27),
+        ("1996-12-19T16:39:57-08:00[u-ca=hebrew", 25),
+        ("1996-12-19T16:39:57-08:00[u-ca=hebrew]x", 38),
+    ];
+    for (input, position) in cases {
+        let error = Utc::parse_rfc9557(input).unwrap_err();
+        assert_eq!(
+            error.component(),
+            ParseComponent::SuffixTag,
+            "Failed for {input}"
+        );
+        assert_eq!(error.position(), position, "Failed for {input}");
+    }
+
+    let error = Utc::parse_rfc9557("1996-12-19T16:39:57-08:0[u-ca=hebrew]").unwrap_err();
+    assert_eq!(error.component(), ParseComponent::Offset);
+    assert_eq!(error.position(), 23);
+}
+
+#[test]
+fn test_parse_error_display() {
+    let error = Utc::parse_rfc3339("1985-04-12T23:20:50+1:00").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid offset at byte 20, expected two digit offset hours\n\
+         1985-04-12T23:20:50+1:00\n                    ^"
+    );
+
+    // The caret lines up by characters for non-ASCII input
+    let error = Utc::parse_rfc3339("1985-ä4-12T23:20:50Z").unwrap_err();
+    assert_eq!(error.position(), 5);
+    assert!(error.to_string().ends_with("1985-ä4-12T23:20:50Z\n     ^"));
+}
+
+#[test]
+fn test_rfc3339_options() {
+    use horae::{Rfc3339Options, SecondsFormat};
+
+    let options = |seconds, utc_as_z| Rfc3339Options { seconds, utc_as_z };
+    for (nanos, seconds, expected) in [
+        (0, SecondsFormat::Seconds, "2019-01-01T09:09:09"),
+        (0, SecondsFormat::Millis, "2019-01-01T09:09:09.000"),
+        (0, SecondsFormat::Micros, "2019-01-01T09:09:09.000000"),
+        (0, SecondsFormat::Nanos, "2019-01-01T09:09:09.000000000"),
+        (0, SecondsFormat::Trimmed, "2019-01-01T09:09:09"),
+        (999_999_999, SecondsFormat::Seconds, "2019-01-01T09:09:09"),
+        (
+            999_999_999,
+            SecondsFormat::Millis,
+            "2019-01-01T09:09:09.999",
+        ),
+        (
+            999_999_999,
+            SecondsFormat::Micros,
+            "2019-01-01T09:09:09.999999",
+        ),
+        (1_500, SecondsFormat::Micros, "2019-01-01T09:09:09.000001"),
+        (1_500, SecondsFormat::Trimmed, "2019-01-01T09:09:09.0000015"),
+        (1_500, SecondsFormat::Nanos, "2019-01-01T09:09:09.000001500"),
+    ] {
+        let utc = Utc::from_timestamp_nanos(1_546_333_749_000_000_000 + nanos);
+        assert_eq!(
+            utc.to_rfc3339_with(options(seconds, true)),
+            format!("{expected}Z")
+        );
+        assert_eq!(
+            utc.to_rfc3339_with(options(seconds, false)),
+            format!("{expected}+00:00")
+        );
+        assert_eq!(
+            &*utc.to_rfc3339_buffer_with(options(seconds, false)),
+            format!("{expected}+00:00")
+        );
+        let mut out = Vec::new();
+        utc.write_rfc3339_with(&mut out, options(seconds, false))
+            .unwrap();
+        assert_eq!(out, format!("{expected}+00:00").as_bytes());
+        // Every output reads back, truncated to its digits
+        let parsed = Utc::from_rfc3339(&utc.to_rfc3339_with(options(seconds, false))).unwrap();
+        assert_eq!(
+            parsed.to_rfc3339_with(options(seconds, true)),
+            format!("{expected}Z")
+        );
+    }
+
+    // Offsets other than UTC are not affected by `utc_as_z`
+    let mut utc = Utc::from_ymd_hms(2019, 1, 1, 3, 39, 9);
+    utc.with_utc_offset(5.5);
+    assert_eq!(
+        utc.to_rfc3339_with(options(SecondsFormat::Millis, false)),
+        "2019-01-01T09:09:09.000+05:30"
+    );
+    assert_eq!(
+        utc.to_rfc3339_with(Rfc3339Options::default()),
+        utc.to_rfc3339()
+    );
+    assert_eq!(
+        Rfc3339Options::default(),
+        options(SecondsFormat::Trimmed, true)
+    );
 }
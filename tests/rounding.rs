@@ -0,0 +1,191 @@
+use horae::{HoraeError, SignedDuration, TimeUnit, Utc};
+
+const UNITS: [TimeUnit; 8] = [
+    TimeUnit::Second,
+    TimeUnit::Minute,
+    TimeUnit::Hour,
+    TimeUnit::Day,
+    TimeUnit::Week,
+    TimeUnit::Month,
+    TimeUnit::Quarter,
+    TimeUnit::Year,
+];
+
+fn strings(utc: Utc, f: fn(&Utc, TimeUnit) -> Utc) -> Vec<String> {
+    UNITS
+        .iter()
+        .map(|unit| f(&utc, *unit).to_string())
+        .collect()
+}
+
+#[test]
+fn truncate() {
+    // Thursday
+    let utc = Utc::from_timestamp_parts(
+        Utc::from_ymd_hms(2024, 8, 15, 13, 47, 29).unix_timestamp_secs(),
+        750_000_000,
+    );
+    assert_eq!(
+        strings(utc, Utc::truncate),
+        [
+            "2024-08-15 13:47:29.000",
+            "2024-08-15 13:47:00.000",
+            "2024-08-15 13:00:00.000",
+            "2024-08-15 00:00:00.000",
+            "2024-08-12 00:00:00.000",
+            "2024-08-01 00:00:00.000",
+            "2024-07-01 00:00:00.000",
+            "2024-01-01 00:00:00.000"
+        ]
+    );
+    // ISO weeks cross years
+    assert_eq!(
+        Utc::from_ymd_hms(2025, 1, 1, 0, 0, 0)
+            .truncate(TimeUnit::Week)
+            .to_string(),
+        "2024-12-30 00:00:00.000"
+    );
+    assert_eq!(
+        Utc::from_ymd_hms(1, 1, 1, 12, 0, 0)
+            .truncate(TimeUnit::Week)
+            .to_string(),
+        "0001-01-01 00:00:00.000"
+    );
+}
+
+#[test]
+fn ceil() {
+    let utc = Utc::from_ymd_hms(2024, 11, 15, 13, 47, 29);
+    assert_eq!(
+        strings(utc, Utc::ceil),
+        [
+            "2024-11-15 13:47:29.000",
+            "2024-11-15 13:48:00.000",
+            "2024-11-15 14:00:00.000",
+            "2024-11-16 00:00:00.000",
+            "2024-11-18 00:00:00.000",
+            "2024-12-01 00:00:00.000",
+            "2025-01-01 00:00:00.000",
+            "2025-01-01 00:00:00.000"
+        ]
+    );
+    let start_of_year = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    for unit in UNITS {
+        assert_eq!(start_of_year.ceil(unit), start_of_year, "{unit:?}");
+    }
+    let just_after = Utc::from_timestamp_parts(start_of_year.unix_timestamp_secs(), 1);
+    assert_eq!(
+        just_after.ceil(TimeUnit::Second).to_string(),
+        "2024-01-01 00:00:01.000"
+    );
+}
+
+#[test]
+fn round() {
+    let utc = Utc::from_ymd_hms(2024, 2, 15, 12, 30, 30);
+    assert_eq!(
+        strings(utc, Utc::round),
+        [
+            "2024-02-15 12:30:30.000",
+            "2024-02-15 12:31:00.000",
+            "2024-02-15 13:00:00.000",
+            "2024-02-16 00:00:00.000",
+            "2024-02-19 00:00:00.000",
+            "2024-03-01 00:00:00.000",
+            "2024-04-01 00:00:00.000",
+            "2024-01-01 00:00:00.000"
+        ]
+    );
+    // Halfway rounds up, just before halfway rounds down
+    let noon = Utc::from_ymd_hms(2024, 2, 15, 12, 0, 0);
+    assert_eq!(
+        noon.round(TimeUnit::Day).to_string(),
+        "2024-02-16 00:00:00.000"
+    );
+    let before_noon = Utc::from_timestamp_parts(noon.unix_timestamp_secs() - 1, 999_999_999);
+    assert_eq!(
+        before_noon.round(TimeUnit::Day).to_string(),
+        "2024-02-15 00:00:00.000"
+    );
+    assert_eq!(
+        Utc::from_timestamp_parts(noon.unix_timestamp_secs(), 500_000_000)
+            .round(TimeUnit::Second)
+            .to_string(),
+        "2024-02-15 12:00:01.000"
+    );
+}
+
+#[test]
+fn local_offset() {
+    // 2024-03-31 23:30 in UTC is already April in UTC+02:00
+    let mut utc = Utc::from_ymd_hms(2024, 3, 31, 23, 30, 0);
+    utc.with_utc_offset(2.0);
+
+    let day = utc.truncate(TimeUnit::Day);
+    assert_eq!(day, Utc::from_ymd_hms(2024, 3, 31, 22, 0, 0));
+    assert_eq!(day.get_utc_offset(), 2.0);
+    assert_eq!(day.to_string(), "2024-04-01 00:00:00.000");
+
+    assert_eq!(
+        utc.truncate(TimeUnit::Quarter),
+        Utc::from_ymd_hms(2024, 3, 31, 22, 0, 0)
+    );
+    assert_eq!(
+        utc.ceil(TimeUnit::Month),
+        Utc::from_ymd_hms(2024, 4, 30, 22, 0, 0)
+    );
+
+    utc.with_utc_offset(-5.5);
+    assert_eq!(
+        utc.truncate(TimeUnit::Hour),
+        Utc::from_ymd_hms(2024, 3, 31, 23, 30, 0)
+    );
+    assert_eq!(
+        utc.truncate(TimeUnit::Day),
+        Utc::from_ymd_hms(2024, 3, 31, 5, 30, 0)
+    );
+}
+
+#[test]
+fn duration_bucket() {
+    let origin = Utc::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let fifteen_minutes = SignedDuration::from_secs(15 * 60);
+    let bucket = |utc: Utc, width, origin| utc.duration_bucket(width, origin).unwrap();
+
+    let utc = Utc::from_ymd_hms(2024, 6, 1, 10, 14, 59);
+    assert_eq!(
+        bucket(utc, fifteen_minutes, origin),
+        Utc::from_ymd_hms(2024, 6, 1, 10, 0, 0)
+    );
+    let utc = Utc::from_ymd_hms(2024, 6, 1, 10, 15, 0);
+    assert_eq!(bucket(utc, fifteen_minutes, origin), utc);
+
+    // Origins after the instant
+    let six_hours = SignedDuration::from_secs(6 * 3600);
+    let late_origin = Utc::from_ymd_hms(2030, 1, 1, 1, 0, 0);
+    assert_eq!(
+        bucket(utc, six_hours, late_origin),
+        Utc::from_ymd_hms(2024, 6, 1, 7, 0, 0)
+    );
+
+    // Sub-second widths
+    let utc = Utc::from_timestamp_parts(origin.unix_timestamp_secs(), 123_456_789);
+    assert_eq!(
+        bucket(utc, SignedDuration::from_millis(100), origin).subsec_nanos(),
+        100_000_000
+    );
+
+    // Keeps the offset of the instant
+    let mut utc = Utc::from_ymd_hms(2024, 6, 1, 10, 20, 0);
+    utc.with_utc_offset(9.0);
+    let local = bucket(utc, fifteen_minutes, origin);
+    assert_eq!(local.get_utc_offset(), 9.0);
+    assert_eq!(local.time().to_string(), "19:15:00.000");
+
+    for width in [SignedDuration::ZERO, SignedDuration::from_secs(-60)] {
+        assert_eq!(
+            utc.duration_bucket(width, origin),
+            Err(HoraeError::InvalidStep)
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+
+use horae::{HoraeError, ParseComponent, SignedDuration, Time, Utc};
+
+#[test]
+fn from_hms_validates() {
+    let time = Time::from_hms(23, 59, 59).unwrap();
+    assert_eq!(
+        (time.hour(), time.minute(), time.second(), time.nanosecond()),
+        (23, 59, 59, 0)
+    );
+    assert_eq!(Time::from_hms(24, 0, 0), Err(HoraeError::InvalidHour(24)));
+    assert_eq!(Time::from_hms(0, 60, 0), Err(HoraeError::InvalidMinute(60)));
+    assert_eq!(Time::from_hms(0, 0, 60), Err(HoraeError::InvalidSecond(60)));
+    assert_eq!(
+        Time::from_hms_nano(0, 0, 0, 999_999_999)
+            .unwrap()
+            .nanosecond(),
+        999_999_999
+    );
+    assert_eq!(
+        Time::from_hms_nano(0, 0, 0, 1_000_000_000),
+        Err(HoraeError::InvalidNanosecond(1_000_000_000))
+    );
+}
+
+#[test]
+fn display_milliseconds() {
+    let time = Time::from_hms_nano(9, 9, 9, 5_000_000).unwrap();
+    assert_eq!(time.to_string(), "09:09:09.005");
+    let time = Time::from_hms_nano(9, 9, 9, 999_999_999).unwrap();
+    assert_eq!(time.to_string(), "09:09:09.999");
+}
+
+#[test]
+fn time_ordering_and_hash() {
+    let a = Time::from_hms(8, 59, 59).unwrap();
+    let b = Time::from_hms(9, 0, 0).unwrap();
+    let c = Time::from_hms_nano(9, 0, 0, 1).unwrap();
+    assert!(Time::MIDNIGHT < a && a < b && b < c);
+
+    let mut times = vec![c, b, a];
+    times.sort();
+    assert_eq!(times, [a, b, c]);
+
+    let set: HashSet<Time> = [a, a, b].into_iter().collect();
+    assert_eq!(set.len(), 2);
+    assert_eq!(Utc::from_ymd_hms(2024, 1, 1, 9, 0, 0).time(), b);
+}
+
+#[test]
+fn seconds_since_midnight() {
+    let time = Time::from_hms_nano(23, 59, 59, 5).unwrap();
+    assert_eq!(time.seconds_since_midnight(), 86_399);
+    assert_eq!(time.nanos_since_midnight(), 86_399_000_000_005);
+    assert_eq!(
+        Time::from_nanos_since_midnight(86_399_000_000_005),
+        Ok(time)
+    );
+    assert_eq!(Time::from_seconds_since_midnight(0), Ok(Time::MIDNIGHT));
+    assert_eq!(
+        Time::from_seconds_since_midnight(86_400),
+        Err(HoraeError::OutOfRange)
+    );
+    assert_eq!(
+        Time::from_nanos_since_midnight(u64::MAX),
+        Err(HoraeError::OutOfRange)
+    );
+}
+
+#[test]
+fn wrapping_add() {
+    let time = Time::from_hms(12, 0, 0).unwrap();
+    assert_eq!(time.wrapping_add(SignedDuration::ZERO), time);
+    assert_eq!(
+        time.wrapping_add(SignedDuration::from_secs(12 * 3600)),
+        Time::MIDNIGHT
+    );
+    assert_eq!(
+        time + SignedDuration::from_millis(-1),
+        Time::from_hms_nano(11, 59, 59, 999_000_000).unwrap()
+    );
+    assert_eq!(
+        time - SignedDuration::from_secs(13 * 3600),
+        Time::from_hms(23, 0, 0).unwrap()
+    );
+    assert_eq!(
+        time.wrapping_add(SignedDuration::from_secs(7 * 86_400 + 60)),
+        Time::from_hms(12, 1, 0).unwrap()
+    );
+}
+
+#[test]
+fn overflowing_add() {
+    let time = Time::from_hms(23, 30, 0).unwrap();
+    assert_eq!(
+        time.overflowing_add(SignedDuration::from_secs(1_800)),
+        (Time::MIDNIGHT, 1)
+    );
+    assert_eq!(
+        time.overflowing_add(SignedDuration::from_secs(1_799)),
+        (Time::from_hms(23, 59, 59).unwrap(), 0)
+    );
+    assert_eq!(
+        Time::MIDNIGHT.overflowing_add(SignedDuration::from_nanos(-1)),
+        (Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap(), -1)
+    );
+    assert_eq!(
+        time.overflowing_add(SignedDuration::from_secs(3 * 86_400)),
+        (time, 3)
+    );
+}
+
+#[test]
+fn parse_time() {
+    assert_eq!(Time::parse("00:00"), Ok(Time::MIDNIGHT));
+    assert_eq!(Time::parse("17:30"), Ok(Time::from_hms(17, 30, 0).unwrap()));
+    assert_eq!(
+        Time::parse("17:30:05"),
+        Ok(Time::from_hms(17, 30, 5).unwrap())
+    );
+    assert_eq!(
+        Time::parse("17:30:05.5"),
+        Ok(Time::from_hms_nano(17, 30, 5, 500_000_000).unwrap())
+    );
+    assert_eq!(
+        Time::parse("17:30:05.1234567891"),
+        Ok(Time::from_hms_nano(17, 30, 5, 123_456_789).unwrap())
+    );
+    assert_eq!(
+        "08:15".parse::<Time>(),
+        Ok(Time::from_hms(8, 15, 0).unwrap())
+    );
+}
+
+#[test]
+fn parse_time_errors() {
+    let cases = [
+        ("", ParseComponent::Hour, 0),
+        ("9:30", ParseComponent::Hour, 0),
+        ("09-30", ParseComponent::TimeSeparator, 2),
+        ("09:3", ParseComponent::Minute, 3),
+        ("09:30:", ParseComponent::Second, 6),
+        ("09:30:00.", ParseComponent::Fraction, 9),
+        ("09:30 ", ParseComponent::TimeSeparator, 5),
+        ("09:30:00Z", ParseComponent::Fraction, 8),
+        ("09:30:00.5x", ParseComponent::Fraction, 10),
+        ("24:00", ParseComponent::Hour, 0),
+        ("23:60", ParseComponent::Minute, 3),
+        ("23:59:60", ParseComponent::Second, 6),
+        ("12:ä0", ParseComponent::Minute, 3),
+    ];
+    for (input, component, position) in cases {
+        let error = Time::parse(input).unwrap_err();
+        assert_eq!(error.component(), component, "{input}");
+        assert_eq!(error.position(), position, "{input}");
+    }
+    assert_eq!("24:00".parse::<Time>(), Err(HoraeError::InvalidHour(24)));
+    assert!(matches!("24-00".parse::<Time>(), Err(HoraeError::Parse(_))));
+}
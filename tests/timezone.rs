@@ -4,7 +4,7 @@ use horae::{TimeZone, Utc};
 fn from_ymd_hms_utc() {
     // utc(+00:00)
     let utc =
-        Utc::from_ymd_hms_timezone(2021, 02, 25, 13, 59, 59, TimeZone::CoordinatedUniversalTime);
+        Utc::from_ymd_hms_timezone(2021, 2, 25, 13, 59, 59, TimeZone::CoordinatedUniversalTime);
     assert_eq!("2021-02-25 13:59:59.000", utc.to_string());
 }
 
@@ -13,7 +13,7 @@ fn positive() {
     // CEST(+02:00)
     let cest = Utc::from_ymd_hms_timezone(
         2021,
-        02,
+        2,
         25,
         13,
         59,
@@ -32,7 +32,7 @@ fn positive() {
 #[test]
 fn negative() {
     // Marquesas Islands =-09:30
-    let mart = Utc::from_ymd_hms_timezone(2021, 02, 25, 13, 59, 59, TimeZone::MarquesasIslandsTime);
+    let mart = Utc::from_ymd_hms_timezone(2021, 2, 25, 13, 59, 59, TimeZone::MarquesasIslandsTime);
     assert_eq!("2021-02-25 13:59:59.000", mart.to_string());
     // Underlying UTC should be 23:29:59 (13:59:59 - (-9.5h) = 13:59:59 + 9:30 = 23:29:59)
     assert_eq!(
@@ -44,21 +44,21 @@ fn negative() {
 #[test]
 fn negative_rollover_year() {
     // Marquesas Islands =-09:30
-    let mart = Utc::from_ymd_hms_timezone(2021, 01, 01, 0, 0, 59, TimeZone::MarquesasIslandsTime);
+    let mart = Utc::from_ymd_hms_timezone(2021, 1, 1, 0, 0, 59, TimeZone::MarquesasIslandsTime);
     assert_eq!("2021-01-01 00:00:59.000", mart.to_string());
 }
 
 #[test]
 fn negative_rollover_month() {
     // Marquesas Islands =-09:30
-    let mart = Utc::from_ymd_hms_timezone(2021, 02, 01, 0, 0, 59, TimeZone::MarquesasIslandsTime);
+    let mart = Utc::from_ymd_hms_timezone(2021, 2, 1, 0, 0, 59, TimeZone::MarquesasIslandsTime);
     assert_eq!("2021-02-01 00:00:59.000", mart.to_string());
 }
 
 #[test]
 fn negative_rollover_day() {
     // Marquesas Islands =-09:30
-    let mart = Utc::from_ymd_hms_timezone(2021, 02, 25, 0, 0, 59, TimeZone::MarquesasIslandsTime);
+    let mart = Utc::from_ymd_hms_timezone(2021, 2, 25, 0, 0, 59, TimeZone::MarquesasIslandsTime);
     assert_eq!("2021-02-25 00:00:59.000", mart.to_string());
 }
 
@@ -74,7 +74,7 @@ fn positive_rollover_year() {
 fn positive_rollover_month() {
     //Rollover positive Chatham(+13.75)
     let chatham =
-        Utc::from_ymd_hms_timezone(2021, 03, 31, 23, 59, 59, TimeZone::ChathamDaylightTime);
+        Utc::from_ymd_hms_timezone(2021, 3, 31, 23, 59, 59, TimeZone::ChathamDaylightTime);
     assert_eq!("2021-03-31 23:59:59.000", chatham.to_string());
 }
 
@@ -82,6 +82,6 @@ fn positive_rollover_month() {
 fn positive_rollover_day() {
     //Rollover positive Chatham(+13.75)
     let chatham =
-        Utc::from_ymd_hms_timezone(2021, 03, 28, 23, 59, 59, TimeZone::ChathamDaylightTime);
+        Utc::from_ymd_hms_timezone(2021, 3, 28, 23, 59, 59, TimeZone::ChathamDaylightTime);
     assert_eq!("2021-03-28 23:59:59.000", chatham.to_string());
 }
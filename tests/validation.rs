@@ -0,0 +1,179 @@
+use horae::{HoraeError, ParseComponent, TimeZone, Utc};
+
+#[test]
+fn try_from_ymd_hms_month_lengths() {
+    assert!(Utc::try_from_ymd_hms(2024, 2, 29, 0, 0, 0).is_ok());
+    assert!(Utc::try_from_ymd_hms(2000, 2, 29, 0, 0, 0).is_ok());
+    assert_eq!(
+        Utc::try_from_ymd_hms(1900, 2, 29, 0, 0, 0),
+        Err(HoraeError::InvalidDay {
+            year: 1900,
+            month: 2,
+            day: 29
+        })
+    );
+    assert_eq!(
+        Utc::try_from_ymd_hms(2023, 2, 31, 0, 0, 0),
+        Err(HoraeError::InvalidDay {
+            year: 2023,
+            month: 2,
+            day: 31
+        })
+    );
+    assert_eq!(
+        Utc::try_from_ymd_hms(2023, 6, 31, 0, 0, 0),
+        Err(HoraeError::InvalidDay {
+            year: 2023,
+            month: 6,
+            day: 31
+        })
+    );
+    assert!(Utc::try_from_ymd_hms(2023, 7, 31, 0, 0, 0).is_ok());
+    assert_eq!(
+        Utc::try_from_ymd_hms(2023, 7, 0, 0, 0, 0),
+        Err(HoraeError::InvalidDay {
+            year: 2023,
+            month: 7,
+            day: 0
+        })
+    );
+}
+
+#[test]
+fn try_from_ymd_hms_fields() {
+    assert_eq!(
+        Utc::try_from_ymd_hms(0, 1, 1, 0, 0, 0),
+        Err(HoraeError::InvalidYear(0))
+    );
+    assert_eq!(
+        Utc::try_from_ymd_hms(10_000, 1, 1, 0, 0, 0),
+        Err(HoraeError::InvalidYear(10_000))
+    );
+    assert_eq!(
+        Utc::try_from_ymd_hms(2023, 13, 1, 0, 0, 0),
+        Err(HoraeError::InvalidMonth(13))
+    );
+    assert_eq!(
+        Utc::try_from_ymd_hms(2023, 1, 1, 24, 0, 0),
+        Err(HoraeError::InvalidHour(24))
+    );
+    assert_eq!(
+        Utc::try_from_ymd_hms(2023, 1, 1, 0, 60, 0),
+        Err(HoraeError::InvalidMinute(60))
+    );
+    assert_eq!(
+        Utc::try_from_ymd_hms(2023, 1, 1, 0, 0, 60),
+        Err(HoraeError::InvalidSecond(60))
+    );
+    assert!(matches!(
+        Utc::try_from_ymd_hms_offset(2023, 1, 1, 0, 0, 0, f64::NAN),
+        Err(HoraeError::InvalidUtcOffset(_))
+    ));
+    assert_eq!(
+        Utc::try_from_ymd_hms_offset(2023, 1, 1, 0, 0, 0, -24.0),
+        Err(HoraeError::InvalidUtcOffset(-24.0))
+    );
+    assert!(
+        Utc::try_from_ymd_hms_timezone(2023, 2, 28, 23, 59, 59, TimeZone::ChathamDaylightTime)
+            .is_ok()
+    );
+}
+
+#[test]
+#[should_panic]
+fn from_ymd_hms_panics_on_impossible_date() {
+    let _ = Utc::from_ymd_hms(2023, 2, 31, 0, 0, 0);
+}
+
+#[test]
+fn rfc3339_never_panics() {
+    let inputs = [
+        "",
+        "2023",
+        "2023-02-31T00:00:00Z",
+        "2023-02-28T23:59:60Z",
+        "2023-02-28T24:00:00Z",
+        "2023-02-28T23:59:59+24:00",
+        "2023-02-28T23:59:59.Z",
+        "+023-02-28T23:59:59Z",
+        "2023-02-28T23:59:59Zjunk",
+        "2023-02-2ö8T23:59:59Z",
+        "ö023-02-28T23:59:59Z",
+        "2023-02-28T23:5ö:59Z",
+        "2023-02-28T23:59:59+0ö:00",
+        "2023-02-28T23:59:59+01:00[!+0ö:0]",
+        "2023-02-28T23:59:59+01:00[!+01:00",
+        "2023-02-28T23:59:59+01:00]",
+        "日本語日本語日本語日本語",
+    ];
+    for input in inputs {
+        let _ = Utc::from_rfc3339(input);
+        let _ = Utc::from_rfc9557(input);
+    }
+}
+
+#[test]
+fn rfc3339_errors() {
+    assert_eq!(
+        Utc::from_rfc3339("2023-02-31T00:00:00Z"),
+        Err(HoraeError::InvalidDay {
+            year: 2023,
+            month: 2,
+            day: 31
+        })
+    );
+    assert_eq!(
+        Utc::from_rfc3339("2023-02-28T24:00:00Z"),
+        Err(HoraeError::InvalidHour(24))
+    );
+    assert!(matches!(
+        Utc::from_rfc3339("2023-02-2ö8T23:59:59Z"),
+        Err(HoraeError::Parse(e)) if e.component() == ParseComponent::Day
+    ));
+    assert!(matches!(
+        Utc::from_rfc3339("+023-02-28T23:59:59Z"),
+        Err(HoraeError::Parse(e)) if e.component() == ParseComponent::Year
+    ));
+    assert!(matches!(
+        Utc::from_rfc3339("2023-02-28T23:59:59.Z"),
+        Err(HoraeError::Parse(e)) if e.component() == ParseComponent::Fraction
+    ));
+    assert!(matches!(
+        Utc::from_rfc3339("2023-02-28T23:59:59+24:00"),
+        Err(HoraeError::Parse(e)) if e.component() == ParseComponent::Offset
+    ));
+    assert!(matches!(
+        Utc::from_rfc9557("2023-02-28T23:59:59+01:00[!+01:00"),
+        Err(HoraeError::Parse(e)) if e.component() == ParseComponent::SuffixTag
+    ));
+}
+
+#[test]
+fn rfc3339_leap_second() {
+    let leap = Utc::from_rfc3339("2016-12-31T23:59:60Z").unwrap();
+    assert_eq!(leap.to_rfc3339(), "2017-01-01T00:00:00Z");
+
+    let leap_offset = Utc::from_rfc3339("2016-12-31T15:59:60.5-08:00").unwrap();
+    assert_eq!(leap_offset.to_rfc3339(), "2016-12-31T16:00:00.5-08:00");
+}
+
+#[test]
+fn error_display() {
+    assert_eq!(
+        HoraeError::InvalidDay {
+            year: 2023,
+            month: 2,
+            day: 31
+        }
+        .to_string(),
+        "invalid day 31, 2023-02 has no such day"
+    );
+    assert_eq!(
+        HoraeError::InvalidMonth(13).to_string(),
+        "invalid month 13, expected 1-12"
+    );
+    assert_eq!(
+        HoraeError::InvalidSecond(61).to_string(),
+        "invalid second 61, expected 0-59, or 60 for a leap second"
+    );
+}
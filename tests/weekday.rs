@@ -0,0 +1,107 @@
+use horae::{Date, HoraeError, Month, ParseComponent, Utc, Weekday};
+
+#[test]
+fn weekday_arithmetic() {
+    assert_eq!(Weekday::Monday.succ(), Weekday::Tuesday);
+    assert_eq!(Weekday::Sunday.succ(), Weekday::Monday);
+    assert_eq!(Weekday::Monday.pred(), Weekday::Sunday);
+    assert_eq!(Weekday::Wednesday + 0, Weekday::Wednesday);
+    assert_eq!(Weekday::Wednesday + 7, Weekday::Wednesday);
+    assert_eq!(Weekday::Saturday + 3, Weekday::Tuesday);
+    assert_eq!(Weekday::Saturday + -3, Weekday::Wednesday);
+    assert_eq!(Weekday::Tuesday - 3, Weekday::Saturday);
+    assert_eq!(Weekday::Tuesday - -3, Weekday::Friday);
+    assert_eq!(Weekday::Monday + 1_000_000, Weekday::Tuesday);
+    assert_eq!(Weekday::Monday + i64::MAX, Weekday::Monday);
+    assert_eq!(Weekday::Monday - i64::MIN, Weekday::Tuesday);
+    for weekday in Weekday::ALL {
+        assert_eq!(weekday.succ().pred(), weekday);
+        assert_eq!(weekday + 10 - 10, weekday);
+    }
+}
+
+#[test]
+fn weekday_numbers() {
+    for (weekday, from_monday, from_sunday) in [
+        (Weekday::Monday, 1, 2),
+        (Weekday::Saturday, 6, 7),
+        (Weekday::Sunday, 7, 1),
+    ] {
+        assert_eq!(weekday.number_from_monday(), from_monday);
+        assert_eq!(weekday.number_from_sunday(), from_sunday);
+        assert_eq!(Weekday::from_number_from_monday(from_monday), Some(weekday));
+        assert_eq!(Weekday::from_number_from_sunday(from_sunday), Some(weekday));
+    }
+    for number in [0, 8, u8::MAX] {
+        assert_eq!(Weekday::from_number_from_monday(number), None);
+        assert_eq!(Weekday::from_number_from_sunday(number), None);
+    }
+}
+
+#[test]
+fn weekday_names() {
+    for text in ["Thursday", "thursday", "THURSDAY", "Thu", "thu", "tHU"] {
+        assert_eq!(text.parse::<Weekday>(), Ok(Weekday::Thursday), "{text}");
+    }
+    assert_eq!(Weekday::Thursday.to_string(), "Thursday");
+    assert_eq!(Weekday::Thursday.name(), "Thursday");
+    assert_eq!(Weekday::Thursday.short_name(), "Thu");
+    for text in ["", "Th", "Thurs", "Thursdays", " Thu"] {
+        let Err(HoraeError::Parse(error)) = text.parse::<Weekday>() else {
+            panic!("{text} parsed");
+        };
+        assert_eq!(error.component(), ParseComponent::WeekDay);
+    }
+}
+
+#[test]
+fn month_arithmetic() {
+    assert_eq!(Month::January.succ(), Month::February);
+    assert_eq!(Month::December.succ(), Month::January);
+    assert_eq!(Month::January.pred(), Month::December);
+    assert_eq!(Month::October + 5, Month::March);
+    assert_eq!(Month::March - 5, Month::October);
+    assert_eq!(Month::March + -15, Month::December);
+    assert_eq!(Month::March - 24, Month::March);
+    assert_eq!(Month::January + i64::MAX, Month::August);
+    for month in Month::ALL {
+        assert_eq!(month.succ().pred(), month);
+    }
+}
+
+#[test]
+fn month_numbers_and_names() {
+    assert_eq!(Month::November.number(), 11);
+    assert_eq!(u8::from(Month::January), 1);
+    assert_eq!(Month::try_from(12), Ok(Month::December));
+    assert_eq!(Month::try_from(0), Err(HoraeError::InvalidMonth(0)));
+    assert_eq!(Month::try_from(13), Err(HoraeError::InvalidMonth(13)));
+    assert_eq!(Month::February.days_in_year(2024), 29);
+    assert_eq!(Month::February.days_in_year(1900), 28);
+    assert_eq!(Month::April.days_in_year(2024), 30);
+
+    for text in ["September", "september", "SEP", "sep"] {
+        assert_eq!(text.parse::<Month>(), Ok(Month::September), "{text}");
+    }
+    assert_eq!(Month::September.to_string(), "September");
+    assert_eq!(Month::September.short_name(), "Sep");
+    let Err(HoraeError::Parse(error)) = "Sept".parse::<Month>() else {
+        panic!("Sept parsed");
+    };
+    assert_eq!(error.component(), ParseComponent::Month);
+}
+
+#[test]
+fn accessors() {
+    let date = Date::from_ymd(2024, 2, 29).unwrap();
+    assert_eq!(date.weekday(), Weekday::Thursday);
+    assert_eq!(date.weekday().number_from_monday(), 4);
+    assert_eq!(Date::from_ymd(1, 1, 1).unwrap().weekday(), Weekday::Monday);
+
+    // The weekday of the local date in the UTC offset
+    let mut utc = Utc::from_ymd_hms(2024, 3, 4, 1, 0, 0);
+    assert_eq!(utc.weekday(), Weekday::Monday);
+    utc.with_utc_offset(-2.0);
+    assert_eq!(utc.weekday(), Weekday::Sunday);
+    assert_eq!(utc.weekday(), utc.date().weekday());
+}
@@ -51,13 +51,13 @@ fn test_weeknumber_timezone() {
     assert_eq!(utc.get_weeknumber(), 53);
 
     // If we are in a timezone that is ahead, say GMT+13, it's still Jan 1.
-    let mut utc_ahead = utc.clone();
+    let mut utc_ahead = utc;
     utc_ahead.with_utc_offset(13.0);
     assert_eq!(utc_ahead.get_weeknumber(), 53);
 
     // If we are in a timezone that is behind, say GMT-1, it's Dec 31 2020.
     // Dec 31 2020 is Thursday. Week 53 of 2020.
-    let mut utc_behind = utc.clone();
+    let mut utc_behind = utc;
     utc_behind.with_utc_offset(-1.0);
     assert_eq!(utc_behind.get_weeknumber(), 53);
 
@@ -70,7 +70,7 @@ fn test_weeknumber_timezone() {
     assert_eq!(utc_2019_sun.get_weeknumber(), 52);
 
     // If we add 2 hours (GMT+2), it becomes Dec 30 2019 01:00:00, which is Week 1.
-    let mut utc_2019_sun_ahead = utc_2019_sun.clone();
+    let mut utc_2019_sun_ahead = utc_2019_sun;
     utc_2019_sun_ahead.with_utc_offset(2.0);
     assert_eq!(utc_2019_sun_ahead.get_weeknumber(), 1);
 }
@@ -0,0 +1,110 @@
+use std::fmt::Write;
+
+use horae::{Date, Format, Iso8601Format, Time, TimeZone, Utc};
+
+#[test]
+fn rfc3339_buffer() {
+    let mut utc = Utc::from_timestamp_nanos(1_546_333_749_123_456_789);
+    for offset in [0.0, 5.5, -9.5, 14.0] {
+        utc.with_utc_offset(offset);
+        let buffer = utc.to_rfc3339_buffer();
+        assert_eq!(buffer.as_str(), utc.to_rfc3339());
+        assert_eq!(buffer.as_bytes(), utc.to_rfc3339().as_bytes());
+        assert_eq!(buffer.to_string(), utc.to_rfc3339());
+        assert_eq!(format!("{buffer:?}"), format!("{:?}", utc.to_rfc3339()));
+    }
+
+    // The longest RFC 3339 string fits
+    let mut utc = Utc::from_timestamp_nanos(253_402_214_400_999_999_999);
+    utc.with_utc_offset(23.0 + 59.0 / 60.0);
+    assert_eq!(
+        &*utc.to_rfc3339_buffer(),
+        "9999-12-31T23:59:00.999999999+23:59"
+    );
+
+    // Years after 9999 and out of range offsets are not cut off
+    let mut utc = Utc::from_timestamp_parts(253_450_000_000, 999_999_999);
+    utc.with_utc_offset(-5.0);
+    assert_eq!(
+        &*utc.to_rfc3339_buffer(),
+        "10001-07-05T20:46:40.999999999-05:00"
+    );
+    utc.with_utc_offset(1e12);
+    assert_eq!(&*utc.to_rfc3339_buffer(), utc.to_rfc3339());
+    assert!(utc.to_rfc3339().ends_with("+596523:14"));
+
+    for (nanos, expected) in [
+        (0, "2019-01-01T09:09:09Z"),
+        (500_000_000, "2019-01-01T09:09:09.5Z"),
+        (120_000, "2019-01-01T09:09:09.00012Z"),
+        (1, "2019-01-01T09:09:09.000000001Z"),
+    ] {
+        let utc = Utc::from_timestamp_nanos(1_546_333_749_000_000_000 + nanos);
+        assert_eq!(&*utc.to_rfc3339_buffer(), expected);
+        assert_eq!(utc.to_rfc3339(), expected);
+    }
+}
+
+#[test]
+fn write_rfc3339() {
+    let mut utc = Utc::from_ymd_hms(2019, 1, 1, 3, 39, 9);
+    utc.with_timezone(TimeZone::IndianStandardTime);
+
+    let mut log = Vec::new();
+    utc.write_rfc3339(&mut log).unwrap();
+    log.push(b' ');
+    utc.write_rfc3339(&mut log).unwrap();
+    assert_eq!(log, b"2019-01-01T09:09:09+05:30 2019-01-01T09:09:09+05:30");
+
+    // Errors of the writer are returned
+    let mut full = [0u8; 10];
+    let error = utc.write_rfc3339(&mut &mut full[..]).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::WriteZero);
+
+    // The ISO 8601 formats match the RFC 3339 writer
+    assert_eq!(
+        utc.to_iso8601(Iso8601Format::Extended),
+        &*utc.to_rfc3339_buffer()
+    );
+    assert_eq!(utc.to_iso8601(Iso8601Format::Basic), "20190101T090909+0530");
+}
+
+#[test]
+fn format_into() {
+    let format = Format::new("%wd %dd %mmm %yyyy %HH:%MM:%SS %tz |").unwrap();
+    let mut utc = Utc::from_ymd_hms(2019, 1, 1, 9, 9, 9);
+    let mut line = String::new();
+    utc.format_into(&mut line, &format).unwrap();
+    utc.with_utc_offset(-3.0);
+    utc.format_into(&mut line, &format).unwrap();
+    assert_eq!(
+        line,
+        "Tue 01 Jan 2019 09:09:09 Coordinated Universal Time |Tue 01 Jan 2019 06:09:09 GMT-03:00 |"
+    );
+    assert_eq!(utc.format_with(&format), utc.format(format.as_str()));
+
+    let date = Date::from_ymd(2019, 1, 1).unwrap();
+    let time = Time::from_hms(9, 9, 9).unwrap();
+    let mut line = String::new();
+    date.format_into(&mut line, &Format::new("%dd.%mm.%yyyy ").unwrap())
+        .unwrap();
+    time.format_into(&mut line, &Format::new("%H:%MM").unwrap())
+        .unwrap();
+    assert_eq!(line, "01.01.2019 9:09");
+}
+
+#[test]
+fn format_into_stack_buffer() {
+    let utc = Utc::from_ymd_hms(2019, 1, 1, 9, 9, 9);
+    let mut buffer = utc.to_rfc3339_buffer();
+    // Writes that do not fit into the 40 bytes are rejected whole
+    assert_eq!(buffer.len(), 20);
+    assert!(buffer.write_str("012345678901234567890").is_err());
+    assert_eq!(buffer.len(), 20);
+    assert!(buffer.write_str("!").is_ok());
+    assert_eq!(&*buffer, "2019-01-01T09:09:09Z!");
+
+    let format = Format::new("%yyyy-%mm-%ddT%HH:%MM:%SS.%NS").unwrap();
+    let mut buffer = utc.to_rfc3339_buffer();
+    assert!(utc.format_into(&mut buffer, &format).is_err());
+}